@@ -29,6 +29,35 @@ pub struct CacheArgs {
     /// To view the location of the cache directory, run `uv cache dir`.
     #[arg(global = true, long, env = EnvVars::UV_CACHE_DIR)]
     pub cache_dir: Option<PathBuf>,
+
+    /// Path to a read-only cache directory to consult for entries that are missing from the cache
+    /// directory.
+    ///
+    /// Fallback cache directories are consulted in the order in which they're provided. Entries
+    /// found in a fallback cache are linked or copied into the cache directory on first use; the
+    /// fallback cache directory is never written to, and so can reside on a read-only filesystem
+    /// (e.g., a shared network mount or a container image layer).
+    ///
+    /// May be provided multiple times. The `UV_FALLBACK_CACHE_DIR` environment variable accepts a
+    /// list of paths, separated by the platform's path separator (`:` on Unix, `;` on Windows).
+    #[arg(global = true, long, hide = true)]
+    pub fallback_cache_dir: Vec<PathBuf>,
+}
+
+impl CacheArgs {
+    /// Return the fallback cache directories provided via `--fallback-cache-dir` or
+    /// `UV_FALLBACK_CACHE_DIR`, if any.
+    pub fn fallback_cache_dirs(&self) -> Option<Vec<PathBuf>> {
+        if !self.fallback_cache_dir.is_empty() {
+            return Some(self.fallback_cache_dir.clone());
+        }
+        let paths = std::env::var_os(EnvVars::UV_FALLBACK_CACHE_DIR)?;
+        Some(
+            std::env::split_paths(&paths)
+                .filter(|path| !path.as_os_str().is_empty())
+                .collect(),
+        )
+    }
 }
 
 impl Cache {
@@ -82,7 +111,8 @@ impl TryFrom<CacheArgs> for Cache {
     type Error = io::Error;
 
     fn try_from(value: CacheArgs) -> Result<Self, Self::Error> {
-        Cache::from_settings(value.no_cache, value.cache_dir)
+        let fallbacks = value.fallback_cache_dirs().unwrap_or_default();
+        Ok(Cache::from_settings(value.no_cache, value.cache_dir)?.with_fallbacks(fallbacks))
     }
 }
 
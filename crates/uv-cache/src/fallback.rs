@@ -0,0 +1,412 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use tracing::{debug, trace};
+
+use crate::{ArchiveId, Cache, CacheBucket, CacheShard};
+
+impl Cache {
+    /// Consult the given read-only caches, in order, for any entries that are missing from this
+    /// cache.
+    ///
+    /// Entries are imported from a fallback cache into this cache on first use, such that
+    /// fallback caches are never written to (or locked). Fallbacks are ignored for temporary
+    /// caches.
+    #[must_use]
+    pub fn with_fallbacks(self, fallbacks: impl IntoIterator<Item = PathBuf>) -> Self {
+        if self.is_temporary() {
+            return self;
+        }
+        let fallbacks = fallbacks
+            .into_iter()
+            .filter(|fallback| *fallback != self.root)
+            .collect();
+        Self { fallbacks, ..self }
+    }
+
+    /// Return the read-only fallback caches.
+    pub fn fallbacks(&self) -> &[PathBuf] {
+        &self.fallbacks
+    }
+
+    /// Import the cache entry at the given path from a fallback cache, if it's missing from this
+    /// cache.
+    ///
+    /// Files are copied, retaining their modification time such that cached HTTP responses are
+    /// revalidated (and `--refresh` is respected) as if they'd been written to this cache
+    /// directly. Links to unzipped archives are recreated, importing the archive itself.
+    ///
+    /// Returns `true` if the entry was imported. Importing is best-effort: failures are logged,
+    /// and the entry is treated as a cache miss.
+    pub fn hydrate(&self, path: impl AsRef<Path>) -> bool {
+        if self.fallbacks.is_empty() {
+            return false;
+        }
+
+        let path = path.as_ref();
+        let Ok(relative) = path.strip_prefix(&self.root) else {
+            return false;
+        };
+        if is_lock(path) || fs_err::symlink_metadata(path).is_ok() {
+            return false;
+        }
+
+        for fallback in &self.fallbacks {
+            match self.import_entry(fallback, relative, true) {
+                Ok(true) => return true,
+                Ok(false) => {}
+                Err(err) => {
+                    debug!(
+                        "Failed to import cache entry `{}` from `{}`: {err}",
+                        relative.display(),
+                        fallback.display()
+                    );
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Import the cache entry at the given path from a fallback cache, if it's missing from this
+    /// cache, without blocking the async runtime.
+    ///
+    /// See [`Cache::hydrate`].
+    pub async fn hydrate_async(&self, path: impl AsRef<Path>) -> bool {
+        if self.fallbacks.is_empty() {
+            return false;
+        }
+        let cache = self.clone();
+        let path = path.as_ref().to_path_buf();
+        tokio::task::spawn_blocking(move || cache.hydrate(path))
+            .await
+            .unwrap_or(false)
+    }
+
+    /// Import every entry in the given shard from the fallback caches, if it's missing from this
+    /// cache.
+    ///
+    /// See [`Cache::hydrate`].
+    pub fn hydrate_shard(&self, shard: &CacheShard) {
+        if self.fallbacks.is_empty() {
+            return;
+        }
+
+        let Ok(relative) = shard.strip_prefix(&self.root) else {
+            return;
+        };
+
+        // Pre-built wheels are read through pointers to their archives, which import the archive
+        // on use (see `Archive::exists`), so avoid eagerly importing every archive in the shard.
+        // Other links (like those to wheels built from source) are read directly, and so require
+        // the archive to exist.
+        let import_archives = !relative.starts_with(CacheBucket::Wheels.to_str());
+
+        for fallback in &self.fallbacks {
+            let root = fallback.join(relative);
+            if !root.is_dir() {
+                continue;
+            }
+
+            let mut walker = walkdir::WalkDir::new(&root).min_depth(1).into_iter();
+            while let Some(entry) = walker.next() {
+                let Ok(entry) = entry else {
+                    continue;
+                };
+                let Ok(relative) = entry.path().strip_prefix(fallback) else {
+                    continue;
+                };
+                let path = self.root.join(relative);
+
+                // Descend into directories, even if they already exist locally.
+                if entry.file_type().is_dir() {
+                    if let Err(err) = fs_err::create_dir_all(&path) {
+                        debug!("Failed to create cache directory: {err}");
+                        walker.skip_current_dir();
+                    }
+                    continue;
+                }
+
+                if is_lock(&path) || fs_err::symlink_metadata(&path).is_ok() {
+                    continue;
+                }
+
+                if let Err(err) = self.import_entry(fallback, relative, import_archives) {
+                    debug!(
+                        "Failed to import cache entry `{}` from `{}`: {err}",
+                        relative.display(),
+                        fallback.display()
+                    );
+                }
+            }
+        }
+    }
+
+    /// Import every entry in the given shard from the fallback caches, if it's missing from this
+    /// cache, without blocking the async runtime.
+    ///
+    /// See [`Cache::hydrate_shard`].
+    pub async fn hydrate_shard_async(&self, shard: &CacheShard) {
+        if self.fallbacks.is_empty() {
+            return;
+        }
+        let cache = self.clone();
+        let shard = shard.clone();
+        let _ = tokio::task::spawn_blocking(move || cache.hydrate_shard(&shard)).await;
+    }
+
+    /// Import the archive with the given ID from a fallback cache, if it's missing from this
+    /// cache.
+    ///
+    /// Returns `true` if the archive exists in this cache.
+    pub fn hydrate_archive(&self, id: &ArchiveId) -> bool {
+        if self.archive(id).is_dir() {
+            return true;
+        }
+
+        for fallback in &self.fallbacks {
+            match self.import_archive(fallback, id) {
+                Ok(true) => return true,
+                Ok(false) => {}
+                Err(err) => {
+                    debug!(
+                        "Failed to import cache archive `{id}` from `{}`: {err}",
+                        fallback.display()
+                    );
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Import every entry from the cache directory at the given path that's missing from this
+    /// cache, e.g., to seed this cache from a bundle.
+    ///
+    /// Unlike entries in a fallback cache, archives are imported eagerly, such that the source
+    /// directory can be removed once the import completes.
+    ///
+    /// Returns the number of imported entries.
+    pub fn import(&self, source: &Path) -> Result<usize, io::Error> {
+        let mut imported = 0;
+
+        // Import the archives first, such that any links to them can be recreated.
+        match fs_err::read_dir(source.join(CacheBucket::Archive.to_str())) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    let Some(id) = entry
+                        .file_name()
+                        .to_str()
+                        .and_then(|name| ArchiveId::from_str(name).ok())
+                    else {
+                        continue;
+                    };
+                    if self.archive(&id).is_dir() {
+                        continue;
+                    }
+                    if self.import_archive(source, &id)? {
+                        imported += 1;
+                    }
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+
+        let mut walker = walkdir::WalkDir::new(source).min_depth(1).into_iter();
+        while let Some(entry) = walker.next() {
+            let entry = entry?;
+            let Ok(relative) = entry.path().strip_prefix(source) else {
+                continue;
+            };
+            if entry.file_type().is_dir() {
+                if relative == Path::new(CacheBucket::Archive.to_str()) {
+                    walker.skip_current_dir();
+                }
+                continue;
+            }
+
+            let path = self.root.join(relative);
+            if is_lock(&path) || fs_err::symlink_metadata(&path).is_ok() {
+                continue;
+            }
+            if self.import_entry(source, relative, true)? {
+                imported += 1;
+            }
+        }
+
+        Ok(imported)
+    }
+
+    /// If the entry at the given path is a link to an archive (i.e., an unzipped wheel), return
+    /// the ID of the archive.
+    pub fn archive_link(&self, path: &Path) -> Option<ArchiveId> {
+        let metadata = fs_err::symlink_metadata(path).ok()?;
+        read_archive_link(path, &metadata)
+    }
+
+    /// Import the entry at the given path (relative to the cache root) from the given fallback
+    /// cache.
+    ///
+    /// If `import_archives` is `false`, links are recreated without importing the archives to which
+    /// they point, such that the archives can be imported on first use.
+    fn import_entry(
+        &self,
+        fallback: &Path,
+        relative: &Path,
+        import_archives: bool,
+    ) -> Result<bool, io::Error> {
+        let source = fallback.join(relative);
+        let metadata = match fs_err::symlink_metadata(&source) {
+            Ok(metadata) => metadata,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(false),
+            Err(err) => return Err(err),
+        };
+
+        let target = self.root.join(relative);
+        if let Some(parent) = target.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+
+        // If the entry is a link to an archive, import the archive, then recreate the link.
+        if let Some(id) = read_archive_link(&source, &metadata) {
+            let exists = if import_archives {
+                self.hydrate_archive(&id)
+            } else {
+                self.archive(&id).is_dir()
+                    || fallback
+                        .join(CacheBucket::Archive.to_str())
+                        .join(&id)
+                        .is_dir()
+            };
+            if !exists {
+                return Ok(false);
+            }
+            trace!("Importing cache link: {}", relative.display());
+            self.create_link(&id, &target)?;
+            return Ok(true);
+        }
+
+        if metadata.is_dir() {
+            // Archives are imported as a unit. Other directories are imported entry-by-entry, via
+            // `hydrate_shard`.
+            return if relative.starts_with(CacheBucket::Archive.to_str()) {
+                let Some(id) = relative
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .and_then(|name| ArchiveId::from_str(name).ok())
+                else {
+                    return Ok(false);
+                };
+                Ok(self.hydrate_archive(&id))
+            } else {
+                Ok(false)
+            };
+        }
+
+        if !metadata.is_file() {
+            return Ok(false);
+        }
+
+        trace!("Importing cache entry: {}", relative.display());
+        let temp_file = tempfile::NamedTempFile::new_in(target.parent().unwrap_or(&self.root))?;
+        fs_err::copy(&source, temp_file.path())?;
+        temp_file.as_file().set_modified(metadata.modified()?)?;
+        match temp_file.persist_noclobber(&target) {
+            Ok(_) => Ok(true),
+            // Another process imported the entry concurrently.
+            Err(err) if err.error.kind() == io::ErrorKind::AlreadyExists => Ok(true),
+            Err(err) => Err(err.error),
+        }
+    }
+
+    /// Import the archive with the given ID from the given fallback cache.
+    ///
+    /// Files are hard-linked if the fallback cache is on the same filesystem, and copied
+    /// otherwise.
+    fn import_archive(&self, fallback: &Path, id: &ArchiveId) -> Result<bool, io::Error> {
+        let source = fallback.join(CacheBucket::Archive.to_str()).join(id);
+        if !source.is_dir() {
+            return Ok(false);
+        }
+
+        debug!("Importing cache archive from: {}", source.display());
+
+        // Import into a temporary directory, then move it into place, such that the archive is
+        // never visible in a partially imported state.
+        fs_err::create_dir_all(&self.root)?;
+        let temp_dir = tempfile::tempdir_in(&self.root)?;
+        let mut link = true;
+        for entry in walkdir::WalkDir::new(&source).min_depth(1) {
+            let entry = entry?;
+            let Ok(relative) = entry.path().strip_prefix(&source) else {
+                continue;
+            };
+            let target = temp_dir.path().join(relative);
+            if entry.file_type().is_dir() {
+                fs_err::create_dir_all(&target)?;
+                continue;
+            }
+            if link {
+                match fs_err::hard_link(entry.path(), &target) {
+                    Ok(()) => continue,
+                    Err(err) => {
+                        trace!("Failed to hard link from fallback cache, copying instead: {err}");
+                        link = false;
+                    }
+                }
+            }
+            fs_err::copy(entry.path(), &target)?;
+        }
+
+        let archive = self.archive(id);
+        fs_err::create_dir_all(self.bucket(CacheBucket::Archive))?;
+        match fs_err::rename(temp_dir.path(), &archive) {
+            Ok(()) => Ok(true),
+            // Another process imported the archive concurrently.
+            Err(_) if archive.is_dir() => Ok(true),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Returns `true` if the path refers to a lockfile, which are never imported from a fallback
+/// cache.
+fn is_lock(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("lock"))
+}
+
+/// If the entry at the given path is a link to an archive, return the ID of the archive.
+///
+/// Links may point to the fallback cache's archive bucket by absolute path, so the fallback may
+/// have been relocated since the link was created; only the archive ID is retained.
+#[cfg(unix)]
+fn read_archive_link(path: &Path, metadata: &std::fs::Metadata) -> Option<ArchiveId> {
+    if !metadata.is_symlink() {
+        return None;
+    }
+    let target = fs_err::read_link(path).ok()?;
+    let bucket = target.parent()?.file_name()?;
+    if bucket != CacheBucket::Archive.to_str() {
+        return None;
+    }
+    ArchiveId::from_str(target.file_name()?.to_str()?).ok()
+}
+
+/// If the entry at the given path is a link to an archive, return the ID of the archive.
+#[cfg(windows)]
+fn read_archive_link(path: &Path, metadata: &std::fs::Metadata) -> Option<ArchiveId> {
+    // Links are small; avoid reading (e.g.) entire wheels into memory.
+    if !metadata.is_file() || metadata.len() > 128 {
+        return None;
+    }
+    let contents = fs_err::read_to_string(path).ok()?;
+    let link = crate::Link::from_str(&contents).ok()?;
+    if link.version != crate::ARCHIVE_VERSION {
+        return None;
+    }
+    Some(link.id)
+}
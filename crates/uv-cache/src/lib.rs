@@ -6,7 +6,7 @@ use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::debug;
 
 pub use archive::ArchiveId;
@@ -20,6 +20,7 @@ pub use crate::by_timestamp::CachedByTimestamp;
 pub use crate::cli::CacheArgs;
 use crate::removal::Remover;
 pub use crate::removal::{rm_rf, Removal};
+pub use crate::stats::{BucketStats, CacheStats};
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 
@@ -27,7 +28,10 @@ mod archive;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod fallback;
 mod removal;
+mod stats;
+mod usage;
 mod wheel;
 
 /// The version of the archive bucket.
@@ -146,6 +150,8 @@ pub struct Cache {
     /// Included to ensure that the temporary directory exists for the length of the operation, but
     /// is dropped at the end as appropriate.
     temp_dir: Option<Arc<tempfile::TempDir>>,
+    /// Read-only caches to consult for entries that are missing from this cache.
+    fallbacks: Vec<PathBuf>,
 }
 
 impl Cache {
@@ -155,6 +161,7 @@ impl Cache {
             root: root.into(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: None,
+            fallbacks: Vec::new(),
         }
     }
 
@@ -165,6 +172,7 @@ impl Cache {
             root: temp_dir.path().to_path_buf(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: Some(Arc::new(temp_dir)),
+            fallbacks: Vec::new(),
         })
     }
 
@@ -361,6 +369,11 @@ impl Cache {
 
         Ok(Self {
             root: std::path::absolute(root)?,
+            fallbacks: self
+                .fallbacks
+                .iter()
+                .map(std::path::absolute)
+                .collect::<Result<_, _>>()?,
             ..self
         })
     }
@@ -523,6 +536,46 @@ impl Cache {
             Err(err) => return Err(err),
         }
 
+        // Fifth, remove the usage records of any archives that no longer exist.
+        self.prune_usage()?;
+
+        Ok(summary)
+    }
+
+    /// Remove the given entries from the archive bucket, along with any links that point to them.
+    ///
+    /// Links are removed before the archives themselves, such that an archive is never reachable
+    /// in a partially removed state.
+    pub fn remove_archives(&self, archives: &[PathBuf]) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
+        if archives.is_empty() {
+            return Ok(summary);
+        }
+
+        let archives = archives
+            .iter()
+            .map(fs_err::canonicalize)
+            .collect::<Result<FxHashSet<_>, _>>()?;
+
+        let mut links = Vec::new();
+        self.visit_archive_links(|_, link, target| {
+            if archives.contains(&target) {
+                links.push(link.to_path_buf());
+            }
+        })?;
+        for link in links {
+            debug!("Removing cache entry: {}", link.display());
+            summary += rm_rf(link)?;
+        }
+
+        for archive in archives {
+            debug!("Removing cache archive: {}", archive.display());
+            summary += rm_rf(&archive)?;
+            if let Some(id) = archive.file_name() {
+                self.forget_usage(id);
+            }
+        }
+
         Ok(summary)
     }
 
@@ -531,16 +584,42 @@ impl Cache {
     /// Archive entries are often referenced by symlinks in other cache buckets. This method
     /// searches for all such references.
     fn find_archive_references(&self) -> Result<FxHashSet<PathBuf>, io::Error> {
-        let mut references = FxHashSet::default();
+        Ok(self.find_archive_owners()?.into_keys().collect())
+    }
+
+    /// Find all references to entries in the archive bucket, along with the package that
+    /// references each entry, if known.
+    fn find_archive_owners(&self) -> Result<FxHashMap<PathBuf, Option<PackageName>>, io::Error> {
+        let mut references = FxHashMap::default();
+        self.visit_archive_links(|bucket, link, target| {
+            let package = link
+                .strip_prefix(self.bucket(bucket))
+                .ok()
+                .and_then(|relative| bucket.package(relative));
+            let owner = references.entry(target).or_insert(None);
+            if owner.is_none() {
+                *owner = package;
+            }
+        })?;
+        Ok(references)
+    }
+
+    /// Visit every link to an entry in the archive bucket, along with the bucket that contains
+    /// the link and the resolved path to the archive.
+    fn visit_archive_links(
+        &self,
+        mut visit: impl FnMut(CacheBucket, &Path, PathBuf),
+    ) -> Result<(), io::Error> {
         for bucket in CacheBucket::iter() {
-            // As an optimization, skip the archive bucket itself.
-            if matches!(bucket, CacheBucket::Archive) {
+            // As an optimization, skip the archive bucket itself, along with the usage records
+            // (which are never links).
+            if matches!(bucket, CacheBucket::Archive | CacheBucket::Usage) {
                 continue;
             }
 
             let bucket_path = self.bucket(bucket);
             if bucket_path.is_dir() {
-                for entry in walkdir::WalkDir::new(bucket_path) {
+                for entry in walkdir::WalkDir::new(&bucket_path) {
                     let entry = entry?;
 
                     // As an optimization, ignore any `.lock`, `.whl`, `.msgpack`, `.rev`, or
@@ -556,12 +635,12 @@ impl Cache {
                     }
 
                     if let Ok(target) = self.resolve_link(entry.path()) {
-                        references.insert(target);
+                        visit(bucket, entry.path(), target);
                     }
                 }
             }
         }
-        Ok(references)
+        Ok(())
     }
 
     /// Create a link to a directory in the archive bucket.
@@ -973,6 +1052,13 @@ pub enum CacheBucket {
     Builds,
     /// Reusable virtual environments used to invoke Python tools.
     Environments,
+    /// The last use of each entry in the archive bucket, for least-recently-used eviction.
+    ///
+    /// Cache structure: `usage-v0/<archive-id>`
+    ///
+    /// Each entry is an empty file, whose modification time reflects the last time that the
+    /// corresponding archive was installed.
+    Usage,
 }
 
 impl CacheBucket {
@@ -983,10 +1069,10 @@ impl CacheBucket {
             Self::SourceDistributions => "sdists-v9",
             Self::FlatIndex => "flat-index-v2",
             Self::Git => "git-v0",
-            Self::Interpreter => "interpreter-v4",
+            Self::Interpreter => "interpreter-v5",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_clean.rs`.
-            Self::Simple => "simple-v15",
+            Self::Simple => "simple-v16",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_prune.rs`.
             Self::Wheels => "wheels-v5",
@@ -995,6 +1081,7 @@ impl CacheBucket {
             Self::Archive => "archive-v0",
             Self::Builds => "builds-v0",
             Self::Environments => "environments-v2",
+            Self::Usage => "usage-v0",
         }
     }
 
@@ -1096,7 +1183,12 @@ impl CacheBucket {
                 let root = cache.bucket(self);
                 summary += rm_rf(root)?;
             }
-            Self::Git | Self::Interpreter | Self::Archive | Self::Builds | Self::Environments => {
+            Self::Git
+            | Self::Interpreter
+            | Self::Archive
+            | Self::Builds
+            | Self::Environments
+            | Self::Usage => {
                 // Nothing to do.
             }
         }
@@ -1115,6 +1207,7 @@ impl CacheBucket {
             Self::Archive,
             Self::Builds,
             Self::Environments,
+            Self::Usage,
         ]
         .iter()
         .copied()
@@ -0,0 +1,249 @@
+use std::collections::BTreeMap;
+use std::fs::Metadata;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::SystemTime;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use uv_normalize::PackageName;
+
+use crate::wheel::WheelCacheKind;
+use crate::{Cache, CacheBucket};
+
+/// Statistics on the contents of a [`Cache`].
+#[derive(Debug, Default)]
+pub struct CacheStats {
+    /// The statistics for each cache bucket, in the order of [`CacheBucket::iter`].
+    pub buckets: Vec<(CacheBucket, BucketStats)>,
+    /// The number of archives that are not referenced by any other cache entry.
+    pub unreferenced_archives: u64,
+    /// The total number of bytes in unreferenced archives.
+    pub unreferenced_bytes: u64,
+}
+
+impl CacheStats {
+    /// The total number of files in the cache.
+    pub fn num_files(&self) -> u64 {
+        self.buckets.iter().map(|(_, stats)| stats.num_files).sum()
+    }
+
+    /// The total number of bytes in the cache, counting hard-linked files once.
+    pub fn total_bytes(&self) -> u64 {
+        self.buckets
+            .iter()
+            .map(|(_, stats)| stats.total_bytes)
+            .sum()
+    }
+
+    /// The most recent time at which any file in the cache was accessed.
+    pub fn last_accessed(&self) -> Option<SystemTime> {
+        self.buckets
+            .iter()
+            .filter_map(|(_, stats)| stats.last_accessed)
+            .max()
+    }
+}
+
+/// Statistics on the contents of a single [`CacheBucket`].
+#[derive(Debug, Default)]
+pub struct BucketStats {
+    /// The number of files in the bucket.
+    pub num_files: u64,
+    /// The total number of bytes in the bucket.
+    ///
+    /// Files with multiple hard links (e.g., unzipped wheels that were linked into a cached
+    /// environment) are only counted once across the cache, and are attributed to the first bucket
+    /// in which they're encountered, starting with [`CacheBucket::Archive`].
+    pub total_bytes: u64,
+    /// The most recent time at which any file in the bucket was accessed.
+    pub last_accessed: Option<SystemTime>,
+    /// The number of bytes attributable to each package, for buckets that are organized by
+    /// package.
+    pub packages: BTreeMap<PackageName, u64>,
+}
+
+impl BucketStats {
+    /// Return the `n` packages with the largest footprint in the bucket, in descending order.
+    pub fn top_packages(&self, n: usize) -> Vec<(&PackageName, u64)> {
+        let mut packages = self
+            .packages
+            .iter()
+            .map(|(name, bytes)| (name, *bytes))
+            .collect::<Vec<_>>();
+        packages.sort_by(|(name_a, bytes_a), (name_b, bytes_b)| {
+            bytes_b.cmp(bytes_a).then_with(|| name_a.cmp(name_b))
+        });
+        packages.truncate(n);
+        packages
+    }
+
+    /// Add the files under the given path to the statistics, attributing each to the package
+    /// returned by `package`.
+    ///
+    /// Returns the number of bytes added.
+    fn visit(
+        &mut self,
+        path: &Path,
+        package: impl Fn(&Path) -> Option<PackageName>,
+        seen: &mut FxHashSet<(u64, u64)>,
+    ) -> Result<u64, io::Error> {
+        let mut total = 0;
+        for entry in walkdir::WalkDir::new(path) {
+            let entry = entry?;
+            if entry.file_type().is_dir() {
+                continue;
+            }
+
+            let metadata = entry.metadata()?;
+            self.num_files += 1;
+            if let Ok(accessed) = metadata.accessed() {
+                self.last_accessed = self.last_accessed.max(Some(accessed));
+            }
+
+            let bytes = file_size(&metadata, seen);
+            self.total_bytes += bytes;
+            total += bytes;
+
+            if let Some(package) = package(entry.path()) {
+                *self.packages.entry(package).or_default() += bytes;
+            }
+        }
+        Ok(total)
+    }
+}
+
+impl Cache {
+    /// Collect statistics on the contents of the cache.
+    pub fn stats(&self) -> Result<CacheStats, io::Error> {
+        // Map each referenced archive to the package that references it, if known.
+        let references = self.find_archive_owners()?;
+
+        let mut seen = FxHashSet::default();
+        let mut buckets = FxHashMap::default();
+        let mut unreferenced_archives = 0;
+        let mut unreferenced_bytes = 0;
+
+        // Visit the archive bucket first, such that hard-linked files are attributed to the archive
+        // in which they're stored, rather than to (e.g.) the cached environments that link to them.
+        let mut archive = BucketStats::default();
+        match fs_err::read_dir(self.bucket(CacheBucket::Archive)) {
+            Ok(entries) => {
+                for entry in entries {
+                    let path = entry?.path();
+                    let canonical = fs_err::canonicalize(&path)?;
+                    let owner = references.get(&canonical);
+                    let bytes = archive.visit(&path, |_| owner.cloned().flatten(), &mut seen)?;
+                    if owner.is_none() {
+                        unreferenced_archives += 1;
+                        unreferenced_bytes += bytes;
+                    }
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+            Err(err) => return Err(err),
+        }
+        buckets.insert(CacheBucket::Archive, archive);
+
+        for bucket in CacheBucket::iter() {
+            if matches!(bucket, CacheBucket::Archive) {
+                continue;
+            }
+            let mut stats = BucketStats::default();
+            let root = self.bucket(bucket);
+            if root.is_dir() {
+                stats.visit(
+                    &root,
+                    |path| {
+                        path.strip_prefix(&root)
+                            .ok()
+                            .and_then(|relative| bucket.package(relative))
+                    },
+                    &mut seen,
+                )?;
+            }
+            buckets.insert(bucket, stats);
+        }
+
+        Ok(CacheStats {
+            buckets: CacheBucket::iter()
+                .map(|bucket| (bucket, buckets.remove(&bucket).unwrap_or_default()))
+                .collect(),
+            unreferenced_archives,
+            unreferenced_bytes,
+        })
+    }
+}
+
+impl CacheBucket {
+    /// Return the package to which the entry at the given path (relative to the bucket root)
+    /// belongs, for buckets that are organized by package.
+    pub(crate) fn package(self, relative: &Path) -> Option<PackageName> {
+        let mut components = relative
+            .components()
+            .map(|component| component.as_os_str().to_str());
+        let kind = components.next()??;
+
+        let name = match self {
+            Self::Wheels | Self::SourceDistributions => {
+                if kind == WheelCacheKind::Pypi.to_str() {
+                    // e.g., `pypi/<name>/...`
+                    components.next()??
+                } else if kind == WheelCacheKind::Index.to_str()
+                    || (kind == WheelCacheKind::Url.to_str() && matches!(self, Self::Wheels))
+                {
+                    // e.g., `index/<digest>/<name>/...`
+                    components.next()??;
+                    components.next()??
+                } else {
+                    return None;
+                }
+            }
+            Self::Simple => {
+                if kind == WheelCacheKind::Pypi.to_str() {
+                    // e.g., `pypi/<name>.rkyv`
+                    components.next()??.strip_suffix(".rkyv")?
+                } else if kind == WheelCacheKind::Index.to_str() {
+                    // e.g., `index/<digest>/<name>.rkyv`
+                    components.next()??;
+                    components.next()??.strip_suffix(".rkyv")?
+                } else {
+                    return None;
+                }
+            }
+            _ => return None,
+        };
+
+        PackageName::from_str(name).ok()
+    }
+}
+
+/// Return the number of bytes to attribute to a file with the given metadata.
+///
+/// Symlinks are not followed, and files with multiple hard links are only counted the first time
+/// they're seen.
+#[cfg(unix)]
+pub(crate) fn file_size(metadata: &Metadata, seen: &mut FxHashSet<(u64, u64)>) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+
+    if metadata.file_type().is_symlink() {
+        return 0;
+    }
+    if metadata.nlink() > 1 && !seen.insert((metadata.dev(), metadata.ino())) {
+        return 0;
+    }
+    metadata.len()
+}
+
+/// Return the number of bytes to attribute to a file with the given metadata.
+///
+/// Symlinks are not followed. Hard links can't be detected without opening each file, so every
+/// link is counted.
+#[cfg(not(unix))]
+pub(crate) fn file_size(metadata: &Metadata, _seen: &mut FxHashSet<(u64, u64)>) -> u64 {
+    if metadata.file_type().is_symlink() {
+        return 0;
+    }
+    metadata.len()
+}
@@ -0,0 +1,274 @@
+use std::ffi::OsStr;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::{debug, trace};
+
+use uv_fs::LockedFile;
+
+use crate::stats::file_size;
+use crate::{rm_rf, Cache, CacheBucket, Removal};
+
+/// Archives that were used within this period are never evicted, to avoid removing an archive
+/// that a concurrent process is in the midst of installing.
+const GRACE_PERIOD: Duration = Duration::from_secs(10 * 60);
+
+/// An entry in the archive bucket, as considered for eviction.
+#[derive(Debug)]
+struct ArchiveUsage {
+    /// The path to the archive.
+    path: PathBuf,
+    /// The total number of bytes in the archive.
+    total_bytes: u64,
+    /// The last time the archive was used.
+    last_used: SystemTime,
+}
+
+impl Cache {
+    /// Record that the given cache entries were used, for the purpose of least-recently-used
+    /// eviction.
+    ///
+    /// Each path may point to an entry in the archive bucket, or to a link to such an entry; any
+    /// other paths are ignored. Usage tracking is best-effort (e.g., the cache may be read-only),
+    /// so failures are logged rather than returned.
+    pub fn record_usage<'a>(&self, paths: impl IntoIterator<Item = &'a Path>) {
+        if self.is_temporary() {
+            return;
+        }
+
+        let Ok(archives) = fs_err::canonicalize(self.bucket(CacheBucket::Archive)) else {
+            return;
+        };
+
+        let usage = self.bucket(CacheBucket::Usage);
+        if let Err(err) = fs_err::create_dir_all(&usage) {
+            debug!("Failed to create cache usage directory: {err}");
+            return;
+        }
+
+        let now = SystemTime::now();
+        for path in paths {
+            let Ok(target) = self
+                .resolve_link(path)
+                .or_else(|_| fs_err::canonicalize(path))
+            else {
+                continue;
+            };
+            let Some(id) = target
+                .strip_prefix(&archives)
+                .ok()
+                .and_then(|relative| relative.components().next())
+            else {
+                continue;
+            };
+            if let Err(err) = touch(&usage.join(id), now) {
+                debug!(
+                    "Failed to record usage of cache archive `{}`: {err}",
+                    target.display()
+                );
+            }
+        }
+    }
+
+    /// Evict entries from the archive bucket in least-recently-used order, along with the links
+    /// that point to them.
+    ///
+    /// Archives that have not been used within `unused_for` are evicted. Then, if the archives are
+    /// larger than `max_size` bytes in total, the least recently used archives are evicted until
+    /// they fit. Other buckets can't be evicted, so they don't count towards `max_size`.
+    ///
+    /// To remain safe in the presence of concurrent uv processes, archives that were used
+    /// recently, or whose links are locked by another process, are always retained. Links are
+    /// removed before the archive itself, such that the archive is never reachable in a partially
+    /// removed state.
+    pub fn evict(
+        &self,
+        max_size: Option<u64>,
+        unused_for: Option<Duration>,
+    ) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
+        if max_size.is_none() && unused_for.is_none() {
+            return Ok(summary);
+        }
+
+        // Determine the size and last use of each archive.
+        let mut archives = Vec::new();
+        let mut seen = FxHashSet::default();
+        match fs_err::read_dir(self.bucket(CacheBucket::Archive)) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    let path = fs_err::canonicalize(entry.path())?;
+                    let last_used = self.last_used(&entry.file_name(), &path)?;
+                    let total_bytes = archive_size(&path, &mut seen)?;
+                    archives.push(ArchiveUsage {
+                        path,
+                        total_bytes,
+                        last_used,
+                    });
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(summary),
+            Err(err) => return Err(err),
+        }
+        archives.sort_by_key(|archive| archive.last_used);
+
+        // Map each archive to the links that point to it.
+        let mut links = FxHashMap::<PathBuf, Vec<PathBuf>>::default();
+        self.visit_archive_links(|_, link, target| {
+            links.entry(target).or_default().push(link.to_path_buf());
+        })?;
+
+        let mut total_bytes = archives
+            .iter()
+            .map(|archive| archive.total_bytes)
+            .sum::<u64>();
+
+        let now = SystemTime::now();
+        for archive in archives {
+            let age = now.duration_since(archive.last_used).unwrap_or_default();
+            let expired = unused_for.is_some_and(|unused_for| age >= unused_for);
+            let oversized = max_size.is_some_and(|max_size| total_bytes > max_size);
+
+            // Archives are sorted by last use, so if this archive should be retained, so should
+            // every subsequent archive.
+            if !expired && !oversized {
+                break;
+            }
+            if age < GRACE_PERIOD {
+                debug!(
+                    "Retaining recently used cache archive: {}",
+                    archive.path.display()
+                );
+                break;
+            }
+
+            // Lock the links to the archive, to avoid evicting an archive while another process is
+            // writing to (or reading from) the corresponding cache entry.
+            let links = links.remove(&archive.path).unwrap_or_default();
+            let Some(_locks) = try_lock(&links)? else {
+                debug!("Retaining locked cache archive: {}", archive.path.display());
+                continue;
+            };
+
+            for link in &links {
+                debug!("Removing cache entry: {}", link.display());
+                summary += rm_rf(link)?;
+            }
+
+            debug!("Evicting cache archive: {}", archive.path.display());
+            summary += rm_rf(&archive.path)?;
+            total_bytes = total_bytes.saturating_sub(archive.total_bytes);
+
+            if let Some(id) = archive.path.file_name() {
+                self.forget_usage(id);
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Remove any usage records for archives that no longer exist.
+    pub(crate) fn prune_usage(&self) -> Result<(), io::Error> {
+        match fs_err::read_dir(self.bucket(CacheBucket::Usage)) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    if !self
+                        .bucket(CacheBucket::Archive)
+                        .join(entry.file_name())
+                        .exists()
+                    {
+                        self.forget_usage(&entry.file_name());
+                    }
+                }
+                Ok(())
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Return the last time the archive with the given ID was used.
+    ///
+    /// Archives are considered used when they're created, whenever they're selected from the cache
+    /// (e.g., when planning an installation), and whenever they're installed.
+    fn last_used(&self, id: &OsStr, path: &Path) -> Result<SystemTime, io::Error> {
+        let created = fs_err::metadata(path)?.modified()?;
+        let installed = fs_err::metadata(self.bucket(CacheBucket::Usage).join(id))
+            .and_then(|metadata| metadata.modified())
+            .ok();
+        Ok(installed.map_or(created, |installed| installed.max(created)))
+    }
+
+    /// Remove the usage record for the archive with the given ID, if it exists.
+    pub(crate) fn forget_usage(&self, id: &OsStr) {
+        let path = self.bucket(CacheBucket::Usage).join(id);
+        trace!("Removing cache usage record: {}", path.display());
+        if let Err(err) = fs_err::remove_file(&path) {
+            if err.kind() != io::ErrorKind::NotFound {
+                debug!("Failed to remove cache usage record: {err}");
+            }
+        }
+    }
+}
+
+/// Set the modification time of the file at the given path, creating it if necessary.
+fn touch(path: &Path, time: SystemTime) -> Result<(), io::Error> {
+    let file = fs_err::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path)?;
+    file.file().set_modified(time)
+}
+
+/// Return the total number of bytes in the archive at the given path.
+///
+/// Like [`Cache::stats`], files with multiple hard links are only counted the first time they're
+/// seen.
+fn archive_size(path: &Path, seen: &mut FxHashSet<(u64, u64)>) -> Result<u64, io::Error> {
+    let mut total_bytes = 0;
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = entry?;
+        if entry.file_type().is_file() {
+            total_bytes += file_size(&entry.metadata()?, seen);
+        }
+    }
+    Ok(total_bytes)
+}
+
+/// Acquire the locks that guard the cache entries at the given paths, without waiting.
+///
+/// Returns `None` if any of the locks are held by another process.
+fn try_lock(links: &[PathBuf]) -> Result<Option<Vec<LockedFile>>, io::Error> {
+    let mut seen = FxHashSet::default();
+    let mut locks = Vec::new();
+    for link in links {
+        let Some(parent) = link.parent() else {
+            continue;
+        };
+        let Some(file_name) = link.file_name().and_then(OsStr::to_str) else {
+            continue;
+        };
+
+        // Entries are guarded by a lock alongside the entry itself (e.g., for unzipped wheels),
+        // or by a lock on the enclosing shard (e.g., for built wheels). Only consider locks that
+        // already exist, to avoid littering the cache with lock files.
+        for lock in [
+            parent.join(format!("{file_name}.lock")),
+            parent.join(".lock"),
+        ] {
+            if !lock.is_file() || !seen.insert(lock.clone()) {
+                continue;
+            }
+            match LockedFile::try_acquire(&lock, link.display())? {
+                Some(lock) => locks.push(lock),
+                None => return Ok(None),
+            }
+        }
+    }
+    Ok(Some(locks))
+}
@@ -2,6 +2,7 @@ use std::ffi::OsString;
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
 use anyhow::{anyhow, Result};
 use clap::builder::styling::{AnsiColor, Effects, Style};
@@ -45,6 +46,15 @@ pub enum PythonListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum CacheStatsFormat {
+    /// Plain text (for humans).
+    #[default]
+    Text,
+    /// JSON (for computers).
+    Json,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -472,6 +482,12 @@ pub enum Commands {
     Build(BuildArgs),
     /// Upload distributions to an index.
     Publish(PublishArgs),
+    /// Manage package indexes.
+    #[command(
+        after_help = "Use `uv help index` for more details.",
+        after_long_help = ""
+    )]
+    Index(IndexNamespace),
     /// The implementation of the build backend.
     ///
     /// These commands are not directly exposed to the user, instead users invoke their build
@@ -498,6 +514,12 @@ pub enum Commands {
     /// Generate shell completion
     #[command(alias = "--generate-shell-completion", hide = true)]
     GenerateShellCompletion(GenerateShellCompletionArgs),
+    /// Run the application contained in a bundle created by `uv bundle`.
+    ///
+    /// This command is not directly exposed to the user; instead, bundles re-enter uv with this
+    /// command when executed.
+    #[command(hide = true)]
+    BundleRun(BundleRunArgs),
     /// Display documentation for a command.
     // To avoid showing the global options when displaying help for the help command, we are
     // responsible for maintaining the options using the `after_help`.
@@ -608,6 +630,39 @@ pub enum CacheCommand {
     Clean(CleanArgs),
     /// Prune all unreachable objects from the cache.
     Prune(PruneArgs),
+    /// Show the size of the cache, broken down by bucket and package.
+    ///
+    /// For each cache bucket, shows the total size, the number of files, the time at which the
+    /// bucket was last accessed, and the packages with the largest footprint. Also shows the number
+    /// of archives that are not referenced by any other cache entry, which can be removed with
+    /// `uv cache prune`.
+    ///
+    /// Files that are hard-linked within the cache (e.g., into cached environments) are only
+    /// counted once.
+    Stats(StatsArgs),
+    /// Verify the integrity of the cache, removing any corrupt entries.
+    ///
+    /// Checks each unzipped wheel in the cache against the `RECORD` of the wheel from which it was
+    /// unzipped, and checks that each cached index response, metadata entry, and archive pointer
+    /// can be deserialized. Entries that fail verification are removed, along with any cache
+    /// entries that point to them, such that they're re-downloaded (or rebuilt) on next use; all
+    /// other entries are retained.
+    Verify(VerifyArgs),
+    /// Export the distributions referenced by a lockfile to a cache bundle.
+    ///
+    /// Writes every wheel and source distribution referenced by the lockfile, across all platforms,
+    /// to a portable `.tar.zst` archive that can be imported into another cache with
+    /// `uv cache import`, e.g., to run `uv sync --offline` on a machine without network access.
+    /// Any distributions that are missing from the cache are downloaded first.
+    ///
+    /// For packages without wheels, the wheel built from the source distribution for the current
+    /// platform is included too. Build dependencies are not included. Packages with Git, path, or
+    /// directory sources are omitted.
+    Export(CacheExportArgs),
+    /// Import a cache bundle created by `uv cache export` into the cache.
+    ///
+    /// Entries that already exist in the cache are retained.
+    Import(CacheImportArgs),
     /// Show the cache directory.
     ///
     ///
@@ -650,6 +705,84 @@ pub struct PruneArgs {
     /// that were built from source.
     #[arg(long)]
     pub ci: bool,
+
+    /// Evict the least recently used wheels from the cache until the unzipped wheels fit within
+    /// the given size (e.g., `20GiB`).
+    ///
+    /// uv records when each unzipped wheel in the cache was last used. Wheels are evicted in
+    /// least-recently-used order, along with the cache entries that point to them, until the
+    /// unzipped wheels are within the given size. Other cache entries (like source distributions
+    /// and index responses) are not evicted, and don't count towards the limit.
+    ///
+    /// Wheels that were used within the last ten minutes, or whose cache entries are locked by
+    /// another uv process, are never evicted.
+    ///
+    /// Accepts a number of bytes, with an optional unit: `KB`, `MB`, `GB`, and `TB` (powers of
+    /// 1000), or `KiB`, `MiB`, `GiB`, and `TiB` (powers of 1024).
+    #[arg(long, value_parser = parse_size)]
+    pub max_size: Option<u64>,
+
+    /// Evict any wheels from the cache that have not been used within the given duration (e.g.,
+    /// `30d`).
+    ///
+    /// Wheels are evicted along with the cache entries that point to them.
+    ///
+    /// Accepts a number followed by a unit: `s` (seconds), `m` (minutes), `h` (hours), `d`
+    /// (days), or `w` (weeks).
+    #[arg(long, value_parser = parse_duration)]
+    pub unused_for: Option<Duration>,
+}
+
+#[derive(Args, Debug)]
+pub struct StatsArgs {
+    /// The number of packages to show for each cache bucket.
+    #[arg(long, default_value_t = 10)]
+    pub top: usize,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = CacheStatsFormat::default())]
+    pub output_format: CacheStatsFormat,
+}
+
+#[derive(Args, Debug)]
+pub struct VerifyArgs {
+    /// Report any corrupt cache entries, but don't remove them.
+    ///
+    /// If any corrupt entries are found, uv will exit with a non-zero status.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheExportArgs {
+    /// The lockfile from which to export distributions.
+    #[arg(long, default_value = "uv.lock")]
+    pub lock: PathBuf,
+
+    /// The path to which the bundle should be written, e.g., `bundle.tar.zst`.
+    #[arg(long, short)]
+    pub output_file: PathBuf,
+
+    /// The Python interpreter to use to build source distributions.
+    ///
+    /// By default, the first interpreter that satisfies the lockfile's `requires-python` is used.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheImportArgs {
+    /// The path to the cache bundle.
+    pub bundle: PathBuf,
 }
 
 #[derive(Args)]
@@ -874,6 +1007,24 @@ pub enum ProjectCommand {
         after_long_help = ""
     )]
     Export(ExportArgs),
+    /// Bundle the project into a single-file executable.
+    ///
+    /// The bundle contains the wheels for the project and its locked dependencies, for one or more
+    /// target platforms, along with the entry point to invoke. The bundle does not require uv or
+    /// Python to be installed: on first run, the wheels are unpacked into the uv cache and
+    /// installed into a cached virtual environment, after which the entry point is invoked
+    /// directly.
+    ///
+    /// The project is re-locked before bundling unless the `--locked` or `--frozen` flag is
+    /// provided.
+    ///
+    /// uv will search for a project in the current directory or any parent directory. If a project
+    /// cannot be found, uv will exit with an error.
+    #[command(
+        after_help = "Use `uv help bundle` for more details.",
+        after_long_help = ""
+    )]
+    Bundle(BundleArgs),
     /// Display the project's dependency tree.
     Tree(TreeArgs),
 }
@@ -1035,6 +1186,66 @@ fn parse_maybe_string(input: &str) -> Result<Maybe<String>, String> {
     }
 }
 
+/// Parse a size in bytes, with an optional unit (e.g., `512MB` or `20GiB`).
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn parse_size(input: &str) -> Result<u64, String> {
+    let input = input.trim();
+    let split = input
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(input.len());
+    let (number, unit) = input.split_at(split);
+    let number = number.parse::<f64>().map_err(|_| {
+        format!("invalid size `{input}`; expected a number with an optional unit (e.g., `20GiB`)")
+    })?;
+    let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" => 1_000,
+        "m" | "mb" => 1_000_000,
+        "g" | "gb" => 1_000_000_000,
+        "t" | "tb" => 1_000_000_000_000,
+        "kib" => 1 << 10,
+        "mib" => 1 << 20,
+        "gib" => 1 << 30,
+        "tib" => 1 << 40,
+        _ => {
+            return Err(format!(
+                "invalid size unit `{}`; expected one of `B`, `KB`, `MB`, `GB`, `TB`, `KiB`, `MiB`, `GiB`, or `TiB`",
+                unit.trim()
+            ))
+        }
+    };
+    Ok((number * multiplier as f64) as u64)
+}
+
+/// Parse a duration, as a number followed by a unit (e.g., `12h` or `30d`).
+fn parse_duration(input: &str) -> Result<Duration, String> {
+    let input = input.trim();
+    let split = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+    let (number, unit) = input.split_at(split);
+    let number = number.parse::<u64>().map_err(|_| {
+        format!("invalid duration `{input}`; expected a number with a unit (e.g., `30d`)")
+    })?;
+    let seconds = match unit.trim() {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        unit => {
+            return Err(format!(
+                "invalid duration unit `{unit}`; expected one of `s`, `m`, `h`, `d`, or `w`"
+            ))
+        }
+    };
+    Ok(Duration::from_secs(number.saturating_mul(seconds)))
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
@@ -3234,6 +3445,19 @@ pub struct SyncArgs {
     #[arg(long, overrides_with = "active", hide = true)]
     pub no_active: bool,
 
+    /// Install the project and its dependencies into the specified directory, rather than into
+    /// the project's virtual environment.
+    ///
+    /// The packages will be installed at the top-level of the directory, as with `uv pip install
+    /// --target`. This is useful for deployments that expect a flat directory of packages, rather
+    /// than a virtual environment (e.g., AWS Lambda). The project's virtual environment will not be
+    /// created or modified.
+    ///
+    /// Workspace members are always installed as non-editable. Any entry points are written to the
+    /// `bin` subdirectory. Use `--compile-bytecode` to compile the installed packages to bytecode.
+    #[arg(long, conflicts_with = "active")]
+    pub target: Option<PathBuf>,
+
     /// Do not install the current project.
     ///
     /// By default, the current project is installed into the environment with all of its
@@ -3333,7 +3557,8 @@ pub struct SyncArgs {
         conflicts_with = "no_group",
         conflicts_with = "no_default_groups",
         conflicts_with = "only_group",
-        conflicts_with = "all_groups"
+        conflicts_with = "all_groups",
+        conflicts_with = "target"
     )]
     pub script: Option<PathBuf>,
 
@@ -3399,6 +3624,37 @@ pub struct LockArgs {
     #[arg(long)]
     pub script: Option<PathBuf>,
 
+    /// Import the pinned versions from a PEP 751 `pylock.toml` file.
+    ///
+    /// The versions pinned in the `pylock.toml` file will be used as preferences when resolving
+    /// the project's dependencies, in lieu of those in the existing `uv.lock`. Pinned versions that
+    /// are incompatible with the project's requirements will be re-resolved.
+    ///
+    /// uv will report which of the pinned versions were kept, and which were re-resolved.
+    #[arg(long, conflicts_with_all = ["check", "check_exists"], value_parser = parse_file_path)]
+    pub from_pylock: Option<PathBuf>,
+
+    /// Report the wheel coverage of each locked package across the declared environments.
+    ///
+    /// For each package, uv will list which of the environments declared in
+    /// `tool.uv.environments` and `tool.uv.required-environments` are covered by a locked wheel,
+    /// and which will fall back to building the package from its source distribution.
+    ///
+    /// Coverage is determined by the platform, Python, and ABI tags of the wheels recorded in the
+    /// lockfile, across the Python versions allowed by `requires-python`.
+    #[arg(long)]
+    pub wheel_coverage: bool,
+
+    /// Fail if any locked package lacks a wheel for any of the declared environments.
+    ///
+    /// When a package would fall back to its source distribution in any of the environments
+    /// declared in `tool.uv.environments` or `tool.uv.required-environments`, uv will exit with an
+    /// error, without writing the lockfile.
+    ///
+    /// At least one environment must be declared.
+    #[arg(long)]
+    pub require_wheel_coverage: bool,
+
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
@@ -3691,6 +3947,12 @@ pub struct TreeArgs {
     #[command(flatten)]
     pub tree: DisplayTreeArgs,
 
+    /// Show the status of each package's project on its index (e.g., archived or deprecated).
+    ///
+    /// Project statuses are published by the package index, per PEP 792.
+    #[arg(long)]
+    pub status: bool,
+
     /// Include the development dependency group.
     ///
     /// Development dependencies are defined via `dependency-groups.dev` or
@@ -4031,6 +4293,106 @@ pub struct ExportArgs {
     pub python: Option<Maybe<String>>,
 }
 
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct BundleArgs {
+    /// The path to which the bundle should be written.
+    ///
+    /// Defaults to `dist/<name>` in the project root, with an `.exe` extension on Windows.
+    #[arg(long, short)]
+    pub output_file: Option<PathBuf>,
+
+    /// The console script to invoke when the bundle is run.
+    ///
+    /// Must be one of the scripts declared in the project's `[project.scripts]` table. If the
+    /// project declares exactly one script, it is used by default.
+    #[arg(long)]
+    pub entry_point: Option<String>,
+
+    /// Bundle a specific package in the workspace.
+    ///
+    /// If the workspace member does not exist, uv will exit with an error.
+    #[arg(long)]
+    pub package: Option<PackageName>,
+
+    /// The platforms for which wheels should be included in the bundle.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// May be provided multiple times. When the bundle is run, the first platform whose wheels are
+    /// compatible with the discovered interpreter is installed. Source distributions can only be
+    /// built for the current platform.
+    ///
+    /// Defaults to the current platform.
+    #[arg(long)]
+    pub python_platform: Vec<TargetTriple>,
+
+    /// Record a managed Python download for each platform in the bundle.
+    ///
+    /// When the bundle is run on a machine without a compatible Python interpreter, the recorded
+    /// managed Python build is downloaded and used, rather than the latest matching build.
+    #[arg(long)]
+    pub python_download: bool,
+
+    /// The uv executable to use as the bundle launcher.
+    ///
+    /// The bundle is executable on the same platform as its launcher. Defaults to the current uv
+    /// executable; provide a uv executable built for another platform to produce a bundle for that
+    /// platform.
+    #[arg(long, value_parser = parse_file_path)]
+    pub launcher: Option<PathBuf>,
+
+    /// Assert that the `uv.lock` will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or needs to be updated,
+    /// uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["frozen", "upgrade"])]
+    pub locked: bool,
+
+    /// Do not update the `uv.lock` before bundling.
+    ///
+    /// If a `uv.lock` does not exist, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["locked", "upgrade", "no_sources"])]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use when building the bundle.
+    ///
+    /// The bundle targets the Python minor version of this interpreter. The interpreter is also
+    /// used to build any source distributions.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+pub struct BundleRunArgs {
+    /// The path to the bundle executable.
+    pub bundle: PathBuf,
+
+    /// The arguments to pass to the bundled entry point.
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+    pub args: Vec<OsString>,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct ToolNamespace {
@@ -4097,8 +4459,25 @@ pub enum ToolCommand {
     /// List installed tools.
     #[command(alias = "ls")]
     List(ToolListArgs),
+    /// Export the installed tools to a manifest.
+    ///
+    /// The manifest is written to stdout in TOML format, and includes the requirements, Python
+    /// version, and settings of each tool, along with the locked resolution of each tool, if
+    /// recorded in its receipt.
+    ///
+    /// The manifest can be installed with `uv tool install --from-manifest`.
+    Export(ToolExportArgs),
     /// Uninstall a tool.
     Uninstall(ToolUninstallArgs),
+    /// Check installed tools for problems.
+    ///
+    /// Detects tool environments whose Python interpreter no longer exists, executables that are
+    /// missing or link to a file that no longer exists, receipts that are out of sync with the
+    /// tool environment, and executables that are owned by another tool or shadowed by another
+    /// executable on the `PATH`.
+    ///
+    /// Use `--fix` to reinstall the affected tools from their receipts.
+    Doctor(ToolDoctorArgs),
     /// Ensure that the tool executable directory is on the `PATH`.
     ///
     /// If the tool executable directory is not present on the `PATH`, uv will attempt to add it to
@@ -4247,7 +4626,8 @@ pub struct UvxArgs {
 #[allow(clippy::struct_excessive_bools)]
 pub struct ToolInstallArgs {
     /// The package to install commands from.
-    pub package: String,
+    #[arg(required_unless_present = "from_manifest")]
+    pub package: Option<String>,
 
     /// The package to install commands from.
     ///
@@ -4255,6 +4635,32 @@ pub struct ToolInstallArgs {
     #[arg(long, hide = true)]
     pub from: Option<String>,
 
+    /// Install the tools listed in the given manifest, as produced by `uv tool export`.
+    ///
+    /// Each tool is installed with the requirements, Python version, and settings recorded in the
+    /// manifest, concurrently. Tools that are already installed with the same requirements are left
+    /// unchanged.
+    ///
+    /// If `--locked` or `--frozen` is provided, each tool is installed from the lock recorded in the
+    /// manifest, without re-resolving.
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = [
+            "package",
+            "from",
+            "with",
+            "with_requirements",
+            "editable",
+            "with_editable",
+            "constraints",
+            "overrides",
+            "build_constraints",
+            "python",
+        ]
+    )]
+    pub from_manifest: Option<PathBuf>,
+
     /// Include the following additional requirements.
     #[arg(long)]
     pub with: Vec<comma::CommaSeparatedRequirements>,
@@ -4318,6 +4724,27 @@ pub struct ToolInstallArgs {
     #[arg(long)]
     pub force: bool,
 
+    /// Install the tool from the lock recorded in its receipt, asserting that the lock is
+    /// up-to-date.
+    ///
+    /// Requires that the tool is already installed with a lock, that the requested requirements
+    /// match those recorded in the receipt, and that re-resolving them yields the locked versions.
+    /// If the lock is missing or needs to be updated, uv will exit with an error.
+    ///
+    /// Only available in preview mode.
+    #[arg(long, conflicts_with_all = ["frozen", "upgrade"])]
+    pub locked: bool,
+
+    /// Install the tool from the lock recorded in its receipt, without checking whether the lock
+    /// is up-to-date.
+    ///
+    /// The requirements recorded in the receipt are retained, and the requested requirements are
+    /// only used to identify the tool. If the lock is missing, uv will exit with an error.
+    ///
+    /// Only available in preview mode.
+    #[arg(long, conflicts_with_all = ["locked", "upgrade"])]
+    pub frozen: bool,
+
     /// The Python interpreter to use to build the tool environment.
     ///
     /// See `uv help python` for details on Python discovery and supported request formats.
@@ -4332,6 +4759,13 @@ pub struct ToolInstallArgs {
     pub python: Option<Maybe<String>>,
 }
 
+#[derive(Args)]
+pub struct ToolExportArgs {
+    /// Omit the locked resolution of each tool from the manifest.
+    #[arg(long)]
+    pub no_lock: bool,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct ToolListArgs {
@@ -4386,6 +4820,21 @@ pub struct ToolUninstallArgs {
     pub all: bool,
 }
 
+#[derive(Args)]
+pub struct ToolDoctorArgs {
+    /// The name of the tools to check.
+    ///
+    /// If omitted, all installed tools are checked.
+    pub name: Vec<PackageName>,
+
+    /// Repair any problems by reinstalling the affected tools from their receipts.
+    ///
+    /// Tools with executables that are owned by another tool are not repaired, as reinstalling them
+    /// would overwrite the other tool's executables.
+    #[arg(long)]
+    pub fix: bool,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct ToolUpgradeArgs {
@@ -4659,6 +5108,22 @@ pub enum PythonCommand {
     /// See `uv help python` to view supported request formats.
     Install(PythonInstallArgs),
 
+    /// Upgrade installed Python versions to the latest patch release.
+    ///
+    /// For each minor version of a managed Python installation, e.g., Python 3.12, uv will install
+    /// the newest available patch release, e.g., Python 3.12.9.
+    ///
+    /// Virtual environments and tool environments that use a superseded patch release are updated
+    /// to use the latest patch release instead. Tool environments are always updated; other
+    /// virtual environments can be provided with `--venv`, in addition to the virtual environment
+    /// of the current project, if any.
+    ///
+    /// By default, superseded patch releases are left installed. Use `--remove-superseded` to
+    /// uninstall them.
+    ///
+    /// Only managed Python versions can be upgraded.
+    Upgrade(PythonUpgradeArgs),
+
     /// Search for a Python installation.
     ///
     /// Displays the path to the Python executable.
@@ -4675,6 +5140,10 @@ pub enum PythonCommand {
     /// the currently pinned version. If no `.python-version` file is found, uv will exit with an
     /// error.
     ///
+    /// If the current directory does not have a `.python-version` file, but has a
+    /// `.tool-versions`, `mise.toml`, or `runtime.txt` file that declares a Python version, that
+    /// file is updated instead.
+    ///
     /// See `uv help python` to view supported request formats.
     Pin(PythonPinArgs),
 
@@ -4690,10 +5159,65 @@ pub enum PythonCommand {
     /// Note that Python executables are only installed when preview mode is enabled.
     Dir(PythonDirArgs),
 
+    /// Manage `python` shims for managed Python versions.
+    ///
+    /// Shims are executables named `python`, `python3`, and `python3.12` (for each installed minor
+    /// version) that select a Python version on each invocation, respecting the nearest
+    /// `.python-version` file. If the selected Python version is not installed, it will be
+    /// downloaded when automatic Python downloads are enabled.
+    ///
+    /// The `python` and `python3` shims use the pinned Python version, if any. Shims for a specific
+    /// minor version, e.g., `python3.12`, always use that minor version.
+    ///
+    /// Shims are only created on request, with `uv python shim rebuild`. Once created, shims are
+    /// kept up-to-date by `uv python install`. To use the shims, add the shim directory to the
+    /// `PATH`.
+    ///
+    /// By default, shims are stored in a `python-shims` directory next to the directory Python
+    /// executables are installed to, e.g., `$HOME/.local/python-shims` when executables are
+    /// installed to `$HOME/.local/bin`. On Windows, shims are trampoline executables.
+    ///
+    /// The shim directory may be overridden with `$UV_PYTHON_SHIM_DIR`.
+    Shim(PythonShimNamespace),
+
     /// Uninstall Python versions.
     Uninstall(PythonUninstallArgs),
 }
 
+#[derive(Args)]
+pub struct PythonShimNamespace {
+    #[command(subcommand)]
+    pub command: PythonShimCommand,
+}
+
+#[derive(Subcommand)]
+pub enum PythonShimCommand {
+    /// List the Python shims.
+    #[command(alias = "ls")]
+    List,
+
+    /// Create or rebuild the Python shims.
+    ///
+    /// Creates the `python` and `python3` shims, along with a shim for each minor version of the
+    /// installed managed Python versions. Stale shims, e.g., for uninstalled Python versions, are
+    /// removed.
+    Rebuild,
+
+    /// Execute the Python version selected by the given shim.
+    #[command(hide = true)]
+    Exec(PythonShimExecArgs),
+}
+
+#[derive(Args)]
+pub struct PythonShimExecArgs {
+    /// The name of the shim, e.g., `python3.12`.
+    pub name: String,
+
+    /// The arguments to pass to the Python interpreter.
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+    pub args: Vec<OsString>,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PythonListArgs {
@@ -4787,6 +5311,10 @@ pub struct PythonInstallArgs {
     /// above are present, uv will check if it has installed any Python versions. If not, it will
     /// install the latest stable version of Python.
     ///
+    /// A path to a `python-build-standalone` archive may also be provided, e.g.,
+    /// `./cpython-3.12.4+20240713-x86_64-unknown-linux-gnu-install_only.tar.gz`. The archive must
+    /// match a known Python download and is verified against its checksum.
+    ///
     /// See `uv help python` to view supported request formats.
     #[arg(env = EnvVars::UV_PYTHON)]
     pub targets: Vec<String>,
@@ -4816,6 +5344,16 @@ pub struct PythonInstallArgs {
     #[arg(long, env = EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL)]
     pub python_downloads_json_url: Option<String>,
 
+    /// Install Python distributions from a local directory of archives, instead of downloading
+    /// them.
+    ///
+    /// The directory is expected to contain the distribution archives under their published file
+    /// names, e.g., `cpython-3.12.4+20240713-x86_64-unknown-linux-gnu-install_only.tar.gz`, without
+    /// the upstream release layout. Each archive is verified against the checksum of the matching
+    /// download. Use `--python-downloads-json-url` to provide the list of available downloads.
+    #[arg(long, value_name = "DIR")]
+    pub archive_dir: Option<PathBuf>,
+
     /// Reinstall the requested Python version, if it's already installed.
     ///
     /// By default, uv will exit successfully if the version is already
@@ -4846,6 +5384,58 @@ pub struct PythonInstallArgs {
     pub default: bool,
 }
 
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PythonUpgradeArgs {
+    /// The directory where the Python versions are installed.
+    #[arg(long, short, env = EnvVars::UV_PYTHON_INSTALL_DIR)]
+    pub install_dir: Option<PathBuf>,
+
+    /// The Python minor version(s) to upgrade.
+    ///
+    /// If not provided, all installed minor versions will be upgraded.
+    ///
+    /// See `uv help python` to view supported request formats.
+    pub targets: Vec<String>,
+
+    /// Uninstall the patch releases that were superseded by the upgrade.
+    ///
+    /// By default, superseded patch releases are left installed.
+    #[arg(long)]
+    pub remove_superseded: bool,
+
+    /// Additional virtual environments to update to the latest patch release.
+    ///
+    /// Tool environments and the virtual environment of the current project are always updated.
+    #[arg(long, value_name = "PATH")]
+    pub venv: Vec<PathBuf>,
+
+    /// Set the URL to use as the source for downloading Python installations.
+    ///
+    /// The provided URL will replace
+    /// `https://github.com/astral-sh/python-build-standalone/releases/download` in, e.g.,
+    /// `https://github.com/astral-sh/python-build-standalone/releases/download/20240713/cpython-3.12.4%2B20240713-aarch64-apple-darwin-install_only.tar.gz`.
+    ///
+    /// Distributions can be read from a local directory by using the `file://` URL scheme.
+    #[arg(long, env = EnvVars::UV_PYTHON_INSTALL_MIRROR)]
+    pub mirror: Option<String>,
+
+    /// Set the URL to use as the source for downloading PyPy installations.
+    ///
+    /// The provided URL will replace `https://downloads.python.org/pypy` in, e.g.,
+    /// `https://downloads.python.org/pypy/pypy3.8-v7.3.7-osx64.tar.bz2`.
+    ///
+    /// Distributions can be read from a local directory by using the `file://` URL scheme.
+    #[arg(long, env = EnvVars::UV_PYPY_INSTALL_MIRROR)]
+    pub pypy_mirror: Option<String>,
+
+    /// URL pointing to JSON of custom Python installations.
+    ///
+    /// Note that currently, only local paths are supported.
+    #[arg(long, env = EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL)]
+    pub python_downloads_json_url: Option<String>,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PythonUninstallArgs {
@@ -5018,6 +5608,28 @@ pub struct IndexArgs {
     #[arg(long, env = EnvVars::UV_INDEX, value_parser = parse_indices, help_heading = "Index options")]
     pub index: Option<Vec<Vec<Maybe<Index>>>>,
 
+    /// The path to a PEM-encoded client certificate to present to the indexes provided on the
+    /// command line, for mutual TLS.
+    ///
+    /// The file may also contain the private key; otherwise, provide it via `--index-client-key`.
+    /// The certificate is only presented to the hosts of the indexes provided via `--index`,
+    /// `--default-index`, `--index-url`, and `--extra-index-url`. Indexes defined in configuration
+    /// files use their own `client-cert` setting instead.
+    #[arg(long, help_heading = "Index options")]
+    pub index_client_cert: Option<PathBuf>,
+
+    /// The path to the PEM-encoded private key for `--index-client-cert`.
+    #[arg(long, help_heading = "Index options")]
+    pub index_client_key: Option<PathBuf>,
+
+    /// The path to a PEM-encoded bundle of certificate authorities to trust for the indexes
+    /// provided on the command line, in addition to the system or bundled roots.
+    ///
+    /// Only applies to the hosts of the indexes provided via `--index`, `--default-index`,
+    /// `--index-url`, and `--extra-index-url`.
+    #[arg(long, help_heading = "Index options")]
+    pub index_ca_bundle: Option<PathBuf>,
+
     /// The URL of the default package index (by default: <https://pypi.org/simple>).
     ///
     /// Accepts either a repository compliant with PEP 503 (the simple repository API), or a local
@@ -5856,10 +6468,91 @@ pub struct PublishArgs {
     #[arg(long, env = EnvVars::UV_PUBLISH_CHECK_URL)]
     pub check_url: Option<IndexUrl>,
 
+    /// The path to a PEM-encoded client certificate to present to the upload endpoint, for mutual
+    /// TLS.
+    ///
+    /// The file may also contain the private key; otherwise, provide it via `--client-key`. The
+    /// certificate is only presented to the hosts of the upload endpoint and of `--check-url`.
+    ///
+    /// When publishing with `--index`, defaults to the index's `client-cert` setting.
+    #[arg(long)]
+    pub client_cert: Option<PathBuf>,
+
+    /// The path to the PEM-encoded private key for `--client-cert`.
+    ///
+    /// When publishing with `--index`, defaults to the index's `client-key` setting.
+    #[arg(long)]
+    pub client_key: Option<PathBuf>,
+
+    /// The path to a PEM-encoded bundle of certificate authorities to trust for the upload
+    /// endpoint, in addition to the system or bundled roots.
+    ///
+    /// Only applies to the hosts of the upload endpoint and of `--check-url`.
+    ///
+    /// When publishing with `--index`, defaults to the index's `ca-bundle` setting.
+    #[arg(long)]
+    pub ca_bundle: Option<PathBuf>,
+
     #[arg(long, hide = true)]
     pub skip_existing: bool,
 }
 
+#[derive(Args)]
+pub struct IndexNamespace {
+    #[command(subcommand)]
+    pub command: IndexCommand,
+}
+
+#[derive(Subcommand)]
+pub enum IndexCommand {
+    /// Serve a directory of distributions, or the wheels in the cache, as a local package index.
+    ///
+    /// Starts an HTTP server that implements the simple repository API, serving both HTML (PEP 503)
+    /// and JSON (PEP 691) project pages, along with the hashes and core metadata (PEP 658) of each
+    /// wheel. The index URL is displayed on startup, e.g., `http://127.0.0.1:8000/simple/`.
+    ///
+    /// With `--upload`, the server also accepts uploads to the same URL, such that
+    /// `uv publish --publish-url` can target it.
+    ///
+    /// The server runs until interrupted.
+    Serve(IndexServeArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct IndexServeArgs {
+    /// Serve the wheels and source distributions in the given directory.
+    ///
+    /// Distributions are grouped into projects by their normalized package name. Subdirectories
+    /// are not searched.
+    #[arg(long, conflicts_with = "cache", required_unless_present = "cache")]
+    pub dir: Option<PathBuf>,
+
+    /// Serve the wheels in the cache.
+    ///
+    /// Includes wheels that were downloaded from an index, or built from source, by a previous uv
+    /// command. Downloaded wheels are stored unzipped in the cache, and so are re-archived when first
+    /// requested; as such, their hashes won't match those of the original wheel.
+    #[arg(long)]
+    pub cache: bool,
+
+    /// The address on which to listen.
+    #[arg(long, default_value = "127.0.0.1")]
+    pub host: String,
+
+    /// The port on which to listen.
+    ///
+    /// If `0`, an available port is chosen at random.
+    #[arg(long, default_value_t = 8000)]
+    pub port: u16,
+
+    /// Accept uploads of wheels and source distributions into the served directory.
+    ///
+    /// Uploads use the legacy upload API supported by `uv publish` and `twine`, and are not
+    /// authenticated. Uploading a file that already exists with different contents is an error.
+    #[arg(long, conflicts_with = "cache")]
+    pub upload: bool,
+}
+
 /// See [PEP 517](https://peps.python.org/pep-0517/) and
 /// [PEP 660](https://peps.python.org/pep-0660/) for specifications of the parameters.
 #[derive(Subcommand)]
@@ -1,5 +1,8 @@
+use std::path::PathBuf;
+
 use uv_cache::Refresh;
 use uv_configuration::ConfigSettings;
+use uv_distribution_types::{Index, PipExtraIndex, PipIndex};
 use uv_resolver::PrereleaseMode;
 use uv_settings::{Combine, PipOptions, ResolverInstallerOptions, ResolverOptions};
 
@@ -18,6 +21,21 @@ pub fn flag(yes: bool, no: bool) -> Option<bool> {
     }
 }
 
+/// Apply the TLS settings provided via `--index-client-cert`, `--index-client-key`, and
+/// `--index-ca-bundle` to an index provided on the command line.
+fn index_tls(
+    client_cert: Option<PathBuf>,
+    client_key: Option<PathBuf>,
+    ca_bundle: Option<PathBuf>,
+) -> impl Fn(Index) -> Index {
+    move |index| Index {
+        client_cert: client_cert.clone().or(index.client_cert),
+        client_key: client_key.clone().or(index.client_key),
+        ca_bundle: ca_bundle.clone().or(index.ca_bundle),
+        ..index
+    }
+}
+
 impl From<RefreshArgs> for Refresh {
     fn from(value: RefreshArgs) -> Self {
         let RefreshArgs {
@@ -189,28 +207,37 @@ impl From<IndexArgs> for PipOptions {
         let IndexArgs {
             default_index,
             index,
+            index_client_cert,
+            index_client_key,
+            index_ca_bundle,
             index_url,
             extra_index_url,
             no_index,
             find_links,
         } = args;
 
+        let tls = index_tls(index_client_cert, index_client_key, index_ca_bundle);
+
         Self {
             index: default_index
                 .and_then(Maybe::into_option)
-                .map(|default_index| vec![default_index])
+                .map(|default_index| vec![tls(default_index)])
                 .combine(index.map(|index| {
                     index
                         .iter()
                         .flat_map(std::clone::Clone::clone)
                         .filter_map(Maybe::into_option)
+                        .map(&tls)
                         .collect()
                 })),
-            index_url: index_url.and_then(Maybe::into_option),
+            index_url: index_url
+                .and_then(Maybe::into_option)
+                .map(|index_url| PipIndex::from(tls(Index::from(index_url)))),
             extra_index_url: extra_index_url.map(|extra_index_urls| {
                 extra_index_urls
                     .into_iter()
                     .filter_map(Maybe::into_option)
+                    .map(|extra_index_url| PipExtraIndex::from(tls(Index::from(extra_index_url))))
                     .collect()
             }),
             no_index: if no_index { Some(true) } else { None },
@@ -259,23 +286,34 @@ pub fn resolver_options(
         no_binary_package,
     } = build_args;
 
+    let tls = index_tls(
+        index_args.index_client_cert,
+        index_args.index_client_key,
+        index_args.index_ca_bundle,
+    );
+
     ResolverOptions {
         index: index_args
             .default_index
             .and_then(Maybe::into_option)
-            .map(|default_index| vec![default_index])
+            .map(|default_index| vec![tls(default_index)])
             .combine(index_args.index.map(|index| {
                 index
                     .into_iter()
                     .flat_map(|v| v.clone())
                     .filter_map(Maybe::into_option)
+                    .map(&tls)
                     .collect()
             })),
-        index_url: index_args.index_url.and_then(Maybe::into_option),
+        index_url: index_args
+            .index_url
+            .and_then(Maybe::into_option)
+            .map(|index_url| PipIndex::from(tls(Index::from(index_url)))),
         extra_index_url: index_args.extra_index_url.map(|extra_index_url| {
             extra_index_url
                 .into_iter()
                 .filter_map(Maybe::into_option)
+                .map(|extra_index_url| PipExtraIndex::from(tls(Index::from(extra_index_url))))
                 .collect()
         }),
         no_index: if index_args.no_index {
@@ -354,25 +392,35 @@ pub fn resolver_installer_options(
         no_binary_package,
     } = build_args;
 
+    let tls = index_tls(
+        index_args.index_client_cert,
+        index_args.index_client_key,
+        index_args.index_ca_bundle,
+    );
     let default_index = index_args
         .default_index
         .and_then(Maybe::into_option)
-        .map(|default_index| vec![default_index]);
+        .map(|default_index| vec![tls(default_index)]);
     let index = index_args.index.map(|index| {
         index
             .into_iter()
             .flat_map(|v| v.clone())
             .filter_map(Maybe::into_option)
+            .map(&tls)
             .collect()
     });
 
     ResolverInstallerOptions {
         index: default_index.combine(index),
-        index_url: index_args.index_url.and_then(Maybe::into_option),
+        index_url: index_args
+            .index_url
+            .and_then(Maybe::into_option)
+            .map(|index_url| PipIndex::from(tls(Index::from(index_url)))),
         extra_index_url: index_args.extra_index_url.map(|extra_index_url| {
             extra_index_url
                 .into_iter()
                 .filter_map(Maybe::into_option)
+                .map(|extra_index_url| PipExtraIndex::from(tls(Index::from(extra_index_url))))
                 .collect()
         }),
         no_index: if index_args.no_index {
@@ -26,8 +26,11 @@ use uv_version::version;
 use uv_warnings::warn_user_once;
 
 use crate::linehaul::LineHaul;
-use crate::middleware::OfflineMiddleware;
-use crate::tls::read_identity;
+use crate::middleware::{IndexMirrors, MirrorMiddleware, OfflineMiddleware};
+use crate::tls::{
+    configure_host_tls, read_identity, HostTls, HostTlsError, HostTlsErrorMiddleware,
+    HostTlsMaterial,
+};
 use crate::Connectivity;
 
 pub const DEFAULT_RETRIES: u32 = 3;
@@ -57,6 +60,8 @@ pub struct BaseClientBuilder<'a> {
     platform: Option<&'a Platform>,
     auth_integration: AuthIntegration,
     indexes: Indexes,
+    index_mirrors: IndexMirrors,
+    host_tls: Vec<HostTls>,
     default_timeout: Duration,
     extra_middleware: Option<ExtraMiddleware>,
     proxies: Vec<Proxy>,
@@ -92,6 +97,8 @@ impl BaseClientBuilder<'_> {
             platform: None,
             auth_integration: AuthIntegration::default(),
             indexes: Indexes::new(),
+            index_mirrors: IndexMirrors::default(),
+            host_tls: vec![],
             default_timeout: Duration::from_secs(30),
             extra_middleware: None,
             proxies: vec![],
@@ -154,6 +161,22 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub(crate) fn index_mirrors(mut self, index_mirrors: IndexMirrors) -> Self {
+        self.index_mirrors = index_mirrors;
+        self
+    }
+
+    /// Add TLS settings (e.g., a client certificate) that apply only to requests to a given host.
+    ///
+    /// If multiple settings apply to the same host, the first takes precedence, and a warning is
+    /// shown if the settings differ.
+    #[must_use]
+    pub fn host_tls(mut self, host_tls: impl IntoIterator<Item = HostTls>) -> Self {
+        self.host_tls.extend(host_tls);
+        self
+    }
+
     #[must_use]
     pub fn default_timeout(mut self, default_timeout: Duration) -> Self {
         self.default_timeout = default_timeout;
@@ -228,6 +251,7 @@ impl<'a> BaseClientBuilder<'a> {
             timeout,
             ssl_cert_file_exists,
             Security::Secure,
+            None,
         );
 
         // Create an insecure client that accepts invalid certificates.
@@ -236,48 +260,182 @@ impl<'a> BaseClientBuilder<'a> {
             timeout,
             ssl_cert_file_exists,
             Security::Insecure,
+            None,
         );
 
-        // Wrap in any relevant middleware and handle connectivity.
-        let client = self.apply_middleware(raw_client.clone());
-        let dangerous_client = self.apply_middleware(raw_dangerous_client.clone());
+        // Create a dedicated pair of clients for each host with its own TLS settings.
+        let mut raw_host_clients: Vec<RawHostClient> = Vec::new();
+        for host_tls in &self.host_tls {
+            let host = host_tls.url().host_str().unwrap_or_default();
+            if let Some(existing) = raw_host_clients
+                .iter()
+                .find(|existing| existing.tls.matches(host_tls.url()))
+            {
+                if !existing.tls.same_settings(host_tls) {
+                    warn_user_once!(
+                        "Ignoring conflicting TLS settings for `{host}`; using the settings of the first index on the host"
+                    );
+                }
+                continue;
+            }
+            debug!("Using dedicated TLS settings for: {host}");
+
+            // If the settings can't be read, requests to the host will fail; in the meantime, use
+            // the default settings for the clients.
+            let (material, error) = match host_tls.load() {
+                Ok(material) => (Some(material), None),
+                Err(err) => (None, Some(Arc::new(err))),
+            };
+            let raw_client = self.create_client(
+                &user_agent_string,
+                timeout,
+                ssl_cert_file_exists,
+                Security::Secure,
+                material.as_ref(),
+            );
+            let raw_dangerous_client = self.create_client(
+                &user_agent_string,
+                timeout,
+                ssl_cert_file_exists,
+                Security::Insecure,
+                material.as_ref(),
+            );
+            raw_host_clients.push(RawHostClient {
+                tls: host_tls.clone(),
+                error,
+                raw_client,
+                raw_dangerous_client,
+            });
+        }
 
-        BaseClient {
-            connectivity: self.connectivity,
-            allow_insecure_host: self.allow_insecure_host.clone(),
-            retries: self.retries,
-            client,
+        self.wrap(raw_client, raw_dangerous_client, &raw_host_clients, timeout)
+    }
+
+    /// Share the underlying client between two different middleware configurations.
+    pub fn wrap_existing(&self, existing: &BaseClient) -> BaseClient {
+        let raw_host_clients = existing
+            .host_clients
+            .iter()
+            .map(|host_client| RawHostClient {
+                tls: host_client.tls.clone(),
+                error: host_client.error.clone(),
+                raw_client: host_client.raw_client.clone(),
+                raw_dangerous_client: host_client.raw_dangerous_client.clone(),
+            })
+            .collect::<Vec<_>>();
+        self.wrap(
+            existing.raw_client.clone(),
+            existing.raw_dangerous_client.clone(),
+            &raw_host_clients,
+            existing.timeout,
+        )
+    }
+
+    /// Wrap the underlying clients in any relevant middleware and handle connectivity.
+    ///
+    /// If any index defines mirrors, requests that fail over to a mirror are re-dispatched to the
+    /// client for the mirror's host, such that each endpoint is only ever sent its own TLS
+    /// settings (e.g., client certificate).
+    fn wrap(
+        &self,
+        raw_client: Client,
+        raw_dangerous_client: Client,
+        raw_host_clients: &[RawHostClient],
+        timeout: Duration,
+    ) -> BaseClient {
+        let endpoints = self.wrap_with_mirrors(
+            raw_client.clone(),
+            raw_dangerous_client.clone(),
+            raw_host_clients,
+            timeout,
+            None,
+        );
+        if self.index_mirrors.is_empty() || matches!(self.connectivity, Connectivity::Offline) {
+            return endpoints;
+        }
+        self.wrap_with_mirrors(
             raw_client,
-            dangerous_client,
             raw_dangerous_client,
+            raw_host_clients,
             timeout,
-        }
+            Some(&Arc::new(endpoints)),
+        )
     }
 
-    /// Share the underlying client between two different middleware configurations.
-    pub fn wrap_existing(&self, existing: &BaseClient) -> BaseClient {
-        // Wrap in any relevant middleware and handle connectivity.
-        let client = self.apply_middleware(existing.raw_client.clone());
-        let dangerous_client = self.apply_middleware(existing.raw_dangerous_client.clone());
+    /// Wrap the underlying clients in any relevant middleware, failing over to index mirrors via
+    /// the given clients, if any.
+    fn wrap_with_mirrors(
+        &self,
+        raw_client: Client,
+        raw_dangerous_client: Client,
+        raw_host_clients: &[RawHostClient],
+        timeout: Duration,
+        endpoints: Option<&Arc<BaseClient>>,
+    ) -> BaseClient {
+        let client = self.apply_middleware(raw_client.clone(), endpoints);
+        let dangerous_client = self.apply_middleware(raw_dangerous_client.clone(), endpoints);
+        let host_clients = self.apply_host_middleware(raw_host_clients, endpoints);
 
         BaseClient {
             connectivity: self.connectivity,
             allow_insecure_host: self.allow_insecure_host.clone(),
             retries: self.retries,
             client,
+            raw_client,
             dangerous_client,
-            raw_client: existing.raw_client.clone(),
-            raw_dangerous_client: existing.raw_dangerous_client.clone(),
-            timeout: existing.timeout,
+            raw_dangerous_client,
+            host_clients,
+            timeout,
         }
     }
 
+    /// Wrap the clients for each host with dedicated TLS settings in any relevant middleware.
+    ///
+    /// If the TLS settings for a host couldn't be read, every request to the host fails instead.
+    fn apply_host_middleware(
+        &self,
+        raw_host_clients: &[RawHostClient],
+        endpoints: Option<&Arc<BaseClient>>,
+    ) -> Vec<HostClient> {
+        raw_host_clients
+            .iter()
+            .map(|raw_host_client| {
+                let RawHostClient {
+                    tls,
+                    error,
+                    raw_client,
+                    raw_dangerous_client,
+                } = raw_host_client;
+                let (client, dangerous_client) = if let Some(error) = error {
+                    let client = reqwest_middleware::ClientBuilder::new(raw_client.clone())
+                        .with(HostTlsErrorMiddleware(error.clone()))
+                        .build();
+                    (client.clone(), client)
+                } else {
+                    (
+                        self.apply_middleware(raw_client.clone(), endpoints),
+                        self.apply_middleware(raw_dangerous_client.clone(), endpoints),
+                    )
+                };
+                HostClient {
+                    tls: tls.clone(),
+                    error: error.clone(),
+                    client,
+                    dangerous_client,
+                    raw_client: raw_client.clone(),
+                    raw_dangerous_client: raw_dangerous_client.clone(),
+                }
+            })
+            .collect()
+    }
+
     fn create_client(
         &self,
         user_agent: &str,
         timeout: Duration,
         ssl_cert_file_exists: bool,
         security: Security,
+        host_tls: Option<&HostTlsMaterial>,
     ) -> Client {
         // Configure the builder.
         let client_builder = ClientBuilder::new()
@@ -312,6 +470,14 @@ impl<'a> BaseClientBuilder<'a> {
             client_builder
         };
 
+        // Configure any TLS settings specific to the host, which take precedence over the global
+        // client certificate.
+        let client_builder = if let Some(host_tls) = host_tls {
+            configure_host_tls(client_builder, host_tls)
+        } else {
+            client_builder
+        };
+
         // apply proxies
         let mut client_builder = client_builder;
         for p in &self.proxies {
@@ -324,11 +490,23 @@ impl<'a> BaseClientBuilder<'a> {
             .expect("Failed to build HTTP client.")
     }
 
-    fn apply_middleware(&self, client: Client) -> ClientWithMiddleware {
+    fn apply_middleware(
+        &self,
+        client: Client,
+        endpoints: Option<&Arc<BaseClient>>,
+    ) -> ClientWithMiddleware {
         match self.connectivity {
             Connectivity::Online => {
                 let mut client = reqwest_middleware::ClientBuilder::new(client);
 
+                // Fail over to any index mirrors, retrying each endpoint independently.
+                if let Some(endpoints) = endpoints {
+                    client = client.with(MirrorMiddleware {
+                        mirrors: self.index_mirrors.clone(),
+                        endpoints: endpoints.clone(),
+                    });
+                }
+
                 // Avoid uncloneable errors with a streaming body during publish.
                 if self.retries > 0 {
                     // Initialize the retry strategy.
@@ -387,6 +565,8 @@ pub struct BaseClient {
     raw_client: Client,
     /// The HTTP client that accepts invalid certificates without middleware.
     raw_dangerous_client: Client,
+    /// The HTTP clients for hosts with dedicated TLS settings.
+    host_clients: Vec<HostClient>,
     /// The connectivity mode to use.
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
@@ -397,6 +577,31 @@ pub struct BaseClient {
     retries: u32,
 }
 
+/// The HTTP clients for a host with dedicated TLS settings, e.g., a client certificate.
+#[derive(Debug, Clone)]
+struct HostClient {
+    /// The TLS settings for the host.
+    tls: HostTls,
+    /// The error encountered reading the TLS settings, if any.
+    error: Option<Arc<HostTlsError>>,
+    /// The underlying HTTP client that enforces valid certificates.
+    client: ClientWithMiddleware,
+    /// The underlying HTTP client that accepts invalid certificates.
+    dangerous_client: ClientWithMiddleware,
+    /// The HTTP client without middleware.
+    raw_client: Client,
+    /// The HTTP client that accepts invalid certificates without middleware.
+    raw_dangerous_client: Client,
+}
+
+/// The HTTP clients for a host with dedicated TLS settings, without middleware.
+struct RawHostClient {
+    tls: HostTls,
+    error: Option<Arc<HostTlsError>>,
+    raw_client: Client,
+    raw_dangerous_client: Client,
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Security {
     /// The client should use secure settings, i.e., valid certificates.
@@ -408,7 +613,17 @@ enum Security {
 impl BaseClient {
     /// Selects the appropriate client based on the host's trustworthiness.
     pub fn for_host(&self, url: &Url) -> &ClientWithMiddleware {
-        if self.disable_ssl(url) {
+        if let Some(host_client) = self
+            .host_clients
+            .iter()
+            .find(|host_client| host_client.tls.matches(url))
+        {
+            if self.disable_ssl(url) {
+                &host_client.dangerous_client
+            } else {
+                &host_client.client
+            }
+        } else if self.disable_ssl(url) {
             &self.dangerous_client
         } else {
             &self.client
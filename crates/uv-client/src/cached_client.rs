@@ -935,7 +935,9 @@ impl DataWithCachePolicy {
             );
             return Err(ErrorKind::ArchiveRead(msg).into());
         };
-        if bytes.len() < len_usize + 8 {
+        // Note that `bytes.len() >= 8`, so this can't underflow (unlike `len_usize + 8`, which
+        // can overflow for a corrupt length).
+        if bytes.len() - 8 < len_usize {
             let msg = format!(
                 "invalid cache entry: data-with-cache-policy has cache policy length of {}, \
                  but total buffer size is {}",
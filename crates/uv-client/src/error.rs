@@ -6,6 +6,7 @@ use async_zip::error::ZipError;
 use url::Url;
 
 use uv_distribution_filename::{WheelFilename, WheelFilenameError};
+use uv_distribution_types::IndexUrl;
 use uv_normalize::PackageName;
 use uv_redacted::redacted_url;
 
@@ -182,6 +183,16 @@ pub enum ErrorKind {
     #[error("Package `{0}` was not found in the local index")]
     FileNotFound(String),
 
+    /// The package was found on multiple indexes that don't declare each other, per PEP 708.
+    #[error(
+        "Package `{package}` was found on multiple indexes ({}), but the indexes don't declare each other via `tracks` or `alternate-locations`",
+        indexes.iter().map(|index| format!("`{}`", redacted_url(index.url()))).collect::<Vec<_>>().join(", ")
+    )]
+    UndeclaredAlternateLocations {
+        package: PackageName,
+        indexes: Vec<IndexUrl>,
+    },
+
     /// The metadata file could not be parsed.
     #[error("Couldn't parse metadata of {0} from {1}")]
     MetadataParseError(
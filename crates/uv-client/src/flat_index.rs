@@ -167,6 +167,7 @@ impl<'a> FlatIndexClient<'a> {
             "html",
             format!("{}.msgpack", cache_digest(&url.to_string())),
         );
+        self.cache.hydrate_async(cache_entry.path()).await;
         let cache_control = match self.connectivity {
             Connectivity::Online => CacheControl::from(
                 self.cache
@@ -195,7 +196,7 @@ impl<'a> FlatIndexClient<'a> {
                     .text()
                     .await
                     .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
-                let SimpleHtml { base, files } = SimpleHtml::parse(&text, &url)
+                let SimpleHtml { base, files, .. } = SimpleHtml::parse(&text, &url)
                     .map_err(|err| Error::from_html_err(err, url.clone()))?;
 
                 // Convert to a reference-counted string.
@@ -1,3 +1,4 @@
+use std::fmt::Write;
 use std::str::FromStr;
 
 use jiff::Timestamp;
@@ -5,17 +6,31 @@ use tl::HTMLTag;
 use tracing::{debug, instrument, warn};
 use url::Url;
 
+use uv_normalize::PackageName;
 use uv_pep440::VersionSpecifiers;
-use uv_pypi_types::{BaseUrl, CoreMetadata, File, Hashes, Yanked};
+use uv_pypi_types::{
+    BaseUrl, CoreMetadata, File, Hashes, ProjectLocations, ProjectStatus, ProjectStatusMarker,
+    Yanked,
+};
 use uv_pypi_types::{HashError, LenientVersionSpecifiers};
 
+/// The opening of a rendered simple HTML page, declaring the repository version (PEP 629).
+const HEADER: &str = "<!DOCTYPE html>\n<html>\n  <head>\n    <meta name=\"pypi:repository-version\" content=\"1.1\">\n";
+
+/// The closing of a rendered simple HTML page.
+const FOOTER: &str = "  </body>\n</html>\n";
+
 /// A parsed structure from PyPI "HTML" index format for a single package.
 #[derive(Debug, Clone)]
-pub(crate) struct SimpleHtml {
+pub struct SimpleHtml {
     /// The [`BaseUrl`] to which all relative URLs should be resolved.
     pub(crate) base: BaseUrl,
     /// The list of [`File`]s available for download sorted by filename.
     pub(crate) files: Vec<File>,
+    /// The status of the project, per PEP 792.
+    pub(crate) project_status: ProjectStatus,
+    /// The locations of the project on other indexes, per PEP 708.
+    pub(crate) locations: ProjectLocations,
 }
 
 impl SimpleHtml {
@@ -39,6 +54,11 @@ impl SimpleHtml {
                 .unwrap_or_else(|| url.clone()),
         );
 
+        // Parse the `<meta>` tags, to extract the project status (PEP 792) and the project's
+        // locations on other indexes (PEP 708).
+        let project_status = Self::parse_project_status(&dom);
+        let locations = Self::parse_project_locations(&dom);
+
         // Parse each `<a>` tag, to extract the filename, hash, and URL.
         let mut files: Vec<File> = dom
             .nodes()
@@ -62,7 +82,186 @@ impl SimpleHtml {
         // probably be the thing that does the sorting.)
         files.sort_unstable_by(|f1, f2| f1.filename.cmp(&f2.filename));
 
-        Ok(Self { base, files })
+        Ok(Self {
+            base,
+            files,
+            project_status,
+            locations,
+        })
+    }
+
+    /// Render the simple HTML page listing every project on an index, per PEP 503.
+    ///
+    /// Each project links to `{name}/`, relative to the page itself.
+    pub fn render_index<'a>(projects: impl IntoIterator<Item = &'a PackageName>) -> String {
+        let mut html = String::from(HEADER);
+        html.push_str("    <title>Simple index</title>\n  </head>\n  <body>\n");
+        for name in projects {
+            let name = html_escape::encode_text(name.as_str());
+            let _ = writeln!(html, "    <a href=\"{name}/\">{name}</a><br />");
+        }
+        html.push_str(FOOTER);
+        html
+    }
+
+    /// Render the simple HTML page for a single project, per PEP 503.
+    ///
+    /// The inverse of [`SimpleHtml::parse`]: in addition to the URL and hash of each file, renders
+    /// the `requires-python`, `core-metadata` (PEP 658 and PEP 714), and `yanked` (PEP 592)
+    /// attributes, along with the non-standard `size` and `upload-time` attributes.
+    pub fn render(name: &PackageName, files: &[File]) -> String {
+        let name = html_escape::encode_text(name.as_str());
+        let mut html = String::from(HEADER);
+        let _ = write!(
+            html,
+            "    <title>Links for {name}</title>\n  </head>\n  <body>\n    <h1>Links for {name}</h1>\n"
+        );
+        for file in files {
+            let mut href = file.url.to_string();
+            if let Some(fragment) = Self::hash_fragment(&file.hashes) {
+                href.push('#');
+                href.push_str(&fragment);
+            }
+            let _ = write!(
+                html,
+                "    <a href=\"{}\"",
+                html_escape::encode_double_quoted_attribute(&href)
+            );
+            if let Some(Ok(requires_python)) = &file.requires_python {
+                let _ = write!(
+                    html,
+                    " data-requires-python=\"{}\"",
+                    html_escape::encode_double_quoted_attribute(&requires_python.to_string())
+                );
+            }
+            if let Some(core_metadata) = &file.core_metadata {
+                let value = match core_metadata {
+                    CoreMetadata::Bool(bool) => Some(bool.to_string()),
+                    CoreMetadata::Hashes(hashes) => Self::hash_fragment(hashes),
+                };
+                if let Some(value) = value {
+                    let value = html_escape::encode_double_quoted_attribute(&value);
+                    let _ = write!(
+                        html,
+                        " data-core-metadata=\"{value}\" data-dist-info-metadata=\"{value}\""
+                    );
+                }
+            }
+            if let Some(yanked) = &file.yanked {
+                match yanked.as_ref() {
+                    Yanked::Bool(false) => {}
+                    Yanked::Bool(true) => html.push_str(" data-yanked=\"\""),
+                    Yanked::Reason(reason) => {
+                        let _ = write!(
+                            html,
+                            " data-yanked=\"{}\"",
+                            html_escape::encode_double_quoted_attribute(reason.as_ref())
+                        );
+                    }
+                }
+            }
+            if let Some(size) = file.size {
+                let _ = write!(html, " data-size=\"{size}\"");
+            }
+            if let Some(upload_time) = &file.upload_time {
+                let _ = write!(html, " data-upload-time=\"{upload_time}\"");
+            }
+            let _ = writeln!(
+                html,
+                ">{}</a><br />",
+                html_escape::encode_text(file.filename.as_ref())
+            );
+        }
+        html.push_str(FOOTER);
+        html
+    }
+
+    /// Render the strongest of the given hashes as a URL fragment, as in `sha256=...`.
+    fn hash_fragment(hashes: &Hashes) -> Option<String> {
+        [
+            ("sha512", &hashes.sha512),
+            ("sha384", &hashes.sha384),
+            ("sha256", &hashes.sha256),
+            ("blake2b", &hashes.blake2b),
+            ("md5", &hashes.md5),
+        ]
+        .into_iter()
+        .find_map(|(algorithm, digest)| {
+            digest
+                .as_ref()
+                .map(|digest| format!("{algorithm}={digest}"))
+        })
+    }
+
+    /// Parse the project status from the `pypi:project-status` and `pypi:project-status-reason`
+    /// `<meta>` tags, per PEP 792.
+    fn parse_project_status(dom: &tl::VDom) -> ProjectStatus {
+        let mut project_status = ProjectStatus::default();
+        for meta in dom
+            .nodes()
+            .iter()
+            .filter_map(|node| node.as_tag())
+            .filter(|tag| tag.name().as_bytes() == b"meta")
+        {
+            let attributes = meta.attributes();
+            let Some(name) = attributes.get("name").flatten() else {
+                continue;
+            };
+            let Some(content) = attributes
+                .get("content")
+                .flatten()
+                .and_then(|content| std::str::from_utf8(content.as_bytes()).ok())
+            else {
+                continue;
+            };
+            let content = html_escape::decode_html_entities(content);
+            match name.as_bytes() {
+                b"pypi:project-status" => {
+                    project_status.status =
+                        ProjectStatusMarker::from_str(content.trim()).unwrap_or_default();
+                }
+                b"pypi:project-status-reason" => {
+                    project_status.reason = Some(content.into());
+                }
+                _ => {}
+            }
+        }
+        project_status
+    }
+
+    /// Parse the project's locations on other indexes from the (repeatable) `pypi:tracks` and
+    /// `pypi:alternate-locations` `<meta>` tags, per PEP 708.
+    fn parse_project_locations(dom: &tl::VDom) -> ProjectLocations {
+        let mut locations = ProjectLocations::default();
+        for meta in dom
+            .nodes()
+            .iter()
+            .filter_map(|node| node.as_tag())
+            .filter(|tag| tag.name().as_bytes() == b"meta")
+        {
+            let attributes = meta.attributes();
+            let Some(name) = attributes.get("name").flatten() else {
+                continue;
+            };
+            let Some(content) = attributes
+                .get("content")
+                .flatten()
+                .and_then(|content| std::str::from_utf8(content.as_bytes()).ok())
+            else {
+                continue;
+            };
+            let content = html_escape::decode_html_entities(content);
+            match name.as_bytes() {
+                b"pypi:tracks" => {
+                    locations.tracks.push(content.trim().into());
+                }
+                b"pypi:alternate-locations" => {
+                    locations.alternate_locations.push(content.trim().into());
+                }
+                _ => {}
+            }
+        }
+        locations
     }
 
     /// Parse the `href` from a `<base>` tag.
@@ -314,6 +513,14 @@ mod tests {
                     yanked: None,
                 },
             ],
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            locations: ProjectLocations {
+                tracks: [],
+                alternate_locations: [],
+            },
         }
         "#);
     }
@@ -371,6 +578,14 @@ mod tests {
                     yanked: None,
                 },
             ],
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            locations: ProjectLocations {
+                tracks: [],
+                alternate_locations: [],
+            },
         }
         "#);
     }
@@ -431,6 +646,14 @@ mod tests {
                     yanked: None,
                 },
             ],
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            locations: ProjectLocations {
+                tracks: [],
+                alternate_locations: [],
+            },
         }
         "#);
     }
@@ -488,6 +711,14 @@ mod tests {
                     yanked: None,
                 },
             ],
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            locations: ProjectLocations {
+                tracks: [],
+                alternate_locations: [],
+            },
         }
         "#);
     }
@@ -545,6 +776,14 @@ mod tests {
                     yanked: None,
                 },
             ],
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            locations: ProjectLocations {
+                tracks: [],
+                alternate_locations: [],
+            },
         }
         "#);
     }
@@ -600,6 +839,14 @@ mod tests {
                     yanked: None,
                 },
             ],
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            locations: ProjectLocations {
+                tracks: [],
+                alternate_locations: [],
+            },
         }
         "#);
     }
@@ -655,6 +902,14 @@ mod tests {
                     yanked: None,
                 },
             ],
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            locations: ProjectLocations {
+                tracks: [],
+                alternate_locations: [],
+            },
         }
         "#);
     }
@@ -693,6 +948,14 @@ mod tests {
                 },
             ),
             files: [],
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            locations: ProjectLocations {
+                tracks: [],
+                alternate_locations: [],
+            },
         }
         "###);
     }
@@ -731,6 +994,14 @@ mod tests {
                 },
             ),
             files: [],
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            locations: ProjectLocations {
+                tracks: [],
+                alternate_locations: [],
+            },
         }
         "###);
     }
@@ -786,6 +1057,14 @@ mod tests {
                     yanked: None,
                 },
             ],
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            locations: ProjectLocations {
+                tracks: [],
+                alternate_locations: [],
+            },
         }
         "#);
     }
@@ -841,6 +1120,14 @@ mod tests {
                     yanked: None,
                 },
             ],
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            locations: ProjectLocations {
+                tracks: [],
+                alternate_locations: [],
+            },
         }
         "#);
     }
@@ -897,6 +1184,14 @@ mod tests {
                         yanked: None,
                     },
                 ],
+                project_status: ProjectStatus {
+                    status: Active,
+                    reason: None,
+                },
+                locations: ProjectLocations {
+                    tracks: [],
+                    alternate_locations: [],
+                },
             },
         )
         "#);
@@ -954,6 +1249,14 @@ mod tests {
                         yanked: None,
                     },
                 ],
+                project_status: ProjectStatus {
+                    status: Active,
+                    reason: None,
+                },
+                locations: ProjectLocations {
+                    tracks: [],
+                    alternate_locations: [],
+                },
             },
         )
         "#);
@@ -1044,6 +1347,14 @@ mod tests {
                     yanked: None,
                 },
             ],
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            locations: ProjectLocations {
+                tracks: [],
+                alternate_locations: [],
+            },
         }
         "#);
     }
@@ -1159,6 +1470,14 @@ mod tests {
                     yanked: None,
                 },
             ],
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            locations: ProjectLocations {
+                tracks: [],
+                alternate_locations: [],
+            },
         }
         "#);
     }
@@ -1226,6 +1545,14 @@ mod tests {
                     yanked: None,
                 },
             ],
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            locations: ProjectLocations {
+                tracks: [],
+                alternate_locations: [],
+            },
         }
         "#);
     }
@@ -1370,7 +1697,163 @@ mod tests {
                     yanked: None,
                 },
             ],
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            locations: ProjectLocations {
+                tracks: [],
+                alternate_locations: [],
+            },
+        }
+        "#);
+    }
+
+    #[test]
+    fn parse_project_status() {
+        let text = r#"
+<!DOCTYPE html>
+<html>
+<head>
+<meta name="pypi:repository-version" content="1.4">
+<meta name="pypi:project-status" content="quarantined">
+<meta name="pypi:project-status-reason" content="Suspected malware &amp; typosquatting">
+</head>
+<body>
+<h1>Links for jinja2</h1>
+</body>
+</html>
+    "#;
+        let base = Url::parse("https://download.pytorch.org/whl/jinja2/").unwrap();
+        let result = SimpleHtml::parse(text, &base).unwrap();
+        assert!(result.files.is_empty());
+        assert_eq!(
+            result.project_status,
+            ProjectStatus {
+                status: ProjectStatusMarker::Quarantined,
+                reason: Some("Suspected malware & typosquatting".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_project_locations() {
+        let text = r#"
+<!DOCTYPE html>
+<html>
+<head>
+<meta name="pypi:repository-version" content="1.1">
+<meta name="pypi:tracks" content="https://pypi.org/simple/holygrail/">
+<meta name="pypi:alternate-locations" content="https://pypi.org/simple/holygrail/">
+<meta name="pypi:alternate-locations" content="https://example.com/simple/holygrail/">
+</head>
+<body>
+<h1>Links for holygrail</h1>
+</body>
+</html>
+    "#;
+        let base = Url::parse("https://example.com/simple/holygrail/").unwrap();
+        let result = SimpleHtml::parse(text, &base).unwrap();
+        assert_eq!(
+            result.locations,
+            ProjectLocations {
+                tracks: vec!["https://pypi.org/simple/holygrail/".into()],
+                alternate_locations: vec![
+                    "https://pypi.org/simple/holygrail/".into(),
+                    "https://example.com/simple/holygrail/".into(),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn render_round_trip() {
+        let name = PackageName::from_str("jinja2").unwrap();
+        let files = vec![
+            File {
+                core_metadata: Some(CoreMetadata::Hashes(Hashes {
+                    sha256: Some(
+                        "1f3cb1a8a9c7ba6f0d7c8ef6dd3b8a0e6b8b1ca8f2cdaa5a5a1c1b0e1f2a3b4c".into(),
+                    ),
+                    ..Hashes::default()
+                })),
+                filename: "Jinja2-3.1.2-py3-none-any.whl".into(),
+                hashes: Hashes {
+                    sha256: Some(
+                        "6088930bfe239f0e6710546ab9c19c9ef35e29792895fed6e6e31a023a182a61".into(),
+                    ),
+                    ..Hashes::default()
+                },
+                requires_python: Some(VersionSpecifiers::from_str(">=3.7")),
+                size: Some(133_101),
+                upload_time: Some(Timestamp::from_str("2022-04-28T17:21:25Z").unwrap()),
+                url: "../../files/Jinja2-3.1.2-py3-none-any.whl".into(),
+                yanked: Some(Box::new(Yanked::Reason("Broken <metadata> & more".into()))),
+            },
+            File {
+                core_metadata: None,
+                filename: "Jinja2-3.1.2.tar.gz".into(),
+                hashes: Hashes::default(),
+                requires_python: None,
+                size: None,
+                upload_time: None,
+                url: "../../files/Jinja2-3.1.2.tar.gz".into(),
+                yanked: None,
+            },
+        ];
+
+        let text = SimpleHtml::render(&name, &files);
+        assert!(text.contains(r#"<meta name="pypi:repository-version" content="1.1">"#));
+
+        let base = Url::parse("http://localhost:8000/simple/jinja2/").unwrap();
+        let result = SimpleHtml::parse(&text, &base).unwrap();
+        assert_eq!(result.files.len(), files.len());
+        for (parsed, file) in result.files.iter().zip(&files) {
+            assert_eq!(parsed.filename, file.filename);
+            assert_eq!(parsed.url, file.url);
+            assert_eq!(parsed.hashes, file.hashes);
+            assert_eq!(parsed.size, file.size);
+            assert_eq!(parsed.upload_time, file.upload_time);
+            assert_eq!(parsed.yanked, file.yanked);
+            assert_eq!(
+                parsed
+                    .requires_python
+                    .as_ref()
+                    .and_then(|specifiers| specifiers.as_ref().ok()),
+                file.requires_python
+                    .as_ref()
+                    .and_then(|specifiers| specifiers.as_ref().ok())
+            );
+            assert_eq!(
+                parsed
+                    .core_metadata
+                    .as_ref()
+                    .is_some_and(CoreMetadata::is_available),
+                file.core_metadata
+                    .as_ref()
+                    .is_some_and(CoreMetadata::is_available)
+            );
         }
+    }
+
+    #[test]
+    fn render_index() {
+        let projects = [
+            PackageName::from_str("jinja2").unwrap(),
+            PackageName::from_str("markupsafe").unwrap(),
+        ];
+        insta::assert_snapshot!(SimpleHtml::render_index(&projects), @r#"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <meta name="pypi:repository-version" content="1.1">
+            <title>Simple index</title>
+          </head>
+          <body>
+            <a href="jinja2/">jinja2</a><br />
+            <a href="markupsafe/">markupsafe</a><br />
+          </body>
+        </html>
         "#);
     }
 }
@@ -1,10 +1,18 @@
 use http::Extensions;
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
-use reqwest::{Request, Response};
+use reqwest::{Request, Response, ResponseBuilderExt};
 use reqwest_middleware::{Middleware, Next};
+use tracing::debug;
 use url::Url;
 
+use uv_distribution_types::{IndexLocations, IndexUrl};
+use uv_redacted::redacted_url;
+
+use crate::BaseClient;
+
 /// A custom error type for the offline middleware.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct OfflineError {
@@ -45,3 +53,196 @@ impl Middleware for OfflineMiddleware {
         ))
     }
 }
+
+/// An index with a set of mirrors to fail over to when the index is unavailable.
+#[derive(Debug, Clone)]
+struct IndexMirror {
+    /// The root URLs of the index, followed by the root URL of each mirror, in order.
+    endpoints: Vec<Url>,
+    /// The endpoint that most recently served a request successfully.
+    preferred: Arc<AtomicUsize>,
+}
+
+impl IndexMirror {
+    /// Return the position of the endpoint that serves the given URL, if any.
+    fn position(&self, url: &Url) -> Option<usize> {
+        self.endpoints
+            .iter()
+            .position(|endpoint| is_prefix_for(endpoint, url))
+    }
+
+    /// Rewrite a URL served by the endpoint at position `from` to the endpoint at position `to`.
+    ///
+    /// Any credentials in the URL are replaced by those of the target endpoint, such that
+    /// credentials are never forwarded from one endpoint to another.
+    fn rewrite(&self, url: &Url, from: usize, to: usize) -> Url {
+        let source = &self.endpoints[from];
+        let target = &self.endpoints[to];
+        let suffix = &url.path()[source.path().len()..];
+        let mut rewritten = target.clone();
+        rewritten.set_path(&format!("{}{suffix}", target.path()));
+        rewritten.set_query(url.query());
+        rewritten.set_fragment(url.fragment());
+        rewritten
+    }
+}
+
+/// Returns `true` if the endpoint root URL is a prefix of the given URL.
+fn is_prefix_for(endpoint: &Url, url: &Url) -> bool {
+    endpoint.scheme() == url.scheme()
+        && endpoint.host_str() == url.host_str()
+        && endpoint.port_or_known_default() == url.port_or_known_default()
+        && url.path().starts_with(endpoint.path())
+}
+
+/// Return the root URL of an index or mirror, with a trailing slash.
+fn endpoint_root(url: &IndexUrl) -> Url {
+    let mut root = url.root().unwrap_or_else(|| url.url().clone());
+    if let Ok(mut segments) = root.path_segments_mut() {
+        segments.pop_if_empty().push("");
+    }
+    root
+}
+
+/// The set of indexes that define mirrors, per `mirrors` in `[[tool.uv.index]]`.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct IndexMirrors(Vec<IndexMirror>);
+
+impl IndexMirrors {
+    /// Returns `true` if no index defines any mirrors.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Return the [`IndexMirror`] that serves the given URL, along with the position of the
+    /// matching endpoint.
+    fn find(&self, url: &Url) -> Option<(&IndexMirror, usize)> {
+        self.0
+            .iter()
+            .find_map(|mirror| Some((mirror, mirror.position(url)?)))
+    }
+}
+
+impl From<&IndexLocations> for IndexMirrors {
+    fn from(index_locations: &IndexLocations) -> Self {
+        Self(
+            index_locations
+                .allowed_indexes()
+                .into_iter()
+                .filter(|index| !index.mirrors.is_empty())
+                .map(|index| IndexMirror {
+                    endpoints: std::iter::once(&index.url)
+                        .chain(&index.mirrors)
+                        .map(endpoint_root)
+                        .collect(),
+                    preferred: Arc::new(AtomicUsize::new(0)),
+                })
+                .collect(),
+        )
+    }
+}
+
+/// A middleware that fails over to an index's mirrors when the index is unavailable, i.e., when a
+/// request fails to connect, times out, or returns a server error.
+///
+/// Endpoints are tried in order, starting with whichever endpoint most recently succeeded, such
+/// that an unavailable index is only retried once all of its mirrors have failed. The response
+/// retains the URL of the original request, such that URLs derived from it (e.g., relative links
+/// on a Simple API page, and in turn, the lockfile) always refer to the canonical index.
+///
+/// Requests to another endpoint are dispatched through the client for that endpoint's host, rather
+/// than the client that received the request, such that each endpoint is only sent its own TLS
+/// settings (e.g., client certificate).
+pub(crate) struct MirrorMiddleware {
+    pub(crate) mirrors: IndexMirrors,
+    /// The clients for each endpoint, without mirror failover.
+    pub(crate) endpoints: Arc<BaseClient>,
+}
+
+#[async_trait::async_trait]
+impl Middleware for MirrorMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let Some((mirror, origin)) = self.mirrors.find(req.url()) else {
+            return next.run(req, extensions).await;
+        };
+
+        // Requests with a streaming body can't be replayed against another endpoint.
+        if req.try_clone().is_none() {
+            return next.run(req, extensions).await;
+        }
+
+        let url = req.url().clone();
+        let start = mirror.preferred.load(Ordering::Relaxed);
+        let count = mirror.endpoints.len();
+        let mut result = None;
+        for offset in 0..count {
+            let position = (start + offset) % count;
+            let mut request = req.try_clone().expect("request body is cloneable");
+            let response = if position == origin {
+                next.clone().run(request, extensions).await
+            } else {
+                let rewritten = mirror.rewrite(&url, origin, position);
+                debug!(
+                    "Failing over from {} to mirror {}",
+                    redacted_url(&url),
+                    redacted_url(&rewritten)
+                );
+                let client = self.endpoints.for_host(&rewritten);
+                *request.url_mut() = rewritten;
+                client.execute_with_extensions(request, extensions).await
+            };
+            if !is_unavailable(&response) {
+                mirror.preferred.store(position, Ordering::Relaxed);
+                if position == origin {
+                    return response;
+                }
+                return response.map(|response| with_url(response, url));
+            }
+            debug!(
+                "Index endpoint {} is unavailable",
+                redacted_url(&mirror.endpoints[position])
+            );
+            result = Some(response);
+        }
+        result.expect("index has at least one endpoint")
+    }
+}
+
+/// Returns `true` if the request failed because the endpoint is unavailable.
+fn is_unavailable(result: &reqwest_middleware::Result<Response>) -> bool {
+    match result {
+        Ok(response) => response.status().is_server_error(),
+        Err(reqwest_middleware::Error::Reqwest(err)) => err.is_connect() || err.is_timeout(),
+        Err(reqwest_middleware::Error::Middleware(err)) => err
+            .chain()
+            .filter_map(|err| err.downcast_ref::<reqwest::Error>())
+            .any(|err| err.is_connect() || err.is_timeout()),
+    }
+}
+
+/// Replace the URL of the given [`Response`].
+fn with_url(response: Response, url: Url) -> Response {
+    let response = http::Response::<reqwest::Body>::from(response);
+    let (parts, body) = response.into_parts();
+    let mut builder = http::Response::builder()
+        .status(parts.status)
+        .version(parts.version);
+    if let Some(headers) = builder.headers_mut() {
+        *headers = parts.headers;
+    }
+    if let Some(extensions) = builder.extensions_mut() {
+        extensions.extend(parts.extensions);
+    }
+    // Set the URL last, so as to override any URL carried over in the extensions.
+    Response::from(
+        builder
+            .url(url)
+            .body(body)
+            .expect("response parts are valid"),
+    )
+}
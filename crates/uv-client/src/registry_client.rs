@@ -19,7 +19,7 @@ use url::Url;
 use uv_auth::Indexes;
 use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache};
 use uv_configuration::KeyringProviderType;
-use uv_configuration::{IndexStrategy, TrustedHost};
+use uv_configuration::{IndexStrategy, PreviewMode, TrustedHost};
 use uv_distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
 use uv_distribution_types::{
     BuiltDist, File, FileLocation, IndexCapabilities, IndexFormat, IndexLocations,
@@ -30,7 +30,7 @@ use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
-use uv_pypi_types::{ResolutionMetadata, SimpleJson};
+use uv_pypi_types::{ProjectLocations, ProjectStatus, ResolutionMetadata, SimpleJson};
 use uv_redacted::redacted_url;
 use uv_small_str::SmallString;
 use uv_torch::TorchStrategy;
@@ -39,19 +39,99 @@ use crate::base_client::{BaseClientBuilder, ExtraMiddleware};
 use crate::cached_client::CacheControl;
 use crate::flat_index::FlatIndexEntry;
 use crate::html::SimpleHtml;
+use crate::middleware::IndexMirrors;
 use crate::remote_metadata::wheel_metadata_from_remote_zip;
 use crate::rkyvutil::OwnedArchive;
+use crate::tls::HostTls;
 use crate::{
     BaseClient, CachedClient, CachedClientError, Error, ErrorKind, FlatIndexClient,
     FlatIndexEntries,
 };
 
+/// Return the URL of the project page for the given package on a "Simple" index.
+fn project_url(index: &IndexUrl, package_name: &PackageName) -> Result<Url, Error> {
+    let mut url = index.url().clone();
+    url.path_segments_mut()
+        .map_err(|()| ErrorKind::CannotBeABase(index.url().clone()))?
+        .pop_if_empty()
+        .push(package_name.as_ref())
+        // The URL *must* end in a trailing slash for proper relative path behavior
+        // ref https://github.com/servo/rust-url/issues/333
+        .push("");
+    Ok(url)
+}
+
+/// Validate that the "Simple" indexes on which a package was found declare each other, such that
+/// their versions can be merged, per PEP 708.
+///
+/// An index that `tracks` another index is treated as equivalent to it (e.g., a mirror). Otherwise,
+/// each pair of indexes must list each other's project page as an `alternate-locations` entry.
+/// `--find-links`-style flat indexes carry no such metadata, and are exempt.
+fn validate_project_locations(
+    package_name: &PackageName,
+    results: &[(&IndexUrl, MetadataFormat)],
+) -> Result<(), Error> {
+    let pages = results
+        .iter()
+        .filter_map(|(index, metadata)| match metadata {
+            MetadataFormat::Simple(metadata) => Some((*index, metadata.locations())),
+            MetadataFormat::Flat(_) => None,
+        })
+        .map(|(index, locations)| Ok((index, project_url(index, package_name)?, locations)))
+        .collect::<Result<Vec<_>, Error>>()?;
+    if pages.len() < 2 {
+        return Ok(());
+    }
+
+    // Group the indexes that track one another.
+    let mut groups = (0..pages.len()).collect::<Vec<_>>();
+    for (i, (_, url_i, locations_i)) in pages.iter().enumerate() {
+        for (j, (_, url_j, locations_j)) in pages.iter().enumerate().skip(i + 1) {
+            if locations_i.tracks(url_j) || locations_j.tracks(url_i) {
+                let (from, to) = (groups[j], groups[i]);
+                for group in &mut groups {
+                    if *group == from {
+                        *group = to;
+                    }
+                }
+            }
+        }
+    }
+
+    // Every pair of groups must declare each other as alternate locations.
+    let declared = |a: usize, b: usize| {
+        (0..pages.len()).filter(|i| groups[*i] == a).any(|i| {
+            (0..pages.len()).filter(|j| groups[*j] == b).any(|j| {
+                pages[i].2.has_alternate_location(&pages[j].1)
+                    && pages[j].2.has_alternate_location(&pages[i].1)
+            })
+        })
+    };
+    let mut roots = groups.clone();
+    roots.sort_unstable();
+    roots.dedup();
+    for (i, a) in roots.iter().enumerate() {
+        for b in roots.iter().skip(i + 1) {
+            if !declared(*a, *b) {
+                return Err(ErrorKind::UndeclaredAlternateLocations {
+                    package: package_name.clone(),
+                    indexes: pages.iter().map(|(index, ..)| (*index).clone()).collect(),
+                }
+                .into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// A builder for an [`RegistryClient`].
 #[derive(Debug, Clone)]
 pub struct RegistryClientBuilder<'a> {
     index_urls: IndexUrls,
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchStrategy>,
+    preview: PreviewMode,
     cache: Cache,
     base_client_builder: BaseClientBuilder<'a>,
 }
@@ -62,6 +142,7 @@ impl RegistryClientBuilder<'_> {
             index_urls: IndexUrls::default(),
             index_strategy: IndexStrategy::default(),
             torch_backend: None,
+            preview: PreviewMode::default(),
             cache,
             base_client_builder: BaseClientBuilder::new(),
         }
@@ -74,7 +155,15 @@ impl<'a> RegistryClientBuilder<'a> {
         self.index_urls = index_locations.index_urls();
         self.base_client_builder = self
             .base_client_builder
-            .indexes(Indexes::from(index_locations));
+            .indexes(Indexes::from(index_locations))
+            .index_mirrors(IndexMirrors::from(index_locations))
+            .host_tls(HostTls::from_index_locations(index_locations));
+        self
+    }
+
+    #[must_use]
+    pub fn host_tls(mut self, host_tls: impl IntoIterator<Item = HostTls>) -> Self {
+        self.base_client_builder = self.base_client_builder.host_tls(host_tls);
         self
     }
 
@@ -84,6 +173,12 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn preview(mut self, preview: PreviewMode) -> Self {
+        self.preview = preview;
+        self
+    }
+
     #[must_use]
     pub fn torch_backend(mut self, torch_backend: Option<TorchStrategy>) -> Self {
         self.torch_backend = torch_backend;
@@ -168,6 +263,7 @@ impl<'a> RegistryClientBuilder<'a> {
             index_urls: self.index_urls,
             index_strategy: self.index_strategy,
             torch_backend: self.torch_backend,
+            preview: self.preview,
             cache: self.cache,
             connectivity,
             client,
@@ -191,6 +287,7 @@ impl<'a> RegistryClientBuilder<'a> {
             index_urls: self.index_urls,
             index_strategy: self.index_strategy,
             torch_backend: self.torch_backend,
+            preview: self.preview,
             cache: self.cache,
             connectivity,
             client,
@@ -208,6 +305,7 @@ impl<'a> TryFrom<BaseClientBuilder<'a>> for RegistryClientBuilder<'a> {
             index_urls: IndexUrls::default(),
             index_strategy: IndexStrategy::default(),
             torch_backend: None,
+            preview: PreviewMode::default(),
             cache: Cache::temp()?,
             base_client_builder: value,
         })
@@ -223,6 +321,8 @@ pub struct RegistryClient {
     index_strategy: IndexStrategy,
     /// The strategy to use when selecting a PyTorch backend, if any.
     torch_backend: Option<TorchStrategy>,
+    /// Whether preview features are enabled.
+    preview: PreviewMode,
     /// The underlying HTTP client.
     client: CachedClient,
     /// Used for the remote wheel METADATA cache.
@@ -325,7 +425,8 @@ impl RegistryClient {
 
         let mut results = Vec::new();
 
-        match self.index_strategy_for(package_name) {
+        let index_strategy = self.index_strategy_for(package_name);
+        match index_strategy {
             // If we're searching for the first index that contains the package, fetch serially.
             IndexStrategy::FirstIndex => {
                 for index in indexes {
@@ -409,6 +510,12 @@ impl RegistryClient {
                     })
                     .try_collect::<Vec<_>>()
                     .await?;
+
+                // Per PEP 708, only merge the versions from multiple indexes if the indexes
+                // declare each other.
+                if index_strategy == IndexStrategy::UnsafeBestMatch && self.preview.is_enabled() {
+                    validate_project_locations(package_name, &results)?;
+                }
             }
         }
 
@@ -476,14 +583,7 @@ impl RegistryClient {
         status_code_strategy: &IndexStatusCodeStrategy,
     ) -> Result<SimpleMetadataSearchOutcome, Error> {
         // Format the URL for PyPI.
-        let mut url = index.url().clone();
-        url.path_segments_mut()
-            .map_err(|()| ErrorKind::CannotBeABase(index.url().clone()))?
-            .pop_if_empty()
-            .push(package_name.as_ref())
-            // The URL *must* end in a trailing slash for proper relative path behavior
-            // ref https://github.com/servo/rust-url/issues/333
-            .push("");
+        let url = project_url(index, package_name)?;
 
         trace!(
             "Fetching metadata for {package_name} from {}",
@@ -495,6 +595,7 @@ impl RegistryClient {
             WheelCache::Index(index).root(),
             format!("{package_name}.rkyv"),
         );
+        self.cache.hydrate_async(cache_entry.path()).await;
         let cache_control = match self.connectivity {
             Connectivity::Online => CacheControl::from(
                 self.cache
@@ -595,7 +696,16 @@ impl RegistryClient {
                         let data: SimpleJson = serde_json::from_slice(bytes.as_ref())
                             .map_err(|err| Error::from_json_err(err, url.clone()))?;
 
-                        SimpleMetadata::from_files(data.files, package_name, &url)
+                        SimpleMetadata::from_files(
+                            data.files,
+                            data.project_status,
+                            ProjectLocations {
+                                tracks: data.tracks,
+                                alternate_locations: data.alternate_locations,
+                            },
+                            package_name,
+                            &url,
+                        )
                     }
                     MediaType::Html => {
                         let text = response
@@ -784,6 +894,7 @@ impl RegistryClient {
                 WheelCache::Index(index).wheel_dir(filename.name.as_ref()),
                 format!("{}.msgpack", filename.cache_key()),
             );
+            self.cache.hydrate_async(cache_entry.path()).await;
             let cache_control = match self.connectivity {
                 Connectivity::Online => CacheControl::from(
                     self.cache
@@ -854,6 +965,7 @@ impl RegistryClient {
             cache_shard.wheel_dir(filename.name.as_ref()),
             format!("{}.msgpack", filename.cache_key()),
         );
+        self.cache.hydrate_async(cache_entry.path()).await;
         let cache_control = match self.connectivity {
             Connectivity::Online => CacheControl::from(
                 self.cache
@@ -1082,7 +1194,13 @@ pub struct VersionSourceDist {
 
 #[derive(Default, Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 #[rkyv(derive(Debug))]
-pub struct SimpleMetadata(Vec<SimpleMetadatum>);
+pub struct SimpleMetadata {
+    versions: Vec<SimpleMetadatum>,
+    /// The status of the project, per PEP 792.
+    status: ProjectStatus,
+    /// The locations of the project on other indexes, per PEP 708.
+    locations: ProjectLocations,
+}
 
 #[derive(Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 #[rkyv(derive(Debug))]
@@ -1093,10 +1211,26 @@ pub struct SimpleMetadatum {
 
 impl SimpleMetadata {
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &SimpleMetadatum> {
-        self.0.iter()
+        self.versions.iter()
+    }
+
+    /// Return the status of the project, per PEP 792.
+    pub fn status(&self) -> &ProjectStatus {
+        &self.status
     }
 
-    fn from_files(files: Vec<uv_pypi_types::File>, package_name: &PackageName, base: &Url) -> Self {
+    /// Return the locations of the project on other indexes, per PEP 708.
+    pub fn locations(&self) -> &ProjectLocations {
+        &self.locations
+    }
+
+    fn from_files(
+        files: Vec<uv_pypi_types::File>,
+        status: ProjectStatus,
+        locations: ProjectLocations,
+        package_name: &PackageName,
+        base: &Url,
+    ) -> Self {
         let mut map: BTreeMap<Version, VersionFiles> = BTreeMap::default();
 
         // Convert to a reference-counted string.
@@ -1132,20 +1266,29 @@ impl SimpleMetadata {
                 }
             }
         }
-        Self(
-            map.into_iter()
+        Self {
+            versions: map
+                .into_iter()
                 .map(|(version, files)| SimpleMetadatum { version, files })
                 .collect(),
-        )
+            status,
+            locations,
+        }
     }
 
     /// Read the [`SimpleMetadata`] from an HTML index.
     fn from_html(text: &str, package_name: &PackageName, url: &Url) -> Result<Self, Error> {
-        let SimpleHtml { base, files } =
-            SimpleHtml::parse(text, url).map_err(|err| Error::from_html_err(err, url.clone()))?;
+        let SimpleHtml {
+            base,
+            files,
+            project_status,
+            locations,
+        } = SimpleHtml::parse(text, url).map_err(|err| Error::from_html_err(err, url.clone()))?;
 
         Ok(SimpleMetadata::from_files(
             files,
+            project_status,
+            locations,
             package_name,
             base.as_url(),
         ))
@@ -1157,17 +1300,29 @@ impl IntoIterator for SimpleMetadata {
     type IntoIter = std::vec::IntoIter<SimpleMetadatum>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.versions.into_iter()
     }
 }
 
 impl ArchivedSimpleMetadata {
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &rkyv::Archived<SimpleMetadatum>> {
-        self.0.iter()
+        self.versions.iter()
     }
 
     pub fn datum(&self, i: usize) -> Option<&rkyv::Archived<SimpleMetadatum>> {
-        self.0.get(i)
+        self.versions.get(i)
+    }
+
+    /// Return the status of the project, per PEP 792.
+    pub fn status(&self) -> ProjectStatus {
+        rkyv::deserialize::<ProjectStatus, rkyv::rancor::Error>(&self.status)
+            .expect("archived project status always deserializes")
+    }
+
+    /// Return the locations of the project on other indexes, per PEP 708.
+    pub fn locations(&self) -> ProjectLocations {
+        rkyv::deserialize::<ProjectLocations, rkyv::rancor::Error>(&self.locations)
+            .expect("archived project locations always deserialize")
     }
 }
 
@@ -1265,6 +1420,8 @@ mod tests {
         let base = Url::parse("https://pypi.org/simple/pyflyby/").unwrap();
         let simple_metadata = SimpleMetadata::from_files(
             data.files,
+            data.project_status,
+            ProjectLocations::default(),
             &PackageName::from_str("pyflyby").unwrap(),
             &base,
         );
@@ -1301,7 +1458,7 @@ mod tests {
         // Note the lack of a trailing `/` here is important for coverage of url-join behavior
         let base = Url::parse("https://account.d.codeartifact.us-west-2.amazonaws.com/pypi/shared-packages-pypi/simple/flask")
             .unwrap();
-        let SimpleHtml { base, files } = SimpleHtml::parse(text, &base).unwrap();
+        let SimpleHtml { base, files, .. } = SimpleHtml::parse(text, &base).unwrap();
 
         // Test parsing of the file urls
         let urls = files
@@ -1,6 +1,16 @@
-use reqwest::Identity;
+use http::Extensions;
+use reqwest::{Certificate, ClientBuilder, Identity, Request, Response};
+use reqwest_middleware::{Middleware, Next};
 use std::ffi::OsStr;
 use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use url::Url;
+
+use uv_distribution_types::{Index, IndexLocations, IndexUrl};
+use uv_fs::Simplified;
+use uv_warnings::warn_user_once;
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum CertificateError {
@@ -10,12 +20,264 @@ pub(crate) enum CertificateError {
     Reqwest(reqwest::Error),
 }
 
+/// An error reading the TLS settings for a host, e.g., a missing client certificate.
+#[derive(thiserror::Error, Debug)]
+#[error("Failed to read `{setting}` for `{host}` ({})", path.user_display())]
+pub(crate) struct HostTlsError {
+    setting: &'static str,
+    host: String,
+    path: PathBuf,
+    #[source]
+    err: CertificateError,
+}
+
 /// Return the `Identity` from the provided file.
 pub(crate) fn read_identity(ssl_client_cert: &OsStr) -> Result<Identity, CertificateError> {
+    read_identity_with_key(Path::new(ssl_client_cert), None)
+}
+
+/// Return the `Identity` from the provided certificate file and, optionally, a separate key file.
+fn read_identity_with_key(
+    client_cert: &Path,
+    client_key: Option<&Path>,
+) -> Result<Identity, CertificateError> {
     let mut buf = Vec::new();
-    fs_err::File::open(ssl_client_cert)?.read_to_end(&mut buf)?;
+    fs_err::File::open(client_cert)?.read_to_end(&mut buf)?;
+    if let Some(client_key) = client_key {
+        buf.push(b'\n');
+        fs_err::File::open(client_key)?.read_to_end(&mut buf)?;
+    }
     Identity::from_pem(&buf).map_err(|tls_err| {
         debug_assert!(tls_err.is_builder(), "must be a rustls::Error internally");
         CertificateError::Reqwest(tls_err)
     })
 }
+
+/// Return the certificates in the provided PEM bundle.
+fn read_ca_bundle(ca_bundle: &Path) -> Result<Vec<Certificate>, CertificateError> {
+    let mut buf = Vec::new();
+    fs_err::File::open(ca_bundle)?.read_to_end(&mut buf)?;
+    Certificate::from_pem_bundle(&buf).map_err(CertificateError::Reqwest)
+}
+
+/// The client certificate and certificate authorities read from a [`HostTls`].
+#[derive(Debug, Clone)]
+pub(crate) struct HostTlsMaterial {
+    identity: Option<Identity>,
+    certificates: Vec<Certificate>,
+}
+
+/// Apply the client certificate and certificate authorities for a given host.
+pub(crate) fn configure_host_tls(
+    client_builder: ClientBuilder,
+    material: &HostTlsMaterial,
+) -> ClientBuilder {
+    let client_builder = if let Some(identity) = &material.identity {
+        client_builder.identity(identity.clone())
+    } else {
+        client_builder
+    };
+    material
+        .certificates
+        .iter()
+        .cloned()
+        .fold(client_builder, ClientBuilder::add_root_certificate)
+}
+
+/// A middleware that fails every request to a host whose TLS settings could not be read.
+///
+/// Since the settings were configured explicitly for the host, sending requests without them
+/// (e.g., without the client certificate) would be incorrect.
+pub(crate) struct HostTlsErrorMiddleware(pub(crate) Arc<HostTlsError>);
+
+#[async_trait::async_trait]
+impl Middleware for HostTlsErrorMiddleware {
+    async fn handle(
+        &self,
+        _req: Request,
+        _extensions: &mut Extensions,
+        _next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        Err(reqwest_middleware::Error::Middleware(self.0.clone().into()))
+    }
+}
+
+/// The TLS settings for requests to a given host, e.g., as configured on an index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostTls {
+    /// A URL on the host to which the settings apply.
+    url: Url,
+    /// The client certificate to present, for mutual TLS.
+    pub(crate) client_cert: Option<PathBuf>,
+    /// The private key for the client certificate, if not included in the certificate file.
+    pub(crate) client_key: Option<PathBuf>,
+    /// Additional certificate authorities to trust.
+    pub(crate) ca_bundle: Option<PathBuf>,
+}
+
+impl HostTls {
+    /// Create a [`HostTls`] for the host of the given URL.
+    ///
+    /// Returns `None` if no settings are provided.
+    pub fn new(
+        url: Url,
+        client_cert: Option<PathBuf>,
+        client_key: Option<PathBuf>,
+        ca_bundle: Option<PathBuf>,
+    ) -> Option<Self> {
+        if client_cert.is_none() && client_key.is_none() && ca_bundle.is_none() {
+            return None;
+        }
+        Some(Self {
+            url,
+            client_cert,
+            client_key,
+            ca_bundle,
+        })
+    }
+
+    /// Create a [`HostTls`] for each host of the given [`Index`], i.e., its URL, its mirrors, and,
+    /// if set, its upload endpoint.
+    pub fn from_index(index: &Index) -> impl Iterator<Item = Self> + '_ {
+        std::iter::once(index.url.url())
+            .chain(index.mirrors.iter().map(IndexUrl::url))
+            .chain(index.publish_url.as_ref())
+            .filter_map(|url| {
+                Self::new(
+                    url.clone(),
+                    index.client_cert.clone(),
+                    index.client_key.clone(),
+                    index.ca_bundle.clone(),
+                )
+            })
+    }
+
+    /// Create a [`HostTls`] for each host of the indexes in the given [`IndexLocations`].
+    pub fn from_index_locations(index_locations: &IndexLocations) -> Vec<Self> {
+        index_locations
+            .allowed_indexes()
+            .into_iter()
+            .flat_map(Self::from_index)
+            .collect()
+    }
+
+    /// The URL on the host to which the settings apply.
+    pub(crate) fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns `true` if the other settings are the same as these, regardless of their URL.
+    pub(crate) fn same_settings(&self, other: &Self) -> bool {
+        self.client_cert == other.client_cert
+            && self.client_key == other.client_key
+            && self.ca_bundle == other.ca_bundle
+    }
+
+    /// Read the client certificate and certificate authorities.
+    pub(crate) fn load(&self) -> Result<HostTlsMaterial, HostTlsError> {
+        let host = self.url.host_str().unwrap_or_default();
+
+        let identity = if let Some(client_cert) = &self.client_cert {
+            let identity = read_identity_with_key(client_cert, self.client_key.as_deref())
+                .map_err(|err| HostTlsError {
+                    setting: "client-cert",
+                    host: host.to_string(),
+                    path: client_cert.clone(),
+                    err,
+                })?;
+            Some(identity)
+        } else {
+            if self.client_key.is_some() {
+                warn_user_once!("Ignoring `client-key` for `{host}` without a `client-cert`");
+            }
+            None
+        };
+
+        let certificates = if let Some(ca_bundle) = &self.ca_bundle {
+            read_ca_bundle(ca_bundle).map_err(|err| HostTlsError {
+                setting: "ca-bundle",
+                host: host.to_string(),
+                path: ca_bundle.clone(),
+                err,
+            })?
+        } else {
+            Vec::new()
+        };
+
+        Ok(HostTlsMaterial {
+            identity,
+            certificates,
+        })
+    }
+
+    /// Returns `true` if the settings apply to the given URL, i.e., if it's on the same host.
+    pub(crate) fn matches(&self, url: &Url) -> bool {
+        self.url.scheme() == url.scheme()
+            && self.url.host_str() == url.host_str()
+            && self.url.port_or_known_default() == url.port_or_known_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    use url::Url;
+
+    use uv_distribution_types::{Index, IndexUrl};
+
+    use super::HostTls;
+
+    #[test]
+    fn host_tls_from_index() {
+        let mut index = Index::from_str("internal=https://example.com/simple").unwrap();
+        assert_eq!(HostTls::from_index(&index).count(), 0);
+
+        index.publish_url = Some(Url::parse("https://upload.example.com:8443/legacy/").unwrap());
+        index.client_cert = Some(PathBuf::from("client.pem"));
+        let host_tls = HostTls::from_index(&index).collect::<Vec<_>>();
+        assert_eq!(host_tls.len(), 2);
+
+        // The settings apply to any path on the index's host...
+        assert!(host_tls[0].matches(&Url::parse("https://example.com/files/a.whl").unwrap()));
+        // ...but not to other hosts, ports, or schemes.
+        assert!(!host_tls[0].matches(&Url::parse("https://pypi.org/simple/").unwrap()));
+        assert!(!host_tls[0].matches(&Url::parse("https://example.com:8443/simple/").unwrap()));
+        assert!(!host_tls[0].matches(&Url::parse("http://example.com/simple/").unwrap()));
+
+        // The upload endpoint's host is matched including its port.
+        assert!(host_tls[1].matches(&Url::parse("https://upload.example.com:8443/").unwrap()));
+        assert!(!host_tls[1].matches(&Url::parse("https://upload.example.com/").unwrap()));
+    }
+
+    #[test]
+    fn host_tls_from_index_mirrors() {
+        let mut index = Index::from_str("internal=https://eu.example.com/simple").unwrap();
+        index.mirrors = vec![IndexUrl::from_str("https://us.example.com/simple").unwrap()];
+        index.client_cert = Some(PathBuf::from("client.pem"));
+        let host_tls = HostTls::from_index(&index).collect::<Vec<_>>();
+        assert_eq!(host_tls.len(), 2);
+
+        // Each mirror has its own settings, matching its own host.
+        assert!(host_tls[1].matches(&Url::parse("https://us.example.com/simple/a/").unwrap()));
+        assert!(!host_tls[1].matches(&Url::parse("https://eu.example.com/simple/a/").unwrap()));
+        assert!(host_tls[0].same_settings(&host_tls[1]));
+    }
+
+    #[test]
+    fn host_tls_missing_file() {
+        let host_tls = HostTls::new(
+            Url::parse("https://example.com/simple").unwrap(),
+            Some(PathBuf::from("missing.pem")),
+            None,
+            None,
+        )
+        .unwrap();
+        let err = host_tls.load().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to read `client-cert` for `example.com` (missing.pem)"
+        );
+    }
+}
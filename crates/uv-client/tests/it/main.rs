@@ -1,2 +1,3 @@
 mod remote_metadata;
+mod tls;
 mod user_agent_version;
@@ -0,0 +1,205 @@
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::Result;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::TokioIo;
+use rcgen::{BasicConstraints, Certificate, CertificateParams, DnType, IsCa, KeyPair};
+use rustls::pki_types::{PrivateKeyDer, PrivatePkcs8KeyDer};
+use rustls::server::WebPkiClientVerifier;
+use rustls::{RootCertStore, ServerConfig};
+use tempfile::TempDir;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use tokio_rustls::TlsAcceptor;
+use url::Url;
+
+use uv_cache::Cache;
+use uv_client::{RegistryClient, RegistryClientBuilder};
+use uv_distribution_types::{Index, IndexLocations, IndexUrl};
+
+/// A certificate authority that issues both server and client certificates.
+struct Authority {
+    cert: Certificate,
+    key: KeyPair,
+}
+
+impl Authority {
+    fn new(name: &str) -> Result<Self> {
+        let key = KeyPair::generate()?;
+        let mut params = CertificateParams::new(Vec::<String>::new())?;
+        params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        params.distinguished_name.push(DnType::CommonName, name);
+        let cert = params.self_signed(&key)?;
+        Ok(Self { cert, key })
+    }
+
+    /// Issue a certificate for the given subject alternative names.
+    fn issue(&self, names: &[&str]) -> Result<(Certificate, KeyPair)> {
+        let key = KeyPair::generate()?;
+        let params =
+            CertificateParams::new(names.iter().map(ToString::to_string).collect::<Vec<_>>())?;
+        let cert = params.signed_by(&key, &self.cert, &self.key)?;
+        Ok((cert, key))
+    }
+
+    /// Write the authority's certificate, and a client certificate and key it issued, to the
+    /// given directory, returning the paths to the CA bundle, client certificate, and client key.
+    fn write(&self, dir: &Path, name: &str) -> Result<(PathBuf, PathBuf, PathBuf)> {
+        let (cert, key) = self.issue(&[])?;
+        let ca_bundle = dir.join(format!("{name}-ca.pem"));
+        let client_cert = dir.join(format!("{name}-client.pem"));
+        let client_key = dir.join(format!("{name}-client.key"));
+        fs_err::write(&ca_bundle, self.cert.pem())?;
+        fs_err::write(&client_cert, cert.pem())?;
+        fs_err::write(&client_key, key.serialize_pem())?;
+        Ok((ca_bundle, client_cert, client_key))
+    }
+}
+
+/// Serve `OK` over TLS with a certificate issued by the given authority, only accepting clients
+/// that present a certificate issued by the same authority.
+async fn serve(authority: &Authority) -> Result<(SocketAddr, JoinHandle<()>)> {
+    let (cert, key) = authority.issue(&["localhost", "127.0.0.1"])?;
+
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let mut roots = RootCertStore::empty();
+    roots.add(authority.cert.der().clone())?;
+    let verifier =
+        WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider.clone()).build()?;
+    let config = ServerConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()?
+        .with_client_cert_verifier(verifier)
+        .with_single_cert(
+            vec![cert.der().clone()],
+            PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(key.serialize_der())),
+        )?;
+    let acceptor = TlsAcceptor::from(Arc::new(config));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let server = tokio::spawn(async move {
+        while let Ok((socket, _)) = listener.accept().await {
+            let acceptor = acceptor.clone();
+            tokio::spawn(async move {
+                // Handshakes with an untrusted client (or server) certificate are expected to fail.
+                let Ok(stream) = acceptor.accept(socket).await else {
+                    return;
+                };
+                let svc = service_fn(|_req: Request<hyper::body::Incoming>| {
+                    future::ok::<_, hyper::Error>(Response::new(Full::new(Bytes::from("OK"))))
+                });
+                let _ = http1::Builder::new()
+                    .serve_connection(TokioIo::new(stream), svc)
+                    .await;
+            });
+        }
+    });
+    Ok((addr, server))
+}
+
+fn registry_client(indexes: Vec<Index>) -> Result<RegistryClient> {
+    let cache = Cache::temp()?.init()?;
+    Ok(RegistryClientBuilder::new(cache)
+        .retries(0)
+        .index_locations(&IndexLocations::new(indexes, vec![], false))
+        .build())
+}
+
+async fn get(client: &RegistryClient, url: &Url) -> Result<String> {
+    let response = client
+        .cached_client()
+        .uncached()
+        .for_host(url)
+        .get(url.clone())
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(response.text().await?)
+}
+
+#[tokio::test]
+async fn index_client_certificate() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let authority = Authority::new("internal")?;
+    let (ca_bundle, client_cert, client_key) = authority.write(temp_dir.path(), "internal")?;
+    let (addr, server) = serve(&authority).await?;
+
+    let url = Url::parse(&format!("https://127.0.0.1:{}/simple/", addr.port()))?;
+    let index = Index::from_str(&format!("internal={url}"))?;
+
+    // Without the CA bundle, the server's certificate isn't trusted.
+    let client = registry_client(vec![index.clone()])?;
+    assert!(get(&client, &url).await.is_err());
+
+    // Without a client certificate, the server rejects the handshake.
+    let client = registry_client(vec![Index {
+        ca_bundle: Some(ca_bundle.clone()),
+        ..index.clone()
+    }])?;
+    assert!(get(&client, &url).await.is_err());
+
+    // With both, the request succeeds.
+    let client = registry_client(vec![Index {
+        client_cert: Some(client_cert),
+        client_key: Some(client_key),
+        ca_bundle: Some(ca_bundle),
+        ..index
+    }])?;
+    assert_eq!(get(&client, &url).await?, "OK");
+
+    server.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn index_mirror_client_certificate() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+
+    // An index that's unavailable, with its own certificate authority and client certificate.
+    let primary = Authority::new("primary")?;
+    let (primary_ca_bundle, primary_client_cert, primary_client_key) =
+        primary.write(temp_dir.path(), "primary")?;
+    let port = TcpListener::bind("127.0.0.1:0").await?.local_addr()?.port();
+    let primary_url = Url::parse(&format!("https://localhost:{port}/simple/"))?;
+
+    // A mirror with a different certificate authority and client certificate, which are
+    // configured on the mirror's host via another index.
+    let mirror = Authority::new("mirror")?;
+    let (mirror_ca_bundle, mirror_client_cert, mirror_client_key) =
+        mirror.write(temp_dir.path(), "mirror")?;
+    let (addr, server) = serve(&mirror).await?;
+    let mirror_url = Url::parse(&format!("https://127.0.0.1:{}/simple/", addr.port()))?;
+
+    let client = registry_client(vec![
+        Index {
+            client_cert: Some(mirror_client_cert),
+            client_key: Some(mirror_client_key),
+            ca_bundle: Some(mirror_ca_bundle),
+            ..Index::from_str(&format!("mirror={mirror_url}"))?
+        },
+        Index {
+            client_cert: Some(primary_client_cert),
+            client_key: Some(primary_client_key),
+            ca_bundle: Some(primary_ca_bundle),
+            mirrors: vec![IndexUrl::from_str(mirror_url.as_str())?],
+            ..Index::from_str(&format!("primary={primary_url}"))?
+        },
+    ])?;
+
+    // The request fails over to the mirror, which only accepts its own client certificate and is
+    // only trusted via its own CA bundle.
+    assert_eq!(get(&client, &primary_url.join("anyio/")?).await?, "OK");
+
+    server.abort();
+
+    Ok(())
+}
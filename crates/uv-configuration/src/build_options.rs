@@ -349,6 +349,10 @@ pub enum IndexStrategy {
     /// as internal packages, causing the resolver to install the malicious package in lieu of
     /// the intended internal package.
     ///
+    /// To mitigate this risk, in preview, the versions for a package found on multiple indexes are
+    /// only merged if the indexes declare each other via `tracks` or `alternate-locations`, per
+    /// PEP 708.
+    ///
     /// See: <https://peps.python.org/pep-0708/>
     UnsafeBestMatch,
 }
@@ -8,7 +8,7 @@ use uv_static::EnvVars;
 /// system.
 ///
 /// See: <https://doc.rust-lang.org/nightly/rustc/platform-support.html>
-#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -226,6 +226,13 @@ pub enum TargetTriple {
     #[serde(rename = "aarch64-manylinux_2_40")]
     #[serde(alias = "aarch64-manylinux240")]
     Aarch64Manylinux240,
+
+    /// A wasm32 Emscripten target, as used by Pyodide.
+    ///
+    /// Assumes the `pyodide_2024_0` platform (i.e., Python 3.12 and Emscripten 3.1.58).
+    #[cfg_attr(feature = "clap", value(name = "wasm32-emscripten"))]
+    #[serde(rename = "wasm32-emscripten")]
+    Wasm32Emscripten,
 }
 
 impl TargetTriple {
@@ -450,6 +457,13 @@ impl TargetTriple {
                 },
                 Arch::Aarch64,
             ),
+            Self::Wasm32Emscripten => Platform::new(
+                Os::Pyodide {
+                    major: 2024,
+                    minor: 0,
+                },
+                Arch::Wasm32,
+            ),
         }
     }
 
@@ -490,6 +504,7 @@ impl TargetTriple {
             Self::Aarch64Manylinux238 => "aarch64",
             Self::Aarch64Manylinux239 => "aarch64",
             Self::Aarch64Manylinux240 => "aarch64",
+            Self::Wasm32Emscripten => "wasm32",
         }
     }
 
@@ -530,6 +545,7 @@ impl TargetTriple {
             Self::Aarch64Manylinux238 => "Linux",
             Self::Aarch64Manylinux239 => "Linux",
             Self::Aarch64Manylinux240 => "Linux",
+            Self::Wasm32Emscripten => "Emscripten",
         }
     }
 
@@ -570,6 +586,7 @@ impl TargetTriple {
             Self::Aarch64Manylinux238 => "",
             Self::Aarch64Manylinux239 => "",
             Self::Aarch64Manylinux240 => "",
+            Self::Wasm32Emscripten => "#1",
         }
     }
 
@@ -610,6 +627,7 @@ impl TargetTriple {
             Self::Aarch64Manylinux238 => "",
             Self::Aarch64Manylinux239 => "",
             Self::Aarch64Manylinux240 => "",
+            Self::Wasm32Emscripten => "3.1.58",
         }
     }
 
@@ -650,6 +668,7 @@ impl TargetTriple {
             Self::Aarch64Manylinux238 => "posix",
             Self::Aarch64Manylinux239 => "posix",
             Self::Aarch64Manylinux240 => "posix",
+            Self::Wasm32Emscripten => "posix",
         }
     }
 
@@ -690,6 +709,7 @@ impl TargetTriple {
             Self::Aarch64Manylinux238 => "linux",
             Self::Aarch64Manylinux239 => "linux",
             Self::Aarch64Manylinux240 => "linux",
+            Self::Wasm32Emscripten => "emscripten",
         }
     }
 
@@ -730,6 +750,7 @@ impl TargetTriple {
             Self::Aarch64Manylinux238 => true,
             Self::Aarch64Manylinux239 => true,
             Self::Aarch64Manylinux240 => true,
+            Self::Wasm32Emscripten => false,
         }
     }
 
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
@@ -104,6 +104,48 @@ pub struct Index {
     /// ```
     #[serde(default)]
     pub ignore_error_codes: Option<Vec<SerializableStatusCode>>,
+    /// Mirrors of the index, to fail over to when the index is unavailable.
+    ///
+    /// If a request to the index fails to connect, times out, or returns a server error (5xx),
+    /// uv will retry the request against each mirror, in order. Mirrors are expected to serve the
+    /// same content as the index, at the same paths relative to the index URL.
+    ///
+    /// The lockfile always refers to the index by its `url`, regardless of which mirror served
+    /// a given request. Credentials are matched per mirror, such that the index's credentials
+    /// are never sent to a mirror (and vice versa).
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://eu.example.com/simple"
+    /// mirrors = ["https://us.example.com/simple"]
+    /// ```
+    #[serde(default)]
+    pub mirrors: Vec<IndexUrl>,
+    /// The path to a PEM-encoded client certificate to present to the index, for mutual TLS.
+    ///
+    /// The file may also contain the private key, or the key may be provided separately via
+    /// `client-key`. The certificate is presented for all requests to the index's host, including
+    /// those to its `publish-url`, if the upload endpoint is on a different host.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://example.com/simple"
+    /// client-cert = "certs/client.pem"
+    /// client-key = "certs/client.key"
+    /// ```
+    ///
+    /// Relative paths are resolved relative to the directory of the configuration file.
+    pub client_cert: Option<PathBuf>,
+    /// The path to the PEM-encoded private key for the `client-cert`.
+    pub client_key: Option<PathBuf>,
+    /// The path to a PEM-encoded bundle of certificate authorities to trust for requests to the
+    /// index, in addition to the system or bundled roots.
+    ///
+    /// Unlike `SSL_CERT_FILE`, the bundle only applies to the index's host (and that of its
+    /// `publish-url`), such that other indexes, like PyPI, continue to use the default roots.
+    pub ca_bundle: Option<PathBuf>,
 }
 
 #[derive(
@@ -142,6 +184,10 @@ impl Index {
             publish_url: None,
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
+            mirrors: Vec::new(),
+            client_cert: None,
+            client_key: None,
+            ca_bundle: None,
         }
     }
 
@@ -157,6 +203,10 @@ impl Index {
             publish_url: None,
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
+            mirrors: Vec::new(),
+            client_cert: None,
+            client_key: None,
+            ca_bundle: None,
         }
     }
 
@@ -172,6 +222,10 @@ impl Index {
             publish_url: None,
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
+            mirrors: Vec::new(),
+            client_cert: None,
+            client_key: None,
+            ca_bundle: None,
         }
     }
 
@@ -225,6 +279,23 @@ impl Index {
                 self.url = IndexUrl::parse(given, Some(root_dir))?;
             }
         }
+        for mirror in &mut self.mirrors {
+            if let IndexUrl::Path(ref url) = mirror {
+                if let Some(given) = url.given() {
+                    *mirror = IndexUrl::parse(given, Some(root_dir))?;
+                }
+            }
+        }
+        for path in [
+            &mut self.client_cert,
+            &mut self.client_key,
+            &mut self.ca_bundle,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *path = root_dir.join(&*path);
+        }
         Ok(self)
     }
 
@@ -250,6 +321,10 @@ impl From<IndexUrl> for Index {
             publish_url: None,
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
+            mirrors: Vec::new(),
+            client_cert: None,
+            client_key: None,
+            ca_bundle: None,
         }
     }
 }
@@ -273,6 +348,10 @@ impl FromStr for Index {
                     publish_url: None,
                     authenticate: AuthPolicy::default(),
                     ignore_error_codes: None,
+                    mirrors: Vec::new(),
+                    client_cert: None,
+                    client_key: None,
+                    ca_bundle: None,
                 });
             }
         }
@@ -289,6 +368,10 @@ impl FromStr for Index {
             publish_url: None,
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
+            mirrors: Vec::new(),
+            client_cert: None,
+            client_key: None,
+            ca_bundle: None,
         })
     }
 }
@@ -412,19 +412,27 @@ impl<'a> IndexLocations {
 
 impl From<&IndexLocations> for uv_auth::Indexes {
     fn from(index_locations: &IndexLocations) -> uv_auth::Indexes {
-        uv_auth::Indexes::from_indexes(index_locations.allowed_indexes().into_iter().map(|index| {
-            let mut url = index.url().url().clone();
-            url.set_username("").ok();
-            url.set_password(None).ok();
-            let mut root_url = index.url().root().unwrap_or_else(|| url.clone());
-            root_url.set_username("").ok();
-            root_url.set_password(None).ok();
-            uv_auth::Index {
-                url,
-                root_url,
-                auth_policy: index.authenticate,
-            }
-        }))
+        // Each mirror is treated as an index in its own right, such that credentials are matched
+        // per mirror, with the authentication policy of the index it mirrors.
+        uv_auth::Indexes::from_indexes(index_locations.allowed_indexes().into_iter().flat_map(
+            |index| {
+                std::iter::once(index.url())
+                    .chain(&index.mirrors)
+                    .map(|index_url| {
+                        let mut url = index_url.url().clone();
+                        url.set_username("").ok();
+                        url.set_password(None).ok();
+                        let mut root_url = index_url.root().unwrap_or_else(|| url.clone());
+                        root_url.set_username("").ok();
+                        root_url.set_password(None).ok();
+                        uv_auth::Index {
+                            url,
+                            root_url,
+                            auth_policy: index.authenticate,
+                        }
+                    })
+            },
+        ))
     }
 }
 
@@ -877,6 +877,21 @@ pub fn implied_markers(filename: &WheelFilename) -> MarkerTree {
                 marker.or(tag_marker);
             }
 
+            // Pyodide
+            PlatformTag::Pyodide { .. } => {
+                let mut tag_marker = MarkerTree::expression(MarkerExpression::String {
+                    key: MarkerValueString::SysPlatform,
+                    operator: MarkerOperator::Equal,
+                    value: arcstr::literal!("emscripten"),
+                });
+                tag_marker.and(MarkerTree::expression(MarkerExpression::String {
+                    key: MarkerValueString::PlatformMachine,
+                    operator: MarkerOperator::Equal,
+                    value: arcstr::literal!("wasm32"),
+                }));
+                marker.or(tag_marker);
+            }
+
             tag => {
                 debug!("Unknown platform tag in wheel tag: {tag}");
             }
@@ -941,5 +956,9 @@ mod tests {
             "numpy-2.2.1-cp310-cp310-macosx_11_0_arm64.whl",
             "sys_platform == 'darwin' and platform_machine == 'arm64'",
         );
+        assert_markers(
+            "numpy-2.2.1-cp312-cp312-pyodide_2024_0_wasm32.whl",
+            "sys_platform == 'emscripten' and platform_machine == 'wasm32'",
+        );
     }
 }
@@ -1,7 +1,7 @@
 use uv_distribution_filename::DistExtension;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep508::MarkerTree;
-use uv_pypi_types::{HashDigest, HashDigests};
+use uv_pypi_types::{HashDigest, HashDigests, ProjectStatusMarker};
 
 use crate::{
     BuiltDist, Diagnostic, Dist, IndexMetadata, Name, RequirementSource, ResolvedDist, SourceDist,
@@ -136,6 +136,15 @@ pub enum ResolutionDiagnostic {
         /// resolution. For example, `black`.
         package_name: PackageName,
     },
+    ProjectStatus {
+        /// The name of the package whose project has a non-active status on its index. For
+        /// example, `black`.
+        package_name: PackageName,
+        /// The status of the project, e.g., `archived` or `deprecated`.
+        status: ProjectStatusMarker,
+        /// The reason for the status, if any.
+        reason: Option<String>,
+    },
 }
 
 impl Diagnostic for ResolutionDiagnostic {
@@ -162,6 +171,17 @@ impl Diagnostic for ResolutionDiagnostic {
                     `--resolution lowest` to avoid using outdated versions."
                 )
             }
+            Self::ProjectStatus {
+                package_name,
+                status,
+                reason,
+            } => {
+                if let Some(reason) = reason {
+                    format!("`{package_name}` is {status} (reason: \"{reason}\")")
+                } else {
+                    format!("`{package_name}` is {status}")
+                }
+            }
         }
     }
 
@@ -172,6 +192,7 @@ impl Diagnostic for ResolutionDiagnostic {
             Self::MissingDev { dist, .. } => name == dist.name(),
             Self::YankedVersion { dist, .. } => name == dist.name(),
             Self::MissingLowerBound { package_name } => name == package_name,
+            Self::ProjectStatus { package_name, .. } => name == package_name,
         }
     }
 }
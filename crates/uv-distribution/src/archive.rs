@@ -27,9 +27,10 @@ impl Archive {
         }
     }
 
-    /// Returns `true` if the archive exists in the cache.
+    /// Returns `true` if the archive exists in the cache, importing it from a fallback cache if
+    /// necessary.
     pub(crate) fn exists(&self, cache: &Cache) -> bool {
-        self.version == ARCHIVE_VERSION && cache.archive(&self.id).exists()
+        self.version == ARCHIVE_VERSION && cache.hydrate_archive(&self.id)
     }
 }
 
@@ -541,8 +541,12 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             lock_entry.lock().await.map_err(Error::CacheWrite)?
         };
 
-        // Create an entry for the HTTP cache.
+        // Create an entry for the HTTP cache, importing it from a fallback cache if necessary.
         let http_entry = wheel_entry.with_file(format!("{}.http", filename.cache_key()));
+        self.build_context
+            .cache()
+            .hydrate_async(http_entry.path())
+            .await;
 
         let download = |response: reqwest::Response| {
             async {
@@ -678,8 +682,12 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             lock_entry.lock().await.map_err(Error::CacheWrite)?
         };
 
-        // Create an entry for the HTTP cache.
+        // Create an entry for the HTTP cache, importing it from a fallback cache if necessary.
         let http_entry = wheel_entry.with_file(format!("{}.http", filename.cache_key()));
+        self.build_context
+            .cache()
+            .hydrate_async(http_entry.path())
+            .await;
 
         let download = |response: reqwest::Response| {
             async {
@@ -48,6 +48,9 @@ impl<'a> BuiltWheelIndex<'a> {
             WheelCache::Url(source_dist.url.raw()).root(),
         );
 
+        // Import any cached revisions from the fallback caches.
+        self.cache.hydrate_shard(&cache_shard);
+
         // Read the revision from the cache.
         let Some(pointer) = HttpRevisionPointer::read_from(cache_shard.entry(HTTP_REVISION))?
         else {
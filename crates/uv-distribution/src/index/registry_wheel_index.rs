@@ -101,6 +101,9 @@ impl<'a> RegistryWheelIndex<'a> {
                 WheelCache::Index(index.url()).wheel_dir(package.as_ref()),
             );
 
+            // Import any cached wheels from the fallback caches.
+            cache.hydrate_shard(&wheel_dir);
+
             // For registry wheels, the cache structure is: `<index>/<package-name>/<wheel>.http`
             // or `<index>/<package-name>/<version>/<wheel>.rev`.
             for file in files(&wheel_dir).ok().into_iter().flatten() {
@@ -169,6 +172,9 @@ impl<'a> RegistryWheelIndex<'a> {
                 WheelCache::Index(index.url()).wheel_dir(package.as_ref()),
             );
 
+            // Import any built wheels from the fallback caches.
+            cache.hydrate_shard(&cache_shard);
+
             // For registry source distributions, the cache structure is: `<index>/<package-name>/<version>/`.
             for shard in directories(&cache_shard).ok().into_iter().flatten() {
                 let cache_shard = cache_shard.shard(shard);
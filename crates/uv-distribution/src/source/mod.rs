@@ -396,6 +396,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     ) -> Result<BuiltWheelMetadata, Error> {
         let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
+        // Import any cached revisions from the fallback caches.
+        self.build_context
+            .cache()
+            .hydrate_shard_async(cache_shard)
+            .await;
+
         // Fetch the revision for the source distribution.
         let revision = self
             .url_revision(source, ext, url, cache_shard, hashes, client)
@@ -511,6 +517,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     ) -> Result<ArchiveMetadata, Error> {
         let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
+        // Import any cached revisions from the fallback caches.
+        self.build_context
+            .cache()
+            .hydrate_shard_async(cache_shard)
+            .await;
+
         // Fetch the revision for the source distribution.
         let revision = self
             .url_revision(source, ext, url, cache_shard, hashes, client)
@@ -645,6 +645,35 @@ impl LockedFile {
         Self::lock_file_blocking(file, &resource)
     }
 
+    /// Attempt to acquire a cross-process lock for a resource using a file at the provided path,
+    /// without waiting for another process to release it.
+    ///
+    /// Returns `None` if the lock is held elsewhere.
+    pub fn try_acquire(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+    ) -> Result<Option<Self>, std::io::Error> {
+        let file = Self::create(path)?;
+        trace!(
+            "Checking lock for `{resource}` at `{}`",
+            file.path().user_display()
+        );
+        match file.file().try_lock_exclusive() {
+            Ok(()) => {
+                debug!("Acquired lock for `{resource}`");
+                Ok(Some(Self(file)))
+            }
+            Err(err) => {
+                // Log error code and enum kind to help debugging more exotic failures.
+                if err.kind() != std::io::ErrorKind::WouldBlock {
+                    debug!("Try lock error: {err:?}");
+                }
+                debug!("Lock for `{resource}` is held elsewhere");
+                Ok(None)
+            }
+        }
+    }
+
     /// Acquire a cross-process lock for a resource using a file at the provided path.
     #[cfg(feature = "tokio")]
     pub async fn acquire(
@@ -1,14 +1,14 @@
 use std::collections::HashMap;
 use std::io;
 use std::io::{BufReader, Read, Seek, Write};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 use data_encoding::BASE64URL_NOPAD;
 use fs_err as fs;
 use fs_err::{DirEntry, File};
 use mailparse::parse_headers;
 use rustc_hash::FxHashMap;
-use sha2::{Digest, Sha256};
+use sha2::{Digest, Sha256, Sha384, Sha512};
 use tracing::{debug, instrument, trace, warn};
 use walkdir::WalkDir;
 
@@ -750,6 +750,95 @@ pub fn read_record_file(record: &mut impl Read) -> Result<Vec<RecordEntry>, Erro
         .collect()
 }
 
+/// Verify the contents of an unzipped wheel against its `RECORD` file.
+///
+/// Returns an error if any file listed in the `RECORD` is missing, or doesn't match the recorded
+/// size or hash. Entries without a hash (like the `RECORD` itself), or with an unsupported hash
+/// algorithm, are only checked for existence.
+pub fn verify_record(wheel: impl AsRef<Path>) -> Result<(), Error> {
+    let wheel = wheel.as_ref();
+    let dist_info_prefix = find_dist_info(wheel)?;
+    let record_path = wheel.join(format!("{dist_info_prefix}.dist-info/RECORD"));
+    let mut record_file = match File::open(&record_path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(Error::InvalidWheel("Missing RECORD file".to_string()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let record = read_record_file(&mut record_file)?;
+
+    for entry in record {
+        // Ignore any entries that point outside the wheel.
+        let relative = Path::new(&entry.path);
+        if !relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            continue;
+        }
+
+        let path = wheel.join(relative);
+        let Some((algorithm, expected)) =
+            entry.hash.as_deref().and_then(|hash| hash.split_once('='))
+        else {
+            if !path.is_file() {
+                return Err(Error::RecordFile(format!("`{}` is missing", entry.path)));
+            }
+            continue;
+        };
+
+        let mut file = match File::open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(Error::RecordFile(format!("`{}` is missing", entry.path)));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let (size, actual) = match algorithm {
+            "sha256" => hash_reader::<Sha256>(&mut file)?,
+            "sha384" => hash_reader::<Sha384>(&mut file)?,
+            "sha512" => hash_reader::<Sha512>(&mut file)?,
+            _ => continue,
+        };
+
+        if let Some(expected) = entry.size {
+            if size != expected {
+                return Err(Error::RecordFile(format!(
+                    "`{}` has size {size}, but the RECORD specifies {expected}",
+                    entry.path
+                )));
+            }
+        }
+        if actual != expected.trim_end_matches('=') {
+            return Err(Error::RecordFile(format!(
+                "`{}` has hash {algorithm}={actual}, but the RECORD specifies {algorithm}={expected}",
+                entry.path
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Compute the size and the URL-safe base64-encoded digest of the given reader.
+fn hash_reader<D: Digest>(reader: &mut impl Read) -> io::Result<(u64, String)> {
+    let mut hasher = D::new();
+    let mut buf = vec![0; 8 * 1024];
+    let mut size = 0;
+    loop {
+        let len = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(len) => len,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        hasher.update(&buf[..len]);
+        size += len as u64;
+    }
+    Ok((size, BASE64URL_NOPAD.encode(&hasher.finalize())))
+}
+
 /// Parse a file with email message format such as WHEEL and METADATA
 fn parse_email_message_file(
     file: impl Read,
@@ -896,7 +985,7 @@ mod test {
 
     use super::{
         get_script_executable, parse_email_message_file, parse_wheel_file, read_record_file,
-        write_installer_metadata, RecordEntry, Script,
+        verify_record, write_installer_metadata, RecordEntry, Script,
     };
 
     #[test]
@@ -1203,4 +1292,43 @@ mod test {
             .collect::<Vec<String>>();
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn test_verify_record() -> Result<()> {
+        let wheel = assert_fs::TempDir::new()?;
+        wheel.child("foo/__init__.py").write_str("x = 1\n")?;
+        wheel
+            .child("foo-1.0.0.dist-info/RECORD")
+            .write_str(indoc! {"
+            foo/__init__.py,sha256=nia_NpkRxFwkPGhBR7I_yeHc_PJX0pmhxjIBam_NM_Q,6
+            foo-1.0.0.dist-info/RECORD,,
+        "})?;
+        verify_record(&wheel)?;
+
+        // A file with modified contents.
+        wheel.child("foo/__init__.py").write_str("x = 2\n")?;
+        let err = verify_record(&wheel).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "RECORD file doesn't match wheel contents: `foo/__init__.py` has hash sha256=QgXEgJqxsID9Mra_lkDl_qptG2m_n6aElUq3EBV-wUE, but the RECORD specifies sha256=nia_NpkRxFwkPGhBR7I_yeHc_PJX0pmhxjIBam_NM_Q"
+        );
+
+        // A truncated file.
+        wheel.child("foo/__init__.py").write_str("x")?;
+        let err = verify_record(&wheel).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "RECORD file doesn't match wheel contents: `foo/__init__.py` has size 1, but the RECORD specifies 6"
+        );
+
+        // A missing file.
+        fs_err::remove_file(wheel.child("foo/__init__.py"))?;
+        let err = verify_record(&wheel).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "RECORD file doesn't match wheel contents: `foo/__init__.py` is missing"
+        );
+
+        Ok(())
+    }
 }
@@ -96,6 +96,12 @@ impl<'a> Installer<'a> {
             }
         }
 
+        // Record the use of any cached wheels, prior to installing them, such that they aren't
+        // evicted by a concurrent `uv cache prune`.
+        if let Some(cache) = cache {
+            cache.record_usage(wheels.iter().map(CachedDist::path));
+        }
+
         let (tx, rx) = oneshot::channel();
 
         let layout = venv.interpreter().layout();
@@ -133,6 +139,12 @@ impl<'a> Installer<'a> {
             }
         }
 
+        // Record the use of any cached wheels, prior to installing them, such that they aren't
+        // evicted by a concurrent `uv cache prune`.
+        if let Some(cache) = self.cache {
+            cache.record_usage(wheels.iter().map(CachedDist::path));
+        }
+
         install(
             wheels,
             self.venv.interpreter().layout(),
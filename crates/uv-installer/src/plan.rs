@@ -431,6 +431,10 @@ impl<'a> Planner<'a> {
             }
         }
 
+        // Record the use of the cached wheels as soon as they're selected, such that they aren't
+        // evicted by a concurrent `uv cache prune` before they're installed.
+        cache.record_usage(cached.iter().map(CachedDist::path));
+
         Ok(Plan {
             cached,
             remote,
@@ -157,6 +157,10 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
                 .map(CachedDist::from);
             match result {
                 Ok(cached) => {
+                    // Record the use of the wheel, which may have been read from an existing cache
+                    // archive, such that it isn't evicted by a concurrent `uv cache prune` before
+                    // it's installed.
+                    self.cache.record_usage([cached.path()]);
                     in_flight.downloads.done(id, Ok(cached.clone()));
                     Ok(cached)
                 }
@@ -276,7 +280,7 @@ pub trait Reporter: Send + Sync {
 
 impl dyn Reporter {
     /// Converts this reporter to a [`uv_distribution::Reporter`].
-    pub(crate) fn into_distribution_reporter(
+    pub fn into_distribution_reporter(
         self: Arc<dyn Reporter>,
     ) -> Arc<dyn uv_distribution::Reporter> {
         Arc::new(Facade {
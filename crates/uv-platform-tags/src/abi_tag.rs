@@ -24,9 +24,10 @@ pub enum AbiTag {
     None,
     /// Ex) `abi3`
     Abi3,
-    /// Ex) `cp39m`, `cp310t`
+    /// Ex) `cp39m`, `cp310t`, `cp313d`
     CPython {
         gil_disabled: bool,
+        debug: bool,
         python_version: (u8, u8),
     },
     /// Ex) `pypy39_pp73`
@@ -79,16 +80,18 @@ impl std::fmt::Display for AbiTag {
             Self::Abi3 => write!(f, "abi3"),
             Self::CPython {
                 gil_disabled,
+                debug,
                 python_version: (major, minor),
             } => {
+                let debug = if *debug { "d" } else { "" };
                 if *minor <= 7 {
-                    write!(f, "cp{major}{minor}m")
+                    write!(f, "cp{major}{minor}{debug}m")
                 } else if *gil_disabled {
                     // https://peps.python.org/pep-0703/#build-configuration-changes
                     // Python 3.13+ only, but it makes more sense to just rely on the sysconfig var.
-                    write!(f, "cp{major}{minor}t")
+                    write!(f, "cp{major}{minor}t{debug}")
                 } else {
-                    write!(f, "cp{major}{minor}")
+                    write!(f, "cp{major}{minor}{debug}")
                 }
             }
             Self::PyPy {
@@ -198,13 +201,15 @@ impl FromStr for AbiTag {
         } else if s == "abi3" {
             Ok(Self::Abi3)
         } else if let Some(cp) = s.strip_prefix("cp") {
-            // Ex) `cp39m`, `cp310t`
+            // Ex) `cp39m`, `cp310t`, `cp313td`
             let version_end = cp.find(|c: char| !c.is_ascii_digit()).unwrap_or(cp.len());
-            let version_str = &cp[..version_end];
+            let (version_str, flags) = cp.split_at(version_end);
             let (major, minor) = parse_python_version(version_str, "CPython", s)?;
-            let gil_disabled = cp.ends_with('t');
+            let gil_disabled = flags.contains('t');
+            let debug = flags.contains('d');
             Ok(Self::CPython {
                 gil_disabled,
+                debug,
                 python_version: (major, minor),
             })
         } else if let Some(rest) = s.strip_prefix("pypy") {
@@ -353,6 +358,7 @@ mod tests {
     fn cpython_abi() {
         let tag = AbiTag::CPython {
             gil_disabled: false,
+            debug: false,
             python_version: (3, 9),
         };
         assert_eq!(AbiTag::from_str("cp39"), Ok(tag));
@@ -360,6 +366,7 @@ mod tests {
 
         let tag = AbiTag::CPython {
             gil_disabled: false,
+            debug: false,
             python_version: (3, 7),
         };
         assert_eq!(AbiTag::from_str("cp37m"), Ok(tag));
@@ -367,11 +374,28 @@ mod tests {
 
         let tag = AbiTag::CPython {
             gil_disabled: true,
+            debug: false,
             python_version: (3, 13),
         };
         assert_eq!(AbiTag::from_str("cp313t"), Ok(tag));
         assert_eq!(tag.to_string(), "cp313t");
 
+        let tag = AbiTag::CPython {
+            gil_disabled: false,
+            debug: true,
+            python_version: (3, 13),
+        };
+        assert_eq!(AbiTag::from_str("cp313d"), Ok(tag));
+        assert_eq!(tag.to_string(), "cp313d");
+
+        let tag = AbiTag::CPython {
+            gil_disabled: true,
+            debug: true,
+            python_version: (3, 13),
+        };
+        assert_eq!(AbiTag::from_str("cp313td"), Ok(tag));
+        assert_eq!(tag.to_string(), "cp313td");
+
         assert_eq!(
             AbiTag::from_str("cpXY"),
             Err(ParseAbiTagError::MissingMajorVersion {
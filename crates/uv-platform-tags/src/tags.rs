@@ -22,6 +22,12 @@ pub enum TagsError {
     InvalidPriority(usize, #[source] std::num::TryFromIntError),
     #[error("Only CPython can be freethreading, not: {0}")]
     GilIsACPythonProblem(String),
+    #[error("Pyodide {}.{} requires Python {}.{}, but Python {}.{} was requested", pyodide.0, pyodide.1, expected.0, expected.1, actual.0, actual.1)]
+    PyodidePythonVersion {
+        pyodide: (u16, u16),
+        expected: (u8, u8),
+        actual: (u8, u8),
+    },
 }
 
 #[derive(Debug, Eq, Ord, PartialEq, PartialOrd, Copy, Clone)]
@@ -116,8 +122,23 @@ impl Tags {
         implementation_version: (u8, u8),
         manylinux_compatible: bool,
         gil_disabled: bool,
+        debug_enabled: bool,
     ) -> Result<Self, TagsError> {
-        let implementation = Implementation::parse(implementation_name, gil_disabled)?;
+        let implementation =
+            Implementation::parse(implementation_name, gil_disabled, debug_enabled)?;
+
+        // Each Pyodide ABI is tied to a single Python version.
+        if let Os::Pyodide { major, minor } = platform.os() {
+            if let Some(expected) = pyodide_python_version(*major, *minor) {
+                if expected != python_version {
+                    return Err(TagsError::PyodidePythonVersion {
+                        pyodide: (*major, *minor),
+                        expected,
+                        actual: python_version,
+                    });
+                }
+            }
+        }
 
         // Determine the compatible tags for the current platform.
         let platform_tags = {
@@ -138,12 +159,41 @@ impl Tags {
                 platform_tag.clone(),
             ));
         }
+        // Debug builds can also load extension modules built for the release ABI, except on
+        // Windows, where debug builds only load extension modules with a `_d` suffix
+        let windows_debug = matches!(platform.os(), Os::Windows)
+            && matches!(
+                implementation,
+                Implementation::CPython {
+                    debug_enabled: true,
+                    ..
+                }
+            );
+        if let Implementation::CPython {
+            gil_disabled,
+            debug_enabled: true,
+        } = implementation
+        {
+            if python_version >= (3, 8) && !windows_debug {
+                let implementation = Implementation::CPython {
+                    gil_disabled,
+                    debug_enabled: false,
+                };
+                for platform_tag in &platform_tags {
+                    tags.push((
+                        implementation.language_tag(python_version),
+                        implementation.abi_tag(python_version, implementation_version),
+                        platform_tag.clone(),
+                    ));
+                }
+            }
+        }
         // 2. abi3 and no abi (e.g. executable binary)
-        if let Implementation::CPython { gil_disabled } = implementation {
+        if let Implementation::CPython { gil_disabled, .. } = implementation {
             // For some reason 3.2 is the minimum python for the cp abi
             for minor in (2..=python_version.1).rev() {
-                // No abi3 for free-threading python
-                if !gil_disabled {
+                // No abi3 for free-threading python, or for debug builds on Windows
+                if !gil_disabled && !windows_debug {
                     for platform_tag in &platform_tags {
                         tags.push((
                             implementation.language_tag((python_version.0, minor)),
@@ -361,7 +411,10 @@ impl std::fmt::Display for Tags {
 
 #[derive(Debug, Clone, Copy)]
 enum Implementation {
-    CPython { gil_disabled: bool },
+    CPython {
+        gil_disabled: bool,
+        debug_enabled: bool,
+    },
     PyPy,
     GraalPy,
     Pyston,
@@ -386,8 +439,12 @@ impl Implementation {
     fn abi_tag(self, python_version: (u8, u8), implementation_version: (u8, u8)) -> AbiTag {
         match self {
             // Ex) `cp39`
-            Self::CPython { gil_disabled } => AbiTag::CPython {
+            Self::CPython {
+                gil_disabled,
+                debug_enabled,
+            } => AbiTag::CPython {
                 gil_disabled,
+                debug: debug_enabled,
                 python_version,
             },
             // Ex) `pypy39_pp73`
@@ -407,13 +464,16 @@ impl Implementation {
         }
     }
 
-    fn parse(name: &str, gil_disabled: bool) -> Result<Self, TagsError> {
+    fn parse(name: &str, gil_disabled: bool, debug_enabled: bool) -> Result<Self, TagsError> {
         if gil_disabled && name != "cpython" {
             return Err(TagsError::GilIsACPythonProblem(name.to_string()));
         }
         match name {
             // Known and supported implementations.
-            "cpython" => Ok(Self::CPython { gil_disabled }),
+            "cpython" => Ok(Self::CPython {
+                gil_disabled,
+                debug_enabled,
+            }),
             "pypy" => Ok(Self::PyPy),
             "graalpy" => Ok(Self::GraalPy),
             "pyston" => Ok(Self::Pyston),
@@ -617,6 +677,12 @@ fn compatible_tags(platform: &Platform) -> Result<Vec<PlatformTag>, PlatformErro
                 arch,
             }]
         }
+        (Os::Pyodide { major, minor }, Arch::Wasm32) => {
+            vec![PlatformTag::Pyodide {
+                major: *major,
+                minor: *minor,
+            }]
+        }
         _ => {
             return Err(PlatformError::OsVersionDetectionError(format!(
                 "Unsupported operating system and architecture combination: {os} {arch}"
@@ -626,6 +692,14 @@ fn compatible_tags(platform: &Platform) -> Result<Vec<PlatformTag>, PlatformErro
     Ok(platform_tags)
 }
 
+/// Returns the Python version of the given Pyodide ABI, if known.
+fn pyodide_python_version(major: u16, minor: u16) -> Option<(u8, u8)> {
+    match (major, minor) {
+        (2024, 0) => Some((3, 12)),
+        _ => None,
+    }
+}
+
 #[derive(
     Debug,
     Copy,
@@ -806,6 +880,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_platform_tags_pyodide() {
+        let tags = compatible_tags(&Platform::new(
+            Os::Pyodide {
+                major: 2024,
+                minor: 0,
+            },
+            Arch::Wasm32,
+        ))
+        .unwrap();
+        let tags = tags.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_debug_snapshot!(
+            tags,
+            @r###"
+    [
+        "pyodide_2024_0_wasm32",
+    ]
+    "###
+        );
+    }
+
+    #[test]
+    fn test_pyodide_python_version() {
+        let platform = Platform::new(
+            Os::Pyodide {
+                major: 2024,
+                minor: 0,
+            },
+            Arch::Wasm32,
+        );
+        assert!(
+            Tags::from_env(&platform, (3, 12), "cpython", (3, 12), false, false, false).is_ok()
+        );
+        let err = Tags::from_env(&platform, (3, 11), "cpython", (3, 11), false, false, false)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Pyodide 2024.0 requires Python 3.12, but Python 3.11 was requested"
+        );
+    }
+
     #[test]
     fn test_platform_tags_macos() {
         let tags = compatible_tags(&Platform::new(
@@ -1141,6 +1256,7 @@ mod tests {
             (3, 9),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_snapshot!(
@@ -1182,6 +1298,130 @@ mod tests {
     "###);
     }
 
+    /// Ensure debug builds prefer the debug ABI, but also accept the release ABI.
+    #[test]
+    fn test_debug_tags() {
+        let tags = Tags::from_env(
+            &Platform::new(
+                Os::Manylinux {
+                    major: 2,
+                    minor: 28,
+                },
+                Arch::X86_64,
+            ),
+            (3, 13),
+            "cpython",
+            (3, 13),
+            false,
+            false,
+            true,
+        )
+        .unwrap();
+        assert_snapshot!(
+        tags,
+        @r###"
+    cp313-cp313d-linux_x86_64
+    cp313-cp313-linux_x86_64
+    cp313-abi3-linux_x86_64
+    cp313-none-linux_x86_64
+    cp312-abi3-linux_x86_64
+    cp311-abi3-linux_x86_64
+    cp310-abi3-linux_x86_64
+    cp39-abi3-linux_x86_64
+    cp38-abi3-linux_x86_64
+    cp37-abi3-linux_x86_64
+    cp36-abi3-linux_x86_64
+    cp35-abi3-linux_x86_64
+    cp34-abi3-linux_x86_64
+    cp33-abi3-linux_x86_64
+    cp32-abi3-linux_x86_64
+    py313-none-linux_x86_64
+    py3-none-linux_x86_64
+    py312-none-linux_x86_64
+    py311-none-linux_x86_64
+    py310-none-linux_x86_64
+    py39-none-linux_x86_64
+    py38-none-linux_x86_64
+    py37-none-linux_x86_64
+    py36-none-linux_x86_64
+    py35-none-linux_x86_64
+    py34-none-linux_x86_64
+    py33-none-linux_x86_64
+    py32-none-linux_x86_64
+    py31-none-linux_x86_64
+    py30-none-linux_x86_64
+    cp313-none-any
+    py313-none-any
+    py3-none-any
+    py312-none-any
+    py311-none-any
+    py310-none-any
+    py39-none-any
+    py38-none-any
+    py37-none-any
+    py36-none-any
+    py35-none-any
+    py34-none-any
+    py33-none-any
+    py32-none-any
+    py31-none-any
+    py30-none-any
+    "###);
+    }
+
+    /// Ensure debug builds on Windows don't accept the release ABI, since they only load extension
+    /// modules with a `_d` suffix.
+    #[test]
+    fn test_debug_tags_windows() {
+        let tags = Tags::from_env(
+            &Platform::new(Os::Windows, Arch::X86_64),
+            (3, 13),
+            "cpython",
+            (3, 13),
+            false,
+            false,
+            true,
+        )
+        .unwrap();
+        assert_snapshot!(
+        tags,
+        @r###"
+    cp313-cp313d-win_amd64
+    cp313-none-win_amd64
+    py313-none-win_amd64
+    py3-none-win_amd64
+    py312-none-win_amd64
+    py311-none-win_amd64
+    py310-none-win_amd64
+    py39-none-win_amd64
+    py38-none-win_amd64
+    py37-none-win_amd64
+    py36-none-win_amd64
+    py35-none-win_amd64
+    py34-none-win_amd64
+    py33-none-win_amd64
+    py32-none-win_amd64
+    py31-none-win_amd64
+    py30-none-win_amd64
+    cp313-none-any
+    py313-none-any
+    py3-none-any
+    py312-none-any
+    py311-none-any
+    py310-none-any
+    py39-none-any
+    py38-none-any
+    py37-none-any
+    py36-none-any
+    py35-none-any
+    py34-none-any
+    py33-none-any
+    py32-none-any
+    py31-none-any
+    py30-none-any
+    "###);
+    }
+
     /// Check full tag ordering.
     /// The list is displayed in decreasing priority.
     ///
@@ -1204,6 +1444,7 @@ mod tests {
             (3, 9),
             true,
             false,
+            false,
         )
         .unwrap();
         assert_snapshot!(
@@ -1828,6 +2069,7 @@ mod tests {
             (3, 9),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_snapshot!(
@@ -143,6 +143,7 @@ class ImplementationName(StrEnum):
 class Variant(StrEnum):
     FREETHREADED = "freethreaded"
     DEBUG = "debug"
+    FREETHREADED_DEBUG = "freethreaded+debug"
 
 
 @dataclass
@@ -369,9 +370,12 @@ class CPythonFinder(Finder):
 
         build_options = build_options.split("+") if build_options else []
         variant: Variant | None
-        for variant in Variant:
-            if variant in build_options:
-                break
+        if "freethreaded" in build_options and "debug" in build_options:
+            variant = Variant.FREETHREADED_DEBUG
+        elif "freethreaded" in build_options:
+            variant = Variant.FREETHREADED
+        elif "debug" in build_options:
+            variant = Variant.DEBUG
         else:
             variant = None
 
@@ -660,6 +664,8 @@ def render(downloads: list[PythonDownload]) -> None:
                 return 1
             case Variant.DEBUG:
                 return 2
+            case Variant.FREETHREADED_DEBUG:
+                return 3
         raise ValueError(f"Missing sort key implementation for variant: {variant}")
 
     def sort_key(download: PythonDownload) -> tuple:
@@ -607,6 +607,11 @@ def main() -> None:
             )
             sys.exit(0)
 
+    # Windows does not expose `Py_DEBUG`, but only debug builds provide `sys.gettotalrefcount`.
+    debug_enabled = sysconfig.get_config_var("Py_DEBUG")
+    if debug_enabled is None:
+        debug_enabled = hasattr(sys, "gettotalrefcount")
+
     interpreter_info = {
         "result": "success",
         "markers": markers,
@@ -631,6 +636,8 @@ def main() -> None:
         # The `t` abiflag for freethreading Python.
         # https://peps.python.org/pep-0703/#build-configuration-changes
         "gil_disabled": bool(sysconfig.get_config_var("Py_GIL_DISABLED")),
+        # The `d` abiflag for debug builds, i.e., `--with-pydebug`.
+        "debug_enabled": bool(debug_enabled),
         # Determine if the interpreter is 32-bit or 64-bit.
         # https://github.com/python/cpython/blob/b228655c227b2ca298a8ffac44d14ce3d22f6faa/Lib/venv/__init__.py#L136
         "pointer_size": "64" if sys.maxsize > 2**32 else "32",
@@ -28,6 +28,7 @@ use crate::interpreter::{StatusCodeError, UnexpectedResponseError};
 use crate::managed::ManagedPythonInstallations;
 #[cfg(windows)]
 use crate::microsoft_store::find_microsoft_store_pythons;
+use crate::version_managers::VersionManager;
 use crate::virtualenv::Error as VirtualEnvError;
 use crate::virtualenv::{
     conda_environment_from_env, virtualenv_from_env, virtualenv_from_working_dir,
@@ -150,6 +151,8 @@ pub enum PythonVariant {
     #[default]
     Default,
     Freethreaded,
+    Debug,
+    FreethreadedDebug,
 }
 
 /// A Python discovery version request.
@@ -203,6 +206,12 @@ pub enum PythonSource {
     Registry,
     /// An executable was found in the known Microsoft Store locations
     MicrosoftStore,
+    /// The Python installation was found in the pyenv versions directory
+    Pyenv,
+    /// The Python installation was found in the asdf installs directory
+    Asdf,
+    /// The Python installation was found in the mise installs directory
+    Mise,
     /// The Python installation was found in the uv managed Python directory
     Managed,
     /// The Python installation was found via the invoking interpreter i.e. via `python -m uv ...`
@@ -292,6 +301,7 @@ fn python_executables_from_virtual_environments<'a>(
 ///
 /// - Managed Python installations (e.g. `uv python install`)
 /// - The search path (i.e. `PATH`)
+/// - Installations managed by pyenv, asdf, and mise
 /// - The registry (Windows only)
 ///
 /// The ordering and presence of each source is determined by the [`PythonPreference`].
@@ -348,6 +358,31 @@ fn python_executables_from_installed<'a>(
     })
     .flatten();
 
+    let from_version_managers = iter::once_with(move || {
+        VersionManager::ALL.into_iter().flat_map(move |manager| {
+            manager
+                .python_executables()
+                .into_iter()
+                // Skip installations that we know do not satisfy the request
+                .filter(move |(found, path)| {
+                    if found
+                        .as_ref()
+                        .is_none_or(|found| version.matches_version(found))
+                    {
+                        true
+                    } else {
+                        debug!(
+                            "Skipping incompatible {manager} installation `{}`",
+                            path.user_display()
+                        );
+                        false
+                    }
+                })
+                .map(move |(_, path)| Ok((manager.source(), path)))
+        })
+    })
+    .flatten();
+
     let from_windows_registry = iter::once_with(move || {
         #[cfg(windows)]
         {
@@ -397,14 +432,20 @@ fn python_executables_from_installed<'a>(
         PythonPreference::Managed => Box::new(
             from_managed_installations
                 .chain(from_search_path)
+                .chain(from_version_managers)
                 .chain(from_windows_registry),
         ),
         PythonPreference::System => Box::new(
             from_search_path
+                .chain(from_version_managers)
                 .chain(from_windows_registry)
                 .chain(from_managed_installations),
         ),
-        PythonPreference::OnlySystem => Box::new(from_search_path.chain(from_windows_registry)),
+        PythonPreference::OnlySystem => Box::new(
+            from_search_path
+                .chain(from_version_managers)
+                .chain(from_windows_registry),
+        ),
     }
 }
 
@@ -1369,21 +1410,45 @@ fn is_windows_store_shim(_path: &Path) -> bool {
 
 impl PythonVariant {
     fn matches_interpreter(self, interpreter: &Interpreter) -> bool {
-        match self {
-            PythonVariant::Default => !interpreter.gil_disabled(),
-            PythonVariant::Freethreaded => interpreter.gil_disabled(),
-        }
+        self.is_freethreaded() == interpreter.gil_disabled()
+            && self.is_debug() == interpreter.debug_enabled()
     }
 
-    /// Return the lib or executable suffix for the variant, e.g., `t` for `python3.13t`.
+    /// Return the lib or executable suffix for the variant, e.g., `t` for `python3.13t` or `d`
+    /// for `python3.13d`.
     ///
     /// Returns an empty string for the default Python variant.
     pub fn suffix(self) -> &'static str {
         match self {
             Self::Default => "",
             Self::Freethreaded => "t",
+            Self::Debug => "d",
+            Self::FreethreadedDebug => "td",
+        }
+    }
+
+    /// Return the suffix of the standard library directory for the variant, e.g., `t` for
+    /// `lib/python3.13t`.
+    ///
+    /// Unlike [`PythonVariant::suffix`], debug builds share the standard library directory of the
+    /// default variant.
+    pub fn lib_suffix(self) -> &'static str {
+        if self.is_freethreaded() {
+            "t"
+        } else {
+            ""
         }
     }
+
+    /// Whether this is a free-threaded variant, with or without debug assertions.
+    pub fn is_freethreaded(self) -> bool {
+        matches!(self, Self::Freethreaded | Self::FreethreadedDebug)
+    }
+
+    /// Whether this is a debug build variant, i.e., built with `--with-pydebug`.
+    pub fn is_debug(self) -> bool {
+        matches!(self, Self::Debug | Self::FreethreadedDebug)
+    }
 }
 impl PythonRequest {
     /// Create a request from a string.
@@ -1642,7 +1707,12 @@ impl PythonSource {
     /// Whether a pre-release Python installation from this source can be used without opt-in.
     pub(crate) fn allows_prereleases(self) -> bool {
         match self {
-            Self::Managed | Self::Registry | Self::MicrosoftStore => false,
+            Self::Managed
+            | Self::Registry
+            | Self::MicrosoftStore
+            | Self::Pyenv
+            | Self::Asdf
+            | Self::Mise => false,
             Self::SearchPath
             | Self::SearchPathFirst
             | Self::CondaPrefix
@@ -1663,7 +1733,10 @@ impl PythonSource {
             // TODO(zanieb): We may want to allow this at some point, but when adding this variant
             // we want compatibility with existing behavior
             | Self::SearchPathFirst
-            | Self::MicrosoftStore => false,
+            | Self::MicrosoftStore
+            | Self::Pyenv
+            | Self::Asdf
+            | Self::Mise => false,
             Self::CondaPrefix
             | Self::BaseCondaPrefix
             | Self::ProvidedPath
@@ -1693,7 +1766,13 @@ impl PythonSource {
             | Self::BaseCondaPrefix
             | Self::ParentInterpreter
             | Self::SearchPathFirst => true,
-            Self::Managed | Self::SearchPath | Self::Registry | Self::MicrosoftStore => false,
+            Self::Managed
+            | Self::SearchPath
+            | Self::Registry
+            | Self::MicrosoftStore
+            | Self::Pyenv
+            | Self::Asdf
+            | Self::Mise => false,
         }
     }
 
@@ -1708,7 +1787,10 @@ impl PythonSource {
             | Self::SearchPath
             | Self::SearchPathFirst
             | Self::Registry
-            | Self::MicrosoftStore => true,
+            | Self::MicrosoftStore
+            | Self::Pyenv
+            | Self::Asdf
+            | Self::Mise => true,
             Self::ActiveEnvironment | Self::DiscoveredEnvironment => false,
         }
     }
@@ -1719,7 +1801,12 @@ impl PythonPreference {
         // If not dealing with a system interpreter source, we don't care about the preference
         if !matches!(
             source,
-            PythonSource::Managed | PythonSource::SearchPath | PythonSource::Registry
+            PythonSource::Managed
+                | PythonSource::SearchPath
+                | PythonSource::Registry
+                | PythonSource::Pyenv
+                | PythonSource::Asdf
+                | PythonSource::Mise
         ) {
             return true;
         }
@@ -1728,10 +1815,22 @@ impl PythonPreference {
             PythonPreference::OnlyManaged => matches!(source, PythonSource::Managed),
             Self::Managed | Self::System => matches!(
                 source,
-                PythonSource::Managed | PythonSource::SearchPath | PythonSource::Registry
+                PythonSource::Managed
+                    | PythonSource::SearchPath
+                    | PythonSource::Registry
+                    | PythonSource::Pyenv
+                    | PythonSource::Asdf
+                    | PythonSource::Mise
             ),
             PythonPreference::OnlySystem => {
-                matches!(source, PythonSource::SearchPath | PythonSource::Registry)
+                matches!(
+                    source,
+                    PythonSource::SearchPath
+                        | PythonSource::Registry
+                        | PythonSource::Pyenv
+                        | PythonSource::Asdf
+                        | PythonSource::Mise
+                )
             }
         }
     }
@@ -2004,10 +2103,13 @@ impl VersionRequest {
             }
         }
 
-        // Include free-threaded variants
-        if self.is_freethreaded() {
+        // Include variant-specific names, e.g., `python3.13t` or `python3.13d`
+        if let Some(variant) = self
+            .variant()
+            .filter(|variant| *variant != PythonVariant::Default)
+        {
             for i in 0..names.len() {
-                let name = names[i].with_variant(PythonVariant::Freethreaded);
+                let name = names[i].with_variant(variant);
                 names.push(name);
             }
         }
@@ -2121,6 +2223,9 @@ impl VersionRequest {
                 | PythonSource::SearchPathFirst
                 | PythonSource::Registry
                 | PythonSource::MicrosoftStore
+                | PythonSource::Pyenv
+                | PythonSource::Asdf
+                | PythonSource::Mise
                 | PythonSource::Managed => Self::Default,
             },
             _ => self,
@@ -2315,7 +2420,7 @@ impl VersionRequest {
             | Self::MajorMinor(_, _, variant)
             | Self::MajorMinorPatch(_, _, _, variant)
             | Self::MajorMinorPrerelease(_, _, _, variant)
-            | Self::Range(_, variant) => variant == &PythonVariant::Freethreaded,
+            | Self::Range(_, variant) => variant.is_freethreaded(),
         }
     }
 
@@ -2360,18 +2465,43 @@ impl FromStr for VersionRequest {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // Check if the version request is for a free-threaded Python version
-        let (s, variant) = s
-            .strip_suffix('t')
-            .map_or((s, PythonVariant::Default), |s| {
-                (s, PythonVariant::Freethreaded)
-            });
+        let request = s;
+
+        // Check if the version request includes a variant as a local version, e.g.,
+        // `3.13+freethreaded` or `3.13+freethreaded+debug`
+        let (s, local_variant) = match s.split_once('+') {
+            Some((s, local)) => match local {
+                "freethreaded" => (s, PythonVariant::Freethreaded),
+                "debug" => (s, PythonVariant::Debug),
+                "freethreaded+debug" => (s, PythonVariant::FreethreadedDebug),
+                _ => return Err(Error::InvalidVersionRequest(request.to_string())),
+            },
+            None => (s, PythonVariant::Default),
+        };
+
+        // Check if the version request includes a variant as an ABI suffix, e.g., `3.13t`,
+        // `3.13d`, or `3.13td`
+        let (s, variant) = if let Some(s) = s.strip_suffix("td") {
+            (s, PythonVariant::FreethreadedDebug)
+        } else if let Some(s) = s.strip_suffix('t') {
+            (s, PythonVariant::Freethreaded)
+        } else if let Some(s) = s.strip_suffix('d') {
+            (s, PythonVariant::Debug)
+        } else {
+            (s, PythonVariant::Default)
+        };
 
-        if variant == PythonVariant::Freethreaded && s.ends_with('t') {
-            // More than one trailing "t" is not allowed
-            return Err(Error::InvalidVersionRequest(format!("{s}t")));
+        if variant != PythonVariant::Default && (s.ends_with('t') || s.ends_with('d')) {
+            // Repeated or misordered suffixes, e.g., `3.13tt` or `3.13dt`, are not allowed
+            return Err(Error::InvalidVersionRequest(request.to_string()));
         }
 
+        // If we already have a variant, do not allow another to be requested
+        let variant = match (local_variant, variant) {
+            (variant, PythonVariant::Default) | (PythonVariant::Default, variant) => variant,
+            _ => return Err(Error::InvalidVersionRequest(request.to_string())),
+        };
+
         let Ok(version) = Version::from_str(s) else {
             return parse_version_specifiers_request(s, variant);
         };
@@ -2384,27 +2514,6 @@ impl FromStr for VersionRequest {
             return Err(Error::InvalidVersionRequest(s.to_string()));
         }
 
-        // Check if the local version includes a variant
-        let variant = if version.local().is_empty() {
-            variant
-        } else {
-            // If we already have a variant, do not allow another to be requested
-            if variant != PythonVariant::Default {
-                return Err(Error::InvalidVersionRequest(s.to_string()));
-            }
-
-            let uv_pep440::LocalVersionSlice::Segments([uv_pep440::LocalSegment::String(local)]) =
-                version.local()
-            else {
-                return Err(Error::InvalidVersionRequest(s.to_string()));
-            };
-
-            match local.as_str() {
-                "freethreaded" => PythonVariant::Freethreaded,
-                _ => return Err(Error::InvalidVersionRequest(s.to_string())),
-            }
-        };
-
         // Cast the release components into u8s since that's what we use in `VersionRequest`
         let Ok(release) = try_into_u8_slice(&version.release()) else {
             return Err(Error::InvalidVersionRequest(s.to_string()));
@@ -2455,6 +2564,8 @@ impl FromStr for PythonVariant {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "t" | "freethreaded" => Ok(Self::Freethreaded),
+            "d" | "debug" => Ok(Self::Debug),
+            "td" | "freethreaded+debug" => Ok(Self::FreethreadedDebug),
             "" => Ok(Self::Default),
             _ => Err(()),
         }
@@ -2466,6 +2577,8 @@ impl fmt::Display for PythonVariant {
         match self {
             Self::Default => f.write_str("default"),
             Self::Freethreaded => f.write_str("freethreaded"),
+            Self::Debug => f.write_str("debug"),
+            Self::FreethreadedDebug => f.write_str("freethreaded+debug"),
         }
     }
 }
@@ -2495,23 +2608,15 @@ impl fmt::Display for VersionRequest {
         match self {
             Self::Any => f.write_str("any"),
             Self::Default => f.write_str("default"),
-            Self::Major(major, PythonVariant::Default) => write!(f, "{major}"),
-            Self::Major(major, PythonVariant::Freethreaded) => write!(f, "{major}t"),
-            Self::MajorMinor(major, minor, PythonVariant::Default) => write!(f, "{major}.{minor}"),
-            Self::MajorMinor(major, minor, PythonVariant::Freethreaded) => {
-                write!(f, "{major}.{minor}t")
-            }
-            Self::MajorMinorPatch(major, minor, patch, PythonVariant::Default) => {
-                write!(f, "{major}.{minor}.{patch}")
-            }
-            Self::MajorMinorPatch(major, minor, patch, PythonVariant::Freethreaded) => {
-                write!(f, "{major}.{minor}.{patch}t")
+            Self::Major(major, variant) => write!(f, "{major}{}", variant.suffix()),
+            Self::MajorMinor(major, minor, variant) => {
+                write!(f, "{major}.{minor}{}", variant.suffix())
             }
-            Self::MajorMinorPrerelease(major, minor, prerelease, PythonVariant::Default) => {
-                write!(f, "{major}.{minor}{prerelease}")
+            Self::MajorMinorPatch(major, minor, patch, variant) => {
+                write!(f, "{major}.{minor}.{patch}{}", variant.suffix())
             }
-            Self::MajorMinorPrerelease(major, minor, prerelease, PythonVariant::Freethreaded) => {
-                write!(f, "{major}.{minor}{prerelease}t")
+            Self::MajorMinorPrerelease(major, minor, prerelease, variant) => {
+                write!(f, "{major}.{minor}{prerelease}{}", variant.suffix())
             }
             Self::Range(specifiers, _) => write!(f, "{specifiers}"),
         }
@@ -2549,6 +2654,9 @@ impl fmt::Display for PythonSource {
             Self::SearchPathFirst => f.write_str("first executable in the search path"),
             Self::Registry => f.write_str("registry"),
             Self::MicrosoftStore => f.write_str("Microsoft Store"),
+            Self::Pyenv => f.write_str("pyenv installations"),
+            Self::Asdf => f.write_str("asdf installations"),
+            Self::Mise => f.write_str("mise installations"),
             Self::Managed => f.write_str("managed installations"),
             Self::ParentInterpreter => f.write_str("parent interpreter"),
         }
@@ -3235,6 +3343,34 @@ mod tests {
             VersionRequest::from_str("3.13tt"),
             Err(Error::InvalidVersionRequest(_))
         ));
+        assert_eq!(
+            VersionRequest::from_str("3.13d").unwrap(),
+            VersionRequest::MajorMinor(3, 13, PythonVariant::Debug)
+        );
+        assert_eq!(
+            VersionRequest::from_str("313td").unwrap(),
+            VersionRequest::MajorMinor(3, 13, PythonVariant::FreethreadedDebug)
+        );
+        assert_eq!(
+            VersionRequest::from_str("3.13.2+debug").unwrap(),
+            VersionRequest::MajorMinorPatch(3, 13, 2, PythonVariant::Debug)
+        );
+        assert_eq!(
+            VersionRequest::from_str("3.13+freethreaded+debug").unwrap(),
+            VersionRequest::MajorMinor(3, 13, PythonVariant::FreethreadedDebug)
+        );
+        assert!(matches!(
+            VersionRequest::from_str("3.13dt"),
+            Err(Error::InvalidVersionRequest(_))
+        ));
+        assert!(matches!(
+            VersionRequest::from_str("3.13t+debug"),
+            Err(Error::InvalidVersionRequest(_))
+        ));
+        assert!(matches!(
+            VersionRequest::from_str("3.13+debug+freethreaded"),
+            Err(Error::InvalidVersionRequest(_))
+        ));
     }
 
     #[test]
@@ -3310,6 +3446,17 @@ mod tests {
             ],
         );
         case("3t", &["python3t", "python3", "pythont", "python"]);
+        case(
+            "3.13td",
+            &[
+                "python3.13td",
+                "python3.13",
+                "python3td",
+                "python3",
+                "pythontd",
+                "python",
+            ],
+        );
 
         case(
             "3.13.2",
@@ -104,6 +104,14 @@ pub enum Error {
         url: Box<Url>,
         python_builds_dir: PathBuf,
     },
+    #[error("`{}` is not a known Python distribution archive; the file name must match an entry in the Python downloads list", _0.user_display())]
+    UnknownArchive(PathBuf),
+    #[error("No archive for `{key}` found in {}; expected `{filename}`", dir.user_display())]
+    MissingArchive {
+        key: Box<PythonInstallationKey>,
+        filename: String,
+        dir: PathBuf,
+    },
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -375,7 +383,12 @@ impl PythonDownloadRequest {
 
 impl From<&ManagedPythonInstallation> for PythonDownloadRequest {
     fn from(installation: &ManagedPythonInstallation) -> Self {
-        let key = installation.key();
+        Self::from(installation.key())
+    }
+}
+
+impl From<&PythonInstallationKey> for PythonDownloadRequest {
+    fn from(key: &PythonInstallationKey) -> Self {
         Self::new(
             Some(VersionRequest::from(&key.version())),
             match &key.implementation {
@@ -571,6 +584,52 @@ impl ManagedPythonDownload {
         self.sha256
     }
 
+    /// Return the file name of the distribution archive, as published upstream.
+    pub fn filename(&self) -> Result<String, Error> {
+        let url = Url::parse(self.url)?;
+        let filename = url
+            .path_segments()
+            .ok_or_else(|| Error::InvalidUrlFormat(url.clone()))?
+            .next_back()
+            .ok_or_else(|| Error::InvalidUrlFormat(url.clone()))?
+            .replace("%2B", "+");
+        Ok(filename)
+    }
+
+    /// Return the [`ManagedPythonDownload`] for a local distribution archive.
+    ///
+    /// The archive is identified by its file name, which must match the file name of a known
+    /// download, e.g., `cpython-3.12.4+20240713-x86_64-unknown-linux-gnu-install_only.tar.gz`.
+    pub fn from_archive(
+        archive: &Path,
+        python_downloads_json_url: Option<&str>,
+    ) -> Result<&'static ManagedPythonDownload, Error> {
+        let Some(name) = archive.file_name().and_then(|name| name.to_str()) else {
+            return Err(Error::UnknownArchive(archive.to_path_buf()));
+        };
+        let name = name.replace("%2B", "+");
+        for download in Self::iter_all(python_downloads_json_url)? {
+            if download.filename()? == name {
+                return Ok(download);
+            }
+        }
+        Err(Error::UnknownArchive(archive.to_path_buf()))
+    }
+
+    /// Return the archive for this distribution in a local directory of archives.
+    pub fn find_archive(&self, dir: &Path) -> Result<PathBuf, Error> {
+        let filename = self.filename()?;
+        let archive = dir.join(&filename);
+        if archive.is_file() {
+            return Ok(archive);
+        }
+        Err(Error::MissingArchive {
+            key: Box::new(self.key.clone()),
+            filename,
+            dir: dir.to_path_buf(),
+        })
+    }
+
     /// Download and extract a Python distribution, retrying on failure.
     #[instrument(skip(client, installation_dir, scratch_dir, reporter), fields(download = % self.key()))]
     pub async fn fetch_with_retry(
@@ -746,6 +805,63 @@ impl ManagedPythonDownload {
             .await?;
         }
 
+        self.persist(temp_dir, &filename, &path).await?;
+
+        Ok(DownloadResult::Fetched(path))
+    }
+
+    /// Extract a local Python distribution archive into the installation directory.
+    ///
+    /// The archive is verified against the known checksum of the distribution.
+    #[instrument(skip(installation_dir, scratch_dir, reporter), fields(download = % self.key()))]
+    pub async fn install_from_archive(
+        &self,
+        archive: &Path,
+        installation_dir: &Path,
+        scratch_dir: &Path,
+        reinstall: bool,
+        reporter: Option<&dyn Reporter>,
+    ) -> Result<DownloadResult, Error> {
+        let path = installation_dir.join(self.key().to_string());
+
+        // If it is not a reinstall and the dir already exists, return it.
+        if !reinstall && path.is_dir() {
+            return Ok(DownloadResult::AlreadyAvailable(path));
+        }
+
+        let filename = archive.user_display().to_string();
+        let ext = SourceDistExtension::from_path(archive)
+            .map_err(|err| Error::MissingExtension(filename.clone(), err))?;
+
+        let temp_dir = tempfile::tempdir_in(scratch_dir).map_err(Error::DownloadDirError)?;
+
+        debug!("Extracting `{filename}`");
+        let file = fs_err::tokio::File::open(archive).await?;
+        let size = file.metadata().await?.len();
+        let reader = tokio::io::BufReader::new(file);
+        self.extract_reader(
+            reader,
+            temp_dir.path(),
+            &filename,
+            ext,
+            Some(size),
+            reporter,
+            Direction::Extract,
+        )
+        .await?;
+
+        self.persist(temp_dir, &filename, &path).await?;
+
+        Ok(DownloadResult::Fetched(path))
+    }
+
+    /// Move an extracted distribution from a temporary directory to its installation path.
+    async fn persist(
+        &self,
+        temp_dir: tempfile::TempDir,
+        filename: &str,
+        path: &Path,
+    ) -> Result<(), Error> {
         // Extract the top-level directory.
         let mut extracted = match uv_extract::strip_component(temp_dir.path()) {
             Ok(top_level) => top_level,
@@ -776,19 +892,19 @@ impl ManagedPythonDownload {
         // Remove the target if it already exists.
         if path.is_dir() {
             debug!("Removing existing directory: {}", path.user_display());
-            fs_err::tokio::remove_dir_all(&path).await?;
+            fs_err::tokio::remove_dir_all(path).await?;
         }
 
         // Persist it to the target.
         debug!("Moving {} to {}", extracted.display(), path.user_display());
-        rename_with_retry(extracted, &path)
+        rename_with_retry(extracted, path)
             .await
             .map_err(|err| Error::CopyError {
-                to: path.clone(),
+                to: path.to_path_buf(),
                 err,
             })?;
 
-        Ok(DownloadResult::Fetched(path))
+        Ok(())
     }
 
     /// Download the managed Python archive into the cache directory.
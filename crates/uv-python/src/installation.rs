@@ -356,6 +356,36 @@ impl PythonInstallationKey {
         &self.variant
     }
 
+    /// Returns `true` if self is a suitable upgrade of other, i.e., a newer patch release of the
+    /// same implementation, minor version, and variant.
+    pub fn is_upgrade_of(&self, other: &PythonInstallationKey) -> bool {
+        // Require matching implementation
+        if self.implementation != other.implementation {
+            return false;
+        }
+        // Require a matching variant
+        if self.variant != other.variant {
+            return false;
+        }
+        // Require matching minor version
+        if (self.major, self.minor) != (other.major, other.minor) {
+            return false;
+        }
+        // Require a newer, or equal patch version (for pre-release upgrades)
+        if self.patch <= other.patch {
+            return false;
+        }
+        if let Some(other_pre) = other.prerelease {
+            if let Some(self_pre) = self.prerelease {
+                return self_pre > other_pre;
+            }
+            // Do not upgrade from non-prerelease to prerelease
+            return false;
+        }
+        // Do not upgrade if the patch versions are the same
+        self.patch != other.patch
+    }
+
     /// Return a canonical name for a minor versioned executable.
     pub fn executable_name_minor(&self) -> String {
         format!(
@@ -391,7 +421,7 @@ impl fmt::Display for PythonInstallationKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let variant = match self.variant {
             PythonVariant::Default => String::new(),
-            PythonVariant::Freethreaded => format!("+{}", self.variant),
+            _ => format!("+{}", self.variant),
         };
         write!(
             f,
@@ -4,6 +4,7 @@ use std::fmt::{Display, Formatter};
 use std::io;
 use std::path::{Path, PathBuf};
 use std::process::{Command, ExitStatus};
+use std::str::FromStr;
 use std::sync::OnceLock;
 
 use configparser::ini::Ini;
@@ -20,14 +21,16 @@ use uv_cache_key::cache_digest;
 use uv_fs::{write_atomic_sync, PythonExt, Simplified};
 use uv_install_wheel::Layout;
 use uv_pep440::Version;
-use uv_pep508::{MarkerEnvironment, StringVersion};
+use uv_pep508::{MarkerEnvironment, MarkerEnvironmentBuilder, StringVersion};
 use uv_platform_tags::Platform;
 use uv_platform_tags::{Tags, TagsError};
 use uv_pypi_types::{ResolverMarkerEnvironment, Scheme};
 
-use crate::implementation::LenientImplementationName;
+use crate::implementation::{ImplementationName, LenientImplementationName};
+use crate::libc::{detect_linux_libc, LibcVersion};
 use crate::platform::{Arch, Libc, Os};
 use crate::pointer_size::PointerSize;
+use crate::sysconfig;
 use crate::{
     Prefix, PythonInstallationKey, PythonVariant, PythonVersion, Target, VersionRequest,
     VirtualEnvironment,
@@ -54,6 +57,7 @@ pub struct Interpreter {
     prefix: Option<Prefix>,
     pointer_size: PointerSize,
     gil_disabled: bool,
+    debug_enabled: bool,
     real_executable: PathBuf,
 }
 
@@ -78,6 +82,7 @@ impl Interpreter {
             sys_base_exec_prefix: info.sys_base_exec_prefix,
             pointer_size: info.pointer_size,
             gil_disabled: info.gil_disabled,
+            debug_enabled: info.debug_enabled,
             sys_base_prefix: info.sys_base_prefix,
             sys_base_executable: info.sys_base_executable,
             sys_executable: info.sys_executable,
@@ -163,7 +168,7 @@ impl Interpreter {
             base_executable,
             self.python_major(),
             self.python_minor(),
-            self.variant().suffix(),
+            self.variant().lib_suffix(),
         ) {
             Ok(path) => path,
             Err(err) => {
@@ -207,10 +212,11 @@ impl Interpreter {
     }
 
     pub fn variant(&self) -> PythonVariant {
-        if self.gil_disabled() {
-            PythonVariant::Freethreaded
-        } else {
-            PythonVariant::default()
+        match (self.gil_disabled(), self.debug_enabled()) {
+            (false, false) => PythonVariant::Default,
+            (true, false) => PythonVariant::Freethreaded,
+            (false, true) => PythonVariant::Debug,
+            (true, true) => PythonVariant::FreethreadedDebug,
         }
     }
 
@@ -239,6 +245,7 @@ impl Interpreter {
                 self.implementation_tuple(),
                 self.manylinux_compatible,
                 self.gil_disabled,
+                self.debug_enabled,
             )?;
             self.tags.set(tags).expect("tags should not be set");
         }
@@ -465,6 +472,14 @@ impl Interpreter {
         self.gil_disabled
     }
 
+    /// Return whether this is a debug build of Python, i.e., built with `--with-pydebug`, as
+    /// specified by the sysconfig var `Py_DEBUG`.
+    ///
+    /// Debug builds add a `d` flag to the ABI tag, e.g., `cp313d`.
+    pub fn debug_enabled(&self) -> bool {
+        self.debug_enabled
+    }
+
     /// Return the `--target` directory for this interpreter, if any.
     pub fn target(&self) -> Option<&Target> {
         self.target.as_ref()
@@ -772,6 +787,7 @@ struct InterpreterInfo {
     standalone: bool,
     pointer_size: PointerSize,
     gil_disabled: bool,
+    debug_enabled: bool,
 }
 
 impl InterpreterInfo {
@@ -846,6 +862,146 @@ impl InterpreterInfo {
         }
     }
 
+    /// Derive the [`InterpreterInfo`] for a managed `python-build-standalone` installation from its
+    /// installation key and `sysconfig` data, without spawning the interpreter.
+    ///
+    /// Returns `None` if the executable is not part of a managed installation, or if any of the
+    /// interpreter's properties can't be determined statically, in which case the interpreter
+    /// should be queried instead.
+    ///
+    /// Currently, only CPython 3.10 and later on glibc-based Linux is supported; other platforms,
+    /// implementations, and versions always fall back to querying the interpreter.
+    fn from_managed_installation(executable: &Path) -> Option<Self> {
+        if !cfg!(target_os = "linux") {
+            return None;
+        }
+
+        let sys_executable = std::path::absolute(executable).ok()?;
+        let (prefix, key) = managed_installation_prefix(&sys_executable)?;
+        let bin = prefix.join("bin");
+
+        if key.implementation != LenientImplementationName::Known(ImplementationName::CPython) {
+            return None;
+        }
+
+        // Prior to Python 3.10, the installation scheme is derived from `distutils`.
+        if (key.major, key.minor) < (3, 10) {
+            return None;
+        }
+
+        // The interpreter must be native to the host.
+        if *key.os != target_lexicon::OperatingSystem::Linux
+            || key.arch.family() != Arch::from_env().family()
+        {
+            return None;
+        }
+
+        // Like `platform.machine()`, i.e., `uname -m`.
+        let platform_machine = fs::read_to_string("/proc/sys/kernel/arch").ok()?;
+        let platform_machine = platform_machine.trim();
+        let (arch, pointer_size) = managed_linux_platform(&key, platform_machine)?;
+
+        // Like `platform.release()` and `platform.version()`, i.e., `uname -r` and `uname -v`.
+        let platform_release = fs::read_to_string("/proc/sys/kernel/osrelease").ok()?;
+        let platform_version = fs::read_to_string("/proc/sys/kernel/version").ok()?;
+
+        // Like `os.confstr("CS_GNU_LIBC_VERSION")`, the host's glibc is used at runtime.
+        let LibcVersion::Manylinux { major, minor } = detect_linux_libc().ok()? else {
+            return None;
+        };
+        let os = uv_platform_tags::Os::Manylinux {
+            major: u16::try_from(major).ok()?,
+            minor: u16::try_from(minor).ok()?,
+        };
+
+        // Ensure the build matches the installation key.
+        let build =
+            sysconfig::read_build_config(&prefix, key.major, key.minor, key.variant.lib_suffix())
+                .ok()?;
+        if !build.standalone
+            || build.gil_disabled != key.variant.is_freethreaded()
+            || build.debug_enabled != key.variant.is_debug()
+            || build
+                .platlibdir
+                .as_deref()
+                .is_some_and(|platlibdir| platlibdir != "lib")
+        {
+            return None;
+        }
+
+        let python_full_version = key.version().to_string();
+        let python_version = format!("{}.{}", key.major, key.minor);
+        let markers = MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
+            implementation_name: "cpython",
+            implementation_version: &python_full_version,
+            os_name: "posix",
+            platform_machine,
+            platform_python_implementation: "CPython",
+            platform_release: platform_release.trim(),
+            platform_system: "Linux",
+            platform_version: platform_version.trim(),
+            python_full_version: &python_full_version,
+            python_version: &python_version,
+            sys_platform: "linux",
+        })
+        .ok()?;
+
+        // The `posix_prefix` and `venv` schemes, respectively.
+        let lib = format!("python{python_version}{}", key.variant.lib_suffix());
+        let stdlib = prefix.join("lib").join(&lib);
+        let site_packages = stdlib.join("site-packages");
+        let scheme = Scheme {
+            purelib: site_packages.clone(),
+            platlib: site_packages.clone(),
+            scripts: bin,
+            data: prefix.clone(),
+            include: prefix
+                .join("include")
+                .join(format!("python{python_version}{}", key.variant.suffix())),
+        };
+        let virtualenv = Scheme {
+            purelib: Path::new("lib").join(&lib).join("site-packages"),
+            platlib: Path::new("lib").join(&lib).join("site-packages"),
+            scripts: PathBuf::from("bin"),
+            data: PathBuf::new(),
+            include: Path::new("include")
+                .join("site")
+                .join(format!("python{python_version}")),
+        };
+
+        let sys_path = vec![
+            prefix.join("lib").join(format!(
+                "python{}{}{}.zip",
+                key.major,
+                key.minor,
+                key.variant.lib_suffix()
+            )),
+            stdlib.clone(),
+            stdlib.join("lib-dynload"),
+            site_packages,
+        ];
+
+        Some(Self {
+            platform: Platform::new(os, arch),
+            markers,
+            scheme,
+            virtualenv,
+            // Like `packaging`, absent a `_manylinux` module, which standalone builds don't ship.
+            manylinux_compatible: true,
+            sys_prefix: prefix.clone(),
+            sys_base_exec_prefix: prefix.clone(),
+            sys_base_prefix: prefix,
+            sys_base_executable: Some(sys_executable.clone()),
+            sys_executable,
+            sys_path,
+            stdlib,
+            standalone: true,
+            pointer_size,
+            gil_disabled: key.variant.is_freethreaded(),
+            debug_enabled: key.variant.is_debug(),
+        })
+    }
+
     /// Duplicate the directory structure we have in `../python` into a tempdir, so we can run
     /// the Python probing scripts with `python -m python.get_interpreter_info` from that tempdir.
     fn setup_python_query_files(root: &Path) -> Result<(), Error> {
@@ -964,12 +1120,21 @@ impl InterpreterInfo {
             }
         }
 
-        // Otherwise, run the Python script.
-        trace!(
-            "Querying interpreter executable at {}",
-            executable.display()
-        );
-        let info = Self::query(executable, cache)?;
+        // Otherwise, derive the interpreter info from the managed installation, if possible, or
+        // run the Python script.
+        let info = if let Some(info) = Self::from_managed_installation(executable) {
+            trace!(
+                "Derived interpreter info from managed installation at {}",
+                executable.display()
+            );
+            info
+        } else {
+            trace!(
+                "Querying interpreter executable at {}",
+                executable.display()
+            );
+            Self::query(executable, cache)?
+        };
 
         // If `executable` is a pyenv shim, a bash script that redirects to the activated
         // python executable at another path, we're not allowed to cache the interpreter info.
@@ -988,6 +1153,76 @@ impl InterpreterInfo {
     }
 }
 
+/// Return the prefix and installation key of the managed installation to which the executable
+/// belongs, if it's the installation's own executable.
+///
+/// Managed installations are laid out as `{key}/[install/]bin/python{major}.{minor}`. Executables
+/// that merely link to a managed installation, like that of a virtual environment, are excluded, as
+/// their `sys.prefix` (and, in turn, installation scheme) differs from that of the installation.
+fn managed_installation_prefix(executable: &Path) -> Option<(PathBuf, PythonInstallationKey)> {
+    // A virtual environment, whose executable links to the base interpreter.
+    let bin = executable.parent()?;
+    if bin.parent()?.join("pyvenv.cfg").is_file() {
+        return None;
+    }
+
+    // A link from outside the installation (e.g., `~/.local/bin/python3.12`) to its executable.
+    let real_executable = uv_fs::canonicalize_executable(executable).ok()?;
+    let real_bin = real_executable.parent()?;
+    if fs::canonicalize(bin).ok()? != real_bin {
+        return None;
+    }
+
+    if real_bin.file_name()? != "bin" {
+        return None;
+    }
+    let prefix = real_bin.parent()?;
+    let root = if prefix.file_name()? == "install" {
+        prefix.parent()?
+    } else {
+        prefix
+    };
+    let key = PythonInstallationKey::from_str(root.file_name()?.to_str()?).ok()?;
+    Some((prefix.to_path_buf(), key))
+}
+
+/// Derive the architecture tag and pointer size of a managed installation on Linux from its
+/// installation key and the kernel's machine name (i.e., `uname -m`).
+///
+/// Returns `None` if the installation must be queried instead, e.g., if it targets musl.
+fn managed_linux_platform(
+    key: &PythonInstallationKey,
+    platform_machine: &str,
+) -> Option<(uv_platform_tags::Arch, PointerSize)> {
+    // The platform tag of musl builds depends on the interpreter's own `libc`.
+    if !matches!(
+        key.libc,
+        Libc::Some(
+            target_lexicon::Environment::Gnu
+                | target_lexicon::Environment::Gnueabi
+                | target_lexicon::Environment::Gnueabihf
+        )
+    ) {
+        return None;
+    }
+
+    let pointer_size = match key.arch.family().pointer_width().ok()? {
+        target_lexicon::PointerWidth::U32 => PointerSize::_32,
+        target_lexicon::PointerWidth::U64 => PointerSize::_64,
+        target_lexicon::PointerWidth::U16 => return None,
+    };
+
+    // Like `packaging`, a 32-bit interpreter on a 64-bit kernel uses the 32-bit architecture.
+    let machine = match (pointer_size, platform_machine) {
+        (PointerSize::_32, "x86_64") => "i686",
+        (PointerSize::_32, "aarch64" | "armv8l") => "armv7l",
+        (_, machine) => machine,
+    };
+    let arch = uv_platform_tags::Arch::from_str(machine).ok()?;
+
+    Some((arch, pointer_size))
+}
+
 /// Find the Python executable that should be considered the "base" for a virtual environment.
 ///
 /// Assumes that the provided executable is that of a standalone Python interpreter.
@@ -1146,7 +1381,8 @@ mod tests {
                 "scripts": "bin"
             },
             "pointer_size": "64",
-            "gil_disabled": true
+            "gil_disabled": true,
+            "debug_enabled": false
         }
     "##};
 
@@ -1185,4 +1421,73 @@ mod tests {
             Version::from_str("3.13").unwrap()
         );
     }
+
+    #[test]
+    #[cfg(unix)]
+    fn managed_installation_prefix() {
+        let temp_dir = tempdir().unwrap();
+        let root = temp_dir.path().canonicalize().unwrap();
+
+        // A managed AArch64 installation, with a `python` link alongside its executable.
+        let prefix = root.join("cpython-3.12.9-linux-aarch64-gnu");
+        fs::create_dir_all(prefix.join("bin")).unwrap();
+        let executable = prefix.join("bin").join("python3.12");
+        fs::write(&executable, "").unwrap();
+        std::os::unix::fs::symlink("python3.12", prefix.join("bin").join("python")).unwrap();
+
+        let (install_prefix, key) = super::managed_installation_prefix(&executable).unwrap();
+        assert_eq!(install_prefix, prefix);
+        assert_eq!(key.to_string(), "cpython-3.12.9-linux-aarch64-gnu");
+        assert!(super::managed_installation_prefix(&prefix.join("bin").join("python")).is_some());
+
+        // A virtual environment created from the installation.
+        let venv = root.join(".venv");
+        fs::create_dir_all(venv.join("bin")).unwrap();
+        fs::write(venv.join("pyvenv.cfg"), "").unwrap();
+        std::os::unix::fs::symlink(&executable, venv.join("bin").join("python")).unwrap();
+        assert!(super::managed_installation_prefix(&venv.join("bin").join("python")).is_none());
+
+        // A link to the installation's executable from elsewhere.
+        let links = root.join("links");
+        fs::create_dir_all(&links).unwrap();
+        std::os::unix::fs::symlink(&executable, links.join("python3.12")).unwrap();
+        assert!(super::managed_installation_prefix(&links.join("python3.12")).is_none());
+    }
+
+    #[test]
+    fn managed_linux_platform() {
+        use uv_platform_tags::Arch;
+
+        use crate::{PointerSize, PythonInstallationKey};
+
+        let platform = |key: &str, machine: &str| {
+            super::managed_linux_platform(&PythonInstallationKey::from_str(key).unwrap(), machine)
+        };
+
+        assert!(matches!(
+            platform("cpython-3.12.9-linux-x86_64-gnu", "x86_64"),
+            Some((Arch::X86_64, PointerSize::_64))
+        ));
+        assert!(matches!(
+            platform("cpython-3.12.9-linux-aarch64-gnu", "aarch64"),
+            Some((Arch::Aarch64, PointerSize::_64))
+        ));
+        assert!(matches!(
+            platform("cpython-3.12.9-linux-armv7-gnueabihf", "armv7l"),
+            Some((Arch::Armv7L, PointerSize::_32))
+        ));
+
+        // A 32-bit interpreter on a 64-bit kernel.
+        assert!(matches!(
+            platform("cpython-3.12.9-linux-x86-gnu", "x86_64"),
+            Some((Arch::X86, PointerSize::_32))
+        ));
+        assert!(matches!(
+            platform("cpython-3.12.9-linux-armv7-gnueabihf", "aarch64"),
+            Some((Arch::Armv7L, PointerSize::_32))
+        ));
+
+        // musl builds are queried instead.
+        assert!(platform("cpython-3.12.9-linux-x86_64-musl", "x86_64").is_none());
+    }
 }
@@ -17,8 +17,10 @@ pub use crate::prefix::Prefix;
 pub use crate::python_version::PythonVersion;
 pub use crate::target::Target;
 pub use crate::version_files::{
-    DiscoveryOptions as VersionFileDiscoveryOptions, FilePreference as VersionFilePreference,
-    PythonVersionFile, PYTHON_VERSIONS_FILENAME, PYTHON_VERSION_FILENAME,
+    DiscoveryOptions as VersionFileDiscoveryOptions, FileKind as VersionFileKind,
+    FilePreference as VersionFilePreference, PythonVersionFile, HIDDEN_MISE_TOML_FILENAME,
+    MISE_TOML_FILENAME, PYTHON_VERSIONS_FILENAME, PYTHON_VERSION_FILENAME, RUNTIME_TXT_FILENAME,
+    TOOL_VERSIONS_FILENAME,
 };
 pub use crate::virtualenv::{Error as VirtualEnvError, PyVenvConfiguration, VirtualEnvironment};
 
@@ -41,6 +43,7 @@ mod python_version;
 mod sysconfig;
 mod target;
 mod version_files;
+mod version_managers;
 mod virtualenv;
 #[cfg(windows)]
 pub mod windows_registry;
@@ -188,6 +191,9 @@ mod tests {
                 .search_path
                 .as_ref()
                 .map(|paths| env::join_paths(paths).unwrap());
+            let pyenv_root = self.tempdir.child("pyenv");
+            let asdf_data_dir = self.tempdir.child("asdf");
+            let mise_data_dir = self.tempdir.child("mise");
 
             let mut run_vars = vec![
                 // Ensure `PATH` is used
@@ -202,6 +208,10 @@ mod tests {
                 ),
                 // Set a working directory
                 ("PWD", Some(self.workdir.path().as_os_str())),
+                // Ignore Python installations managed by other tools (i.e. that the dev is using)
+                (EnvVars::PYENV_ROOT, Some(pyenv_root.as_os_str())),
+                (EnvVars::ASDF_DATA_DIR, Some(asdf_data_dir.as_os_str())),
+                (EnvVars::MISE_DATA_DIR, Some(mise_data_dir.as_os_str())),
             ];
             for (key, value) in vars {
                 run_vars.push((key, *value));
@@ -268,7 +278,8 @@ mod tests {
                         "scripts": "bin"
                     },
                     "pointer_size": "64",
-                    "gil_disabled": {FREE_THREADED}
+                    "gil_disabled": {FREE_THREADED},
+                    "debug_enabled": false
                 }
             "##};
 
@@ -2500,4 +2511,88 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn find_python_version_from_version_managers() -> Result<()> {
+        let mut context = TestContext::new()?;
+        context.search_path = Some(vec![]);
+
+        let pyenv_bin = context.tempdir.child("pyenv/versions/3.11.4/bin");
+        pyenv_bin.create_dir_all()?;
+        TestContext::create_mock_interpreter(
+            &pyenv_bin.join("python3"),
+            &PythonVersion::from_str("3.11.4").unwrap(),
+            ImplementationName::CPython,
+            true,
+            false,
+        )?;
+        let mise_bin = context.tempdir.child("mise/installs/python/3.12.1/bin");
+        mise_bin.create_dir_all()?;
+        TestContext::create_mock_interpreter(
+            &mise_bin.join("python3"),
+            &PythonVersion::from_str("3.12.1").unwrap(),
+            ImplementationName::CPython,
+            true,
+            false,
+        )?;
+
+        let python = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("3.11.4"),
+                EnvironmentPreference::Any,
+                PythonPreference::default(),
+                &context.cache,
+            )
+        })??;
+        assert!(
+            matches!(
+                python,
+                PythonInstallation {
+                    source: PythonSource::Pyenv,
+                    interpreter: _
+                }
+            ),
+            "We should find the pyenv installation; got {python:?}"
+        );
+        assert_eq!(
+            &python.interpreter().python_full_version().to_string(),
+            "3.11.4",
+            "We should find the correct interpreter for the request"
+        );
+
+        let python = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("3.12"),
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                &context.cache,
+            )
+        })??;
+        assert!(
+            matches!(
+                python,
+                PythonInstallation {
+                    source: PythonSource::Mise,
+                    interpreter: _
+                }
+            ),
+            "We should find the mise installation; got {python:?}"
+        );
+
+        // Installations managed by other tools are not managed by uv
+        let result = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("3.11.4"),
+                EnvironmentPreference::Any,
+                PythonPreference::OnlyManaged,
+                &context.cache,
+            )
+        })?;
+        assert!(
+            matches!(result, Err(PythonNotFound { .. })),
+            "We should not find the pyenv installation with only managed Python; got {result:?}"
+        );
+
+        Ok(())
+    }
 }
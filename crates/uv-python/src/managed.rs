@@ -16,7 +16,7 @@ use uv_state::{StateBucket, StateStore};
 use uv_static::EnvVars;
 use uv_trampoline_builder::{windows_python_launcher, Launcher};
 
-use crate::downloads::{Error as DownloadError, ManagedPythonDownload};
+use crate::downloads::{Error as DownloadError, ManagedPythonDownload, PythonDownloadRequest};
 use crate::implementation::{
     Error as ImplementationError, ImplementationName, LenientImplementationName,
 };
@@ -25,7 +25,7 @@ use crate::libc::LibcDetectionError;
 use crate::platform::Error as PlatformError;
 use crate::platform::{Arch, Libc, Os};
 use crate::python_version::PythonVersion;
-use crate::{macos_dylib, sysconfig, PythonRequest, PythonVariant};
+use crate::{macos_dylib, sysconfig, PythonRequest, PythonVariant, VersionRequest};
 
 #[derive(Error, Debug)]
 pub enum Error {
@@ -510,7 +510,7 @@ impl ManagedPythonInstallation {
         let stdlib = if matches!(self.key.os, Os(target_lexicon::OperatingSystem::Windows)) {
             self.python_dir().join("Lib")
         } else {
-            let lib_suffix = self.key.variant.suffix();
+            let lib_suffix = self.key.variant.lib_suffix();
             let python = if matches!(
                 self.key.implementation,
                 LenientImplementationName::Known(ImplementationName::PyPy)
@@ -536,7 +536,7 @@ impl ManagedPythonInstallation {
                     self.path(),
                     self.key.major,
                     self.key.minor,
-                    self.key.variant.suffix(),
+                    self.key.variant.lib_suffix(),
                 )?;
             }
         }
@@ -633,31 +633,33 @@ impl ManagedPythonInstallation {
 
     /// Returns `true` if self is a suitable upgrade of other.
     pub fn is_upgrade_of(&self, other: &ManagedPythonInstallation) -> bool {
-        // Require matching implementation
-        if self.key.implementation != other.key.implementation {
-            return false;
-        }
-        // Require a matching variant
-        if self.key.variant != other.key.variant {
-            return false;
-        }
-        // Require matching minor version
-        if (self.key.major, self.key.minor) != (other.key.major, other.key.minor) {
-            return false;
-        }
-        // Require a newer, or equal patch version (for pre-release upgrades)
-        if self.key.patch <= other.key.patch {
-            return false;
-        }
-        if let Some(other_pre) = other.key.prerelease {
-            if let Some(self_pre) = self.key.prerelease {
-                return self_pre > other_pre;
-            }
-            // Do not upgrade from non-prerelease to prerelease
-            return false;
-        }
-        // Do not upgrade if the patch versions are the same
-        self.key.patch != other.key.patch
+        self.key.is_upgrade_of(&other.key)
+    }
+
+    /// Returns the newest available [`ManagedPythonDownload`] that is a suitable upgrade of this
+    /// installation (i.e., the latest patch release of the same minor version), if any.
+    pub fn find_upgrade(
+        &self,
+        python_downloads_json_url: Option<&str>,
+    ) -> Result<Option<&'static ManagedPythonDownload>, Error> {
+        let request = PythonDownloadRequest::new(
+            Some(VersionRequest::MajorMinor(
+                self.key.major,
+                self.key.minor,
+                self.key.variant,
+            )),
+            Some(*self.implementation()),
+            Some(self.key.arch),
+            Some(self.key.os),
+            Some(self.key.libc),
+            Some(self.key.prerelease.is_some()),
+        );
+        Ok(request
+            .iter_downloads(python_downloads_json_url)?
+            // Require an exact match on the architecture, rather than a compatible one.
+            .filter(|download| download.key().arch == self.key.arch)
+            .filter(|download| download.key().is_upgrade_of(&self.key))
+            .max_by(|a, b| a.key().cmp(b.key())))
     }
 
     pub fn url(&self) -> Option<&'static str> {
@@ -696,3 +698,21 @@ pub fn python_executable_dir() -> Result<PathBuf, Error> {
     uv_dirs::user_executable_directory(Some(EnvVars::UV_PYTHON_BIN_DIR))
         .ok_or(Error::NoExecutableDirectory)
 }
+
+/// Find the directory to install Python shims into.
+///
+/// Prefer, in order:
+///
+/// 1. The specific directory specified with the `UV_PYTHON_SHIM_DIR` environment variable.
+/// 2. A `python-shims` directory next to the Python executable directory, e.g.,
+///    `~/.local/python-shims` when executables are installed to `~/.local/bin`.
+pub fn python_shim_dir() -> Result<PathBuf, Error> {
+    if let Some(shim_dir) = std::env::var_os(EnvVars::UV_PYTHON_SHIM_DIR).filter(|s| !s.is_empty())
+    {
+        Ok(std::path::absolute(shim_dir)?)
+    } else {
+        let bin = python_executable_dir()?;
+        let parent = bin.parent().ok_or(Error::NoExecutableDirectory)?;
+        Ok(parent.join("python-shims"))
+    }
+}
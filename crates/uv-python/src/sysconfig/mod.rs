@@ -203,6 +203,48 @@ pub(crate) fn update_sysconfig(
     Ok(())
 }
 
+/// The build configuration of a Python installation, as recorded in its `_sysconfigdata_` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BuildConfig {
+    /// Whether the build is a `python-build-standalone` build.
+    pub(crate) standalone: bool,
+    /// Whether the build is free-threaded, i.e., `Py_GIL_DISABLED`.
+    pub(crate) gil_disabled: bool,
+    /// Whether the build is a debug build, i.e., `Py_DEBUG`.
+    pub(crate) debug_enabled: bool,
+    /// The `platlibdir` of the build (e.g., `lib` or `lib64`).
+    pub(crate) platlibdir: Option<String>,
+}
+
+impl BuildConfig {
+    fn from_sysconfigdata(data: &SysconfigData) -> Self {
+        let flag = |key: &str| matches!(data.get(key), Some(Value::Int(value)) if *value != 0);
+        let string = |key: &str| match data.get(key) {
+            Some(Value::String(value)) => Some(value.as_str()),
+            _ => None,
+        };
+        Self {
+            standalone: flag("PYTHON_BUILD_STANDALONE") || string("prefix") == Some("/install"),
+            gil_disabled: flag("Py_GIL_DISABLED"),
+            debug_enabled: flag("Py_DEBUG"),
+            platlibdir: string("PLATLIBDIR").map(ToString::to_string),
+        }
+    }
+}
+
+/// Read the build configuration from the `sysconfig` data in a Python installation.
+pub(crate) fn read_build_config(
+    install_root: &Path,
+    major: u8,
+    minor: u8,
+    suffix: &str,
+) -> Result<BuildConfig, Error> {
+    let sysconfigdata = find_sysconfigdata(install_root, major, minor, suffix)?;
+    let contents = fs_err::read_to_string(&sysconfigdata)?;
+    let data = SysconfigData::from_str(&contents)?;
+    Ok(BuildConfig::from_sysconfigdata(&data))
+}
+
 /// Find the `_sysconfigdata_` file in a Python installation.
 ///
 /// For example, on macOS, returns `{real_prefix}/lib/python3.12/_sysconfigdata__darwin_darwin.py"`.
@@ -401,6 +443,46 @@ mod tests {
     use super::*;
     use indoc::indoc;
 
+    #[test]
+    fn build_config() {
+        let sysconfigdata = [
+            ("PLATLIBDIR", Value::String("lib".to_string())),
+            ("PYTHON_BUILD_STANDALONE", Value::Int(1)),
+            ("Py_DEBUG", Value::Int(0)),
+            ("Py_GIL_DISABLED", Value::Int(1)),
+            ("prefix", Value::String("/real/prefix".to_string())),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect::<SysconfigData>();
+
+        assert_eq!(
+            BuildConfig::from_sysconfigdata(&sysconfigdata),
+            BuildConfig {
+                standalone: true,
+                gil_disabled: true,
+                debug_enabled: false,
+                platlibdir: Some("lib".to_string()),
+            }
+        );
+
+        // Builds prior to `sysconfig` patching only record the `/install` prefix.
+        let sysconfigdata = [("prefix", Value::String("/install".to_string()))]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect::<SysconfigData>();
+
+        assert_eq!(
+            BuildConfig::from_sysconfigdata(&sysconfigdata),
+            BuildConfig {
+                standalone: true,
+                gil_disabled: false,
+                debug_enabled: false,
+                platlibdir: None,
+            }
+        );
+    }
+
     #[test]
     fn update_real_prefix() -> Result<(), Error> {
         let sysconfigdata = [
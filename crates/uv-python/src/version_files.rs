@@ -3,6 +3,7 @@ use std::path::{Path, PathBuf};
 
 use fs_err as fs;
 use itertools::Itertools;
+use toml_edit::{DocumentMut, Item};
 use tracing::debug;
 use uv_dirs::user_uv_config_dir;
 use uv_fs::Simplified;
@@ -16,7 +17,191 @@ pub static PYTHON_VERSION_FILENAME: &str = ".python-version";
 /// The file name for multiple Python version declarations.
 pub static PYTHON_VERSIONS_FILENAME: &str = ".python-versions";
 
-/// A `.python-version` or `.python-versions` file.
+/// The file name for asdf and mise tool version declarations.
+pub static TOOL_VERSIONS_FILENAME: &str = ".tool-versions";
+
+/// The file name for mise configuration.
+pub static MISE_TOML_FILENAME: &str = "mise.toml";
+
+/// The file name for hidden mise configuration.
+pub static HIDDEN_MISE_TOML_FILENAME: &str = ".mise.toml";
+
+/// The file name for Heroku-style runtime declarations.
+pub static RUNTIME_TXT_FILENAME: &str = "runtime.txt";
+
+/// The format of a Python version file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+    /// A `.python-version` file.
+    PythonVersion,
+    /// A `.python-versions` file.
+    PythonVersions,
+    /// An asdf or mise `.tool-versions` file, e.g., `python 3.12.1`.
+    ToolVersions,
+    /// A mise `mise.toml` file, e.g., `python = "3.12"` in the `[tools]` table.
+    MiseToml,
+    /// A Heroku-style `runtime.txt` file, e.g., `python-3.12.1`.
+    RuntimeTxt,
+}
+
+impl FileKind {
+    /// Determine the kind of a version file from its file name.
+    ///
+    /// Unknown file names are treated as `.python-version` files.
+    fn from_path(path: &Path) -> Self {
+        match path.file_name().and_then(|name| name.to_str()) {
+            Some(name) if name == PYTHON_VERSIONS_FILENAME => Self::PythonVersions,
+            Some(name) if name == TOOL_VERSIONS_FILENAME => Self::ToolVersions,
+            Some(name) if name == MISE_TOML_FILENAME || name == HIDDEN_MISE_TOML_FILENAME => {
+                Self::MiseToml
+            }
+            Some(name) if name == RUNTIME_TXT_FILENAME => Self::RuntimeTxt,
+            _ => Self::PythonVersion,
+        }
+    }
+
+    /// Whether this file is owned by another tool, i.e., it is not a `.python-version` or
+    /// `.python-versions` file.
+    pub fn is_foreign(self) -> bool {
+        match self {
+            Self::PythonVersion | Self::PythonVersions => false,
+            Self::ToolVersions | Self::MiseToml | Self::RuntimeTxt => true,
+        }
+    }
+
+    /// Whether this file requires a full Python version, e.g., `3.12.1` rather than `3.12`.
+    ///
+    /// asdf and Heroku do not resolve partial versions.
+    pub fn requires_patch_version(self) -> bool {
+        matches!(self, Self::ToolVersions | Self::RuntimeTxt)
+    }
+
+    /// Parse the Python versions declared in the content of a file of this kind.
+    ///
+    /// Returns `None` if the file does not declare a Python version, e.g., a `.tool-versions` file
+    /// without a `python` entry.
+    fn parse(self, content: &str) -> Option<Vec<String>> {
+        match self {
+            Self::PythonVersion | Self::PythonVersions => Some(
+                content
+                    .lines()
+                    .map(str::trim)
+                    // Skip comments and empty lines.
+                    .filter(|line| !(line.is_empty() || line.starts_with('#')))
+                    .map(ToString::to_string)
+                    .collect(),
+            ),
+            Self::ToolVersions => content.lines().find_map(|line| {
+                // Strip trailing comments, e.g., `python 3.12.1 # the default`.
+                let line = line.split_once('#').map_or(line, |(line, _)| line);
+                let mut parts = line.split_whitespace();
+                (parts.next() == Some("python"))
+                    .then(|| parts.map(ToString::to_string).collect::<Vec<_>>())
+            }),
+            Self::MiseToml => {
+                let document = match content.parse::<DocumentMut>() {
+                    Ok(document) => document,
+                    Err(err) => {
+                        debug!("Failed to parse mise configuration: {err}");
+                        return None;
+                    }
+                };
+                let python = document.get("tools")?.get("python")?;
+                // The version can be a string, a table with a `version` key, or an array of either.
+                let version = |item: &toml_edit::Value| match item {
+                    toml_edit::Value::String(version) => Some(version.value().clone()),
+                    toml_edit::Value::InlineTable(table) => table
+                        .get("version")
+                        .and_then(toml_edit::Value::as_str)
+                        .map(ToString::to_string),
+                    _ => None,
+                };
+                match python {
+                    Item::Value(toml_edit::Value::Array(array)) => {
+                        Some(array.iter().filter_map(version).collect())
+                    }
+                    Item::Value(value) => Some(version(value).into_iter().collect()),
+                    Item::Table(table) => Some(
+                        table
+                            .get("version")
+                            .and_then(Item::as_str)
+                            .map(ToString::to_string)
+                            .into_iter()
+                            .collect(),
+                    ),
+                    _ => None,
+                }
+            }
+            Self::RuntimeTxt => {
+                let line = content
+                    .lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())?;
+                let version = line.strip_prefix("python-")?;
+                Some(vec![version.to_string()])
+            }
+        }
+    }
+
+    /// Update the content of a file of this kind to declare the given Python versions.
+    ///
+    /// Declarations for other tools are retained.
+    fn update(self, content: &str, versions: &[String]) -> Result<String, std::io::Error> {
+        match self {
+            Self::PythonVersion | Self::PythonVersions => Ok(versions.iter().join("\n").add("\n")),
+            Self::ToolVersions => {
+                let entry = format!("python {}", versions.iter().join(" "));
+                let mut found = false;
+                let mut lines = content
+                    .lines()
+                    .map(|line| {
+                        if line.split_whitespace().next() == Some("python") && !found {
+                            found = true;
+                            // Retain trailing comments, e.g., `python 3.12.1 # the default`.
+                            match line.find('#') {
+                                Some(index) => format!("{entry} {}", &line[index..]),
+                                None => entry.clone(),
+                            }
+                        } else {
+                            line.to_string()
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                if !found {
+                    lines.push(entry);
+                }
+                Ok(lines.join("\n").add("\n"))
+            }
+            Self::MiseToml => {
+                let mut document = content
+                    .parse::<DocumentMut>()
+                    .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+                let tools = document
+                    .entry("tools")
+                    .or_insert_with(toml_edit::table)
+                    .as_table_like_mut()
+                    .ok_or_else(|| {
+                        std::io::Error::new(
+                            std::io::ErrorKind::InvalidData,
+                            "Expected `tools` to be a table",
+                        )
+                    })?;
+                let python = match versions {
+                    [version] => toml_edit::value(version.as_str()),
+                    versions => toml_edit::value(versions.iter().collect::<toml_edit::Array>()),
+                };
+                tools.insert("python", python);
+                Ok(document.to_string())
+            }
+            Self::RuntimeTxt => Ok(format!(
+                "python-{}\n",
+                versions.first().map(String::as_str).unwrap_or_default()
+            )),
+        }
+    }
+}
+
+/// A Python version file, e.g., a `.python-version` or `.tool-versions` file.
 #[derive(Debug, Clone)]
 pub struct PythonVersionFile {
     /// The path to the version file.
@@ -25,6 +210,15 @@ pub struct PythonVersionFile {
     versions: Vec<PythonRequest>,
 }
 
+/// A Python version file found during discovery.
+#[derive(Debug)]
+struct FoundFile {
+    /// The path to the version file.
+    path: PathBuf,
+    /// The content of the version file, if it was read during discovery.
+    content: Option<String>,
+}
+
 /// Whether to prefer the `.python-version` or `.python-versions` file.
 #[derive(Debug, Clone, Copy, Default)]
 pub enum FilePreference {
@@ -70,7 +264,7 @@ impl PythonVersionFile {
         working_directory: impl AsRef<Path>,
         options: &DiscoveryOptions<'_>,
     ) -> Result<Option<Self>, std::io::Error> {
-        let Some(path) = Self::find_nearest(&working_directory, options) else {
+        let Some(found) = Self::find_nearest(&working_directory, options) else {
             if let Some(stop_discovery_at) = options.stop_discovery_at {
                 if stop_discovery_at == working_directory.as_ref() {
                     debug!(
@@ -102,13 +296,12 @@ impl PythonVersionFile {
         if options.no_config {
             debug!(
                 "Ignoring Python version file at `{}` due to `--no-config`",
-                path.user_display()
+                found.path.user_display()
             );
             return Ok(None);
         }
 
-        // Uses `try_from_path` instead of `from_path` to avoid TOCTOU failures.
-        Self::try_from_path(path).await
+        Self::from_found(found).await
     }
 
     pub async fn discover_user_config(
@@ -116,18 +309,26 @@ impl PythonVersionFile {
         options: &DiscoveryOptions<'_>,
     ) -> Result<Option<Self>, std::io::Error> {
         if !options.no_config {
-            if let Some(path) =
+            if let Some(found) =
                 Self::find_in_directory(user_config_working_directory.as_ref(), options)
-                    .into_iter()
-                    .find(|path| path.is_file())
             {
-                return Self::try_from_path(path).await;
+                return Self::from_found(found).await;
             }
         }
         Ok(None)
     }
 
-    fn find_nearest(path: impl AsRef<Path>, options: &DiscoveryOptions<'_>) -> Option<PathBuf> {
+    /// Read a Python version file found during discovery, reusing its content if it was already
+    /// read.
+    async fn from_found(found: FoundFile) -> Result<Option<Self>, std::io::Error> {
+        match found.content {
+            Some(content) => Ok(Some(Self::from_content(found.path, &content))),
+            // Uses `try_from_path` instead of `from_path` to avoid TOCTOU failures.
+            None => Self::try_from_path(found.path).await,
+        }
+    }
+
+    fn find_nearest(path: impl AsRef<Path>, options: &DiscoveryOptions<'_>) -> Option<FoundFile> {
         path.as_ref()
             .ancestors()
             .take_while(|path| {
@@ -141,7 +342,14 @@ impl PythonVersionFile {
             .find_map(|path| Self::find_in_directory(path, options))
     }
 
-    fn find_in_directory(path: &Path, options: &DiscoveryOptions<'_>) -> Option<PathBuf> {
+    /// Find a Python version file in the given directory.
+    ///
+    /// The `.python-version` and `.python-versions` files are preferred (in the order given by the
+    /// [`FilePreference`]), then `.tool-versions`, `mise.toml`, `.mise.toml`, and `runtime.txt`.
+    /// Files owned by other tools are skipped if they do not declare a Python version, which
+    /// requires reading them; as such, they're only read if no file precedes them, and at most
+    /// once.
+    fn find_in_directory(path: &Path, options: &DiscoveryOptions<'_>) -> Option<FoundFile> {
         let version_path = path.join(PYTHON_VERSION_FILENAME);
         let versions_path = path.join(PYTHON_VERSIONS_FILENAME);
 
@@ -149,8 +357,42 @@ impl PythonVersionFile {
             FilePreference::Versions => [versions_path, version_path],
             FilePreference::Version => [version_path, versions_path],
         };
+        if let Some(path) = paths.into_iter().find(|path| path.is_file()) {
+            return Some(FoundFile {
+                path,
+                content: None,
+            });
+        }
 
-        paths.into_iter().find(|path| path.is_file())
+        [
+            TOOL_VERSIONS_FILENAME,
+            MISE_TOML_FILENAME,
+            HIDDEN_MISE_TOML_FILENAME,
+            RUNTIME_TXT_FILENAME,
+        ]
+        .into_iter()
+        .map(|name| path.join(name))
+        .find_map(|path| {
+            let content = match fs::read_to_string(&path) {
+                Ok(content) => content,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => return None,
+                Err(err) => {
+                    debug!("Failed to read `{}`: {err}", path.user_display());
+                    return None;
+                }
+            };
+            if FileKind::from_path(&path).parse(&content).is_none() {
+                debug!(
+                    "Ignoring `{}` without a Python version",
+                    path.user_display()
+                );
+                return None;
+            }
+            Some(FoundFile {
+                path,
+                content: Some(content),
+            })
+        })
     }
 
     /// Try to read a Python version file at the given path.
@@ -158,39 +400,41 @@ impl PythonVersionFile {
     /// If the file does not exist, `Ok(None)` is returned.
     pub async fn try_from_path(path: PathBuf) -> Result<Option<Self>, std::io::Error> {
         match fs::tokio::read_to_string(&path).await {
-            Ok(content) => {
-                debug!(
-                    "Reading Python requests from version file at `{}`",
-                    path.display()
-                );
-                let versions = content
-                    .lines()
-                    .filter(|line| {
-                        // Skip comments and empty lines.
-                        let trimmed = line.trim();
-                        !(trimmed.is_empty() || trimmed.starts_with('#'))
-                    })
-                    .map(ToString::to_string)
-                    .map(|version| PythonRequest::parse(&version))
-                    .filter(|request| {
-                        if let PythonRequest::ExecutableName(name) = request {
-                            warn_user_once!(
-                                "Ignoring unsupported Python request `{name}` in version file: {}",
-                                path.display()
-                            );
-                            false
-                        } else {
-                            true
-                        }
-                    })
-                    .collect();
-                Ok(Some(Self { path, versions }))
-            }
+            Ok(content) => Ok(Some(Self::from_content(path, &content))),
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
             Err(err) => Err(err),
         }
     }
 
+    /// Parse the content of a Python version file at the given path.
+    fn from_content(path: PathBuf, content: &str) -> Self {
+        debug!(
+            "Reading Python requests from version file at `{}`",
+            path.display()
+        );
+        let kind = FileKind::from_path(&path);
+        let versions = kind
+            .parse(content)
+            .unwrap_or_default()
+            .into_iter()
+            // asdf and mise use `system` to defer to the `PATH`
+            .filter(|version| !(kind.is_foreign() && version == "system"))
+            .map(|version| PythonRequest::parse(&version))
+            .filter(|request| {
+                if let PythonRequest::ExecutableName(name) = request {
+                    warn_user_once!(
+                        "Ignoring unsupported Python request `{name}` in version file: {}",
+                        path.display()
+                    );
+                    false
+                } else {
+                    true
+                }
+            })
+            .collect();
+        Self { path, versions }
+    }
+
     /// Read a Python version file at the given path.
     ///
     /// If the file does not exist, an error is returned.
@@ -240,12 +484,16 @@ impl PythonVersionFile {
         &self.path
     }
 
-    /// Return the file name of the version file (guaranteed to be one of `.python-version` or
-    /// `.python-versions`).
+    /// Return the file name of the version file, e.g., `.python-version` or `.tool-versions`.
     pub fn file_name(&self) -> &str {
         self.path.file_name().unwrap().to_str().unwrap()
     }
 
+    /// Return the [`FileKind`] of the version file.
+    pub fn kind(&self) -> FileKind {
+        FileKind::from_path(&self.path)
+    }
+
     /// Set the versions for the file.
     #[must_use]
     pub fn with_versions(self, versions: Vec<PythonRequest>) -> Self {
@@ -256,17 +504,26 @@ impl PythonVersionFile {
     }
 
     /// Update the version file on the file system.
+    ///
+    /// For files owned by other tools, e.g., `.tool-versions`, only the Python declaration is
+    /// replaced.
     pub async fn write(&self) -> Result<(), std::io::Error> {
         debug!("Writing Python versions to `{}`", self.path.display());
-        fs::tokio::write(
-            &self.path,
-            self.versions
-                .iter()
-                .map(PythonRequest::to_canonical_string)
-                .join("\n")
-                .add("\n")
-                .as_bytes(),
-        )
-        .await
+        let kind = self.kind();
+        let existing = if kind.is_foreign() {
+            match fs::tokio::read_to_string(&self.path).await {
+                Ok(content) => content,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+                Err(err) => return Err(err),
+            }
+        } else {
+            String::new()
+        };
+        let versions = self
+            .versions
+            .iter()
+            .map(PythonRequest::to_canonical_string)
+            .collect::<Vec<_>>();
+        fs::tokio::write(&self.path, kind.update(&existing, &versions)?.as_bytes()).await
     }
 }
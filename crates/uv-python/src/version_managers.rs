@@ -0,0 +1,156 @@
+//! Python installations managed by other tools, i.e., pyenv, asdf, and mise.
+//!
+//! These tools install each Python version into a separate directory under a known root, e.g.,
+//! `~/.pyenv/versions/3.12.4`. We enumerate those directories directly, rather than relying on the
+//! tool's shims being on the `PATH`, so that a specific version can be found even if it is not the
+//! one selected by the tool.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use etcetera::BaseStrategy;
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_static::EnvVars;
+
+use crate::discovery::PythonSource;
+use crate::PythonVersion;
+
+/// A tool that manages Python installations outside of uv.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum VersionManager {
+    /// <https://github.com/pyenv/pyenv>
+    Pyenv,
+    /// <https://asdf-vm.com>
+    Asdf,
+    /// <https://mise.jdx.dev>
+    Mise,
+}
+
+impl VersionManager {
+    /// All supported version managers, in the order they are searched.
+    pub(crate) const ALL: [Self; 3] = [Self::Pyenv, Self::Asdf, Self::Mise];
+
+    /// The [`PythonSource`] for installations managed by this tool.
+    pub(crate) fn source(self) -> PythonSource {
+        match self {
+            Self::Pyenv => PythonSource::Pyenv,
+            Self::Asdf => PythonSource::Asdf,
+            Self::Mise => PythonSource::Mise,
+        }
+    }
+
+    /// The directory containing the Python installations of this tool, if it can be determined.
+    ///
+    /// The directory is not guaranteed to exist.
+    fn installations_dir(self) -> Option<PathBuf> {
+        match self {
+            // `$PYENV_ROOT/versions`, or `~/.pyenv/pyenv-win/versions` for pyenv-win
+            Self::Pyenv => {
+                let root = env_path(EnvVars::PYENV_ROOT)
+                    .or_else(|| etcetera::home_dir().ok().map(|home| home.join(".pyenv")))?;
+                if cfg!(windows) && root.join("pyenv-win").is_dir() {
+                    Some(root.join("pyenv-win").join("versions"))
+                } else {
+                    Some(root.join("versions"))
+                }
+            }
+            // `$ASDF_DATA_DIR/installs/python`
+            Self::Asdf => {
+                let root = env_path(EnvVars::ASDF_DATA_DIR)
+                    .or_else(|| etcetera::home_dir().ok().map(|home| home.join(".asdf")))?;
+                Some(root.join("installs").join("python"))
+            }
+            // `$MISE_DATA_DIR/installs/python`, defaulting to `~/.local/share/mise` (respecting
+            // `$XDG_DATA_HOME`) or, on Windows, `%LOCALAPPDATA%\mise`
+            Self::Mise => {
+                let root = env_path(EnvVars::MISE_DATA_DIR).or_else(|| {
+                    if cfg!(windows) {
+                        env_path(EnvVars::LOCALAPPDATA).map(|dir| dir.join("mise"))
+                    } else {
+                        etcetera::base_strategy::choose_base_strategy()
+                            .ok()
+                            .map(|dirs| dirs.data_dir().join("mise"))
+                    }
+                })?;
+                Some(root.join("installs").join("python"))
+            }
+        }
+    }
+
+    /// Return the Python executables of the installations managed by this tool.
+    ///
+    /// Installations are returned with the version parsed from their directory name, if possible,
+    /// newest first. Aliases, e.g., mise's `3.12` symlink to `3.12.4`, are skipped.
+    pub(crate) fn python_executables(self) -> Vec<(Option<PythonVersion>, PathBuf)> {
+        let Some(dir) = self.installations_dir() else {
+            return Vec::new();
+        };
+        let entries = match fs_err::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(err) => {
+                if err.kind() != std::io::ErrorKind::NotFound {
+                    debug!("Failed to read {self} installations: {err}");
+                }
+                return Vec::new();
+            }
+        };
+
+        debug!(
+            "Searching for {self} installations at `{}`",
+            dir.user_display()
+        );
+
+        let mut executables = entries
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+            .filter_map(|entry| {
+                let path = entry.path();
+                let executable = installation_executable(&path)?;
+                let version = entry
+                    .file_name()
+                    .to_str()
+                    .and_then(|name| PythonVersion::from_str(name).ok());
+                Some((version, executable))
+            })
+            .collect::<Vec<_>>();
+        executables.sort_by(|(a, _), (b, _)| {
+            b.as_ref()
+                .map(PythonVersion::version)
+                .cmp(&a.as_ref().map(PythonVersion::version))
+        });
+        executables
+    }
+}
+
+impl std::fmt::Display for VersionManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pyenv => f.write_str("pyenv"),
+            Self::Asdf => f.write_str("asdf"),
+            Self::Mise => f.write_str("mise"),
+        }
+    }
+}
+
+/// Return the Python executable in an installation directory, if it exists.
+fn installation_executable(dir: &Path) -> Option<PathBuf> {
+    let candidates: &[&str] = if cfg!(windows) {
+        &["python.exe"]
+    } else {
+        &["bin/python3", "bin/python"]
+    };
+    candidates
+        .iter()
+        .map(|candidate| dir.join(candidate))
+        .find(|executable| executable.is_file())
+}
+
+/// Read a path from an environment variable, if it is set and non-empty.
+fn env_path(name: &'static str) -> Option<PathBuf> {
+    env::var_os(name)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
@@ -46,6 +46,8 @@ pub struct PyVenvConfiguration {
     pub(crate) include_system_site_packages: bool,
     /// The Python version the virtual environment was created with
     pub(crate) version: Option<PythonVersion>,
+    /// The directory containing the base Python executable (i.e., the `home` key).
+    pub(crate) home: Option<PathBuf>,
 }
 
 #[derive(Debug, Error)]
@@ -199,6 +201,7 @@ impl PyVenvConfiguration {
         let mut seed = false;
         let mut include_system_site_packages = true;
         let mut version = None;
+        let mut home = None;
 
         // Per https://snarky.ca/how-virtual-environments-work/, the `pyvenv.cfg` file is not a
         // valid INI file, and is instead expected to be parsed by partitioning each line on the
@@ -225,6 +228,9 @@ impl PyVenvConfiguration {
                 "include-system-site-packages" => {
                     include_system_site_packages = value.trim().to_lowercase() == "true";
                 }
+                "home" => {
+                    home = Some(PathBuf::from(value.trim()));
+                }
                 "version" | "version_info" => {
                     version = Some(
                         PythonVersion::from_str(value.trim())
@@ -242,6 +248,7 @@ impl PyVenvConfiguration {
             seed,
             include_system_site_packages,
             version,
+            home,
         })
     }
 
@@ -270,6 +277,11 @@ impl PyVenvConfiguration {
         self.include_system_site_packages
     }
 
+    /// Returns the directory containing the base Python executable, if set.
+    pub fn home(&self) -> Option<&Path> {
+        self.home.as_deref()
+    }
+
     /// Set the key-value pair in the `pyvenv.cfg` file.
     pub fn set(content: &str, key: &str, value: &str) -> String {
         let mut lines = content.lines().map(Cow::Borrowed).collect::<Vec<_>>();
@@ -115,6 +115,19 @@ pub async fn read_pylock_toml_requirements(
     let content = fs_err::tokio::read_to_string(&output_file).await?;
     let lock = toml::from_str::<PylockToml>(&content)?;
 
+    Ok(read_pylock_requirements(&lock, upgrade)?)
+}
+
+/// Load the preferred requirements from a parsed `pylock.toml` file, applying the upgrade strategy.
+pub fn read_pylock_requirements(
+    lock: &PylockToml,
+    upgrade: &Upgrade,
+) -> Result<LockedRequirements, LockError> {
+    // As an optimization, skip iterating over the lockfile if we're upgrading all packages anyway.
+    if upgrade.is_all() {
+        return Ok(LockedRequirements::default());
+    }
+
     let mut preferences = Vec::new();
     let mut git = Vec::new();
 
@@ -0,0 +1,302 @@
+use petgraph::Graph;
+use rustc_hash::{FxBuildHasher, FxHashMap};
+
+use uv_distribution_filename::WheelFilename;
+use uv_distribution_types::implied_markers;
+use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifier};
+use uv_pep508::{
+    MarkerExpression, MarkerOperator, MarkerTree, MarkerValueString, MarkerValueVersion,
+};
+use uv_platform_tags::{AbiTag, LanguageTag};
+
+use crate::graph_ops::marker_reachability;
+use crate::{Lock, Package};
+
+/// The wheel coverage of a lockfile across its declared environments.
+///
+/// The declared environments are the union of `tool.uv.environments` and
+/// `tool.uv.required-environments`. For each locked package, an environment is considered
+/// covered if the locked wheels (in aggregate) include every platform and every Python version
+/// (within `requires-python`) in the environment. Otherwise, installing the package in that
+/// environment will fall back to the source distribution (or fail, if the package doesn't have
+/// one).
+#[derive(Debug)]
+pub struct WheelCoverage<'lock> {
+    /// The declared environments, simplified with respect to `requires-python`.
+    environments: Vec<MarkerTree>,
+    /// The coverage of each locked distribution, in lockfile order.
+    packages: Vec<PackageCoverage<'lock>>,
+}
+
+impl<'lock> WheelCoverage<'lock> {
+    /// Compute the [`WheelCoverage`] for the given lockfile.
+    pub fn from_lock(lock: &'lock Lock) -> Self {
+        let mut environments = Vec::new();
+        for environment in lock
+            .supported_environments()
+            .iter()
+            .chain(lock.required_environments())
+        {
+            if !environments.contains(environment) {
+                environments.push(*environment);
+            }
+        }
+
+        // Determine the markers under which each package is reachable, such that (e.g.) a
+        // Windows-only dependency isn't reported as lacking a Linux wheel.
+        let size_guess = lock.packages().len();
+        let mut graph = Graph::<&Package, MarkerTree>::with_capacity(size_guess, size_guess);
+        let mut inverse = FxHashMap::with_capacity_and_hasher(size_guess, FxBuildHasher);
+        for package in lock.packages() {
+            inverse.insert(&package.id, graph.add_node(package));
+        }
+        for package in lock.packages() {
+            let index = inverse[&package.id];
+            let dependencies = package
+                .dependencies
+                .iter()
+                .chain(package.optional_dependencies.values().flatten())
+                .chain(package.dependency_groups.values().flatten());
+            for dependency in dependencies {
+                let Some(dependency_index) = inverse.get(&dependency.package_id) else {
+                    continue;
+                };
+                graph.add_edge(
+                    index,
+                    *dependency_index,
+                    dependency.complexified_marker.pep508(),
+                );
+            }
+        }
+        let reachability = marker_reachability(&graph, &[]);
+
+        let mut packages = Vec::new();
+        for (index, package) in graph.node_indices().zip(lock.packages()) {
+            // Packages that are always built from source (e.g., workspace members, Git and
+            // directory dependencies) have neither wheels nor a source distribution in the
+            // lockfile.
+            if package.wheels.is_empty() && package.sdist.is_none() {
+                continue;
+            }
+
+            // Determine the platforms and Python versions supported by the locked wheels.
+            let mut supported = MarkerTree::FALSE;
+            for wheel in &package.wheels {
+                let mut wheel_marker = implied_markers(&wheel.filename);
+                wheel_marker.and(implied_python_markers(&wheel.filename));
+                supported.or(wheel_marker);
+            }
+
+            let reachable = reachability
+                .get(&index)
+                .copied()
+                .unwrap_or(MarkerTree::TRUE);
+
+            let mut coverage = PackageCoverage {
+                package,
+                wheel: Vec::new(),
+                sdist: Vec::new(),
+                missing: Vec::new(),
+            };
+            for environment in &environments {
+                // Skip environments in which the package is never installed.
+                let mut applicable = *environment;
+                applicable.and(reachable);
+                applicable.and(lock.requires_python().to_marker_tree());
+                if applicable.is_false() {
+                    continue;
+                }
+
+                let simplified = lock.simplify_environment(*environment);
+                if applicable.is_disjoint(supported.negate()) {
+                    coverage.wheel.push(simplified);
+                } else if package.sdist.is_some() {
+                    coverage.sdist.push(simplified);
+                } else {
+                    coverage.missing.push(simplified);
+                }
+            }
+            packages.push(coverage);
+        }
+
+        Self {
+            environments: environments
+                .into_iter()
+                .map(|environment| lock.simplify_environment(environment))
+                .collect(),
+            packages,
+        }
+    }
+
+    /// Returns the declared environments, simplified with respect to `requires-python`.
+    pub fn environments(&self) -> &[MarkerTree] {
+        &self.environments
+    }
+
+    /// Returns the coverage of each locked distribution.
+    pub fn packages(&self) -> &[PackageCoverage<'lock>] {
+        &self.packages
+    }
+
+    /// Returns `true` if any package lacks a wheel for any declared environment.
+    pub fn is_incomplete(&self) -> bool {
+        self.packages.iter().any(PackageCoverage::is_incomplete)
+    }
+}
+
+/// Returns the markers implied by the Python and ABI tags of a wheel, i.e., the Python versions
+/// (and, where the tags require it, the implementation) on which the wheel can be installed.
+///
+/// Unknown tags are assumed to be compatible with any Python.
+fn implied_python_markers(filename: &WheelFilename) -> MarkerTree {
+    let mut marker = MarkerTree::FALSE;
+    for python_tag in filename.python_tags() {
+        for abi_tag in filename.abi_tags() {
+            marker.or(tag_markers(*python_tag, *abi_tag));
+        }
+    }
+    marker
+}
+
+/// Returns the markers implied by a single Python and ABI tag pair.
+fn tag_markers(python_tag: LanguageTag, abi_tag: AbiTag) -> MarkerTree {
+    match (python_tag, abi_tag) {
+        // Ex) `py3-none-any`
+        (LanguageTag::Python { major, minor: None }, AbiTag::None) => {
+            if major == 3 {
+                MarkerTree::TRUE
+            } else {
+                MarkerTree::FALSE
+            }
+        }
+        // Ex) `py38-none-any`, which is compatible with Python 3.8 and later.
+        (
+            LanguageTag::Python {
+                major,
+                minor: Some(minor),
+            },
+            AbiTag::None,
+        ) => python_version(VersionSpecifier::greater_than_equal_version(Version::new(
+            [u64::from(major), u64::from(minor)],
+        ))),
+        // Ex) `cp38-abi3-manylinux_2_17_x86_64`, which is compatible with CPython 3.8 and later.
+        (
+            LanguageTag::CPython {
+                python_version: (major, minor),
+            },
+            AbiTag::Abi3,
+        ) => {
+            let mut marker = implementation("CPython");
+            marker.and(python_version(
+                VersionSpecifier::greater_than_equal_version(Version::new([
+                    u64::from(major),
+                    u64::from(minor),
+                ])),
+            ));
+            marker
+        }
+        // Ex) `cp312-cp312-manylinux_2_17_x86_64` or `cp312-none-any`, which are only compatible
+        // with CPython 3.12.
+        (
+            LanguageTag::CPython {
+                python_version: (major, minor),
+            },
+            AbiTag::None | AbiTag::CPython { .. },
+        ) => {
+            let mut marker = implementation("CPython");
+            marker.and(python_version(VersionSpecifier::equals_version(
+                Version::new([u64::from(major), u64::from(minor)]),
+            )));
+            marker
+        }
+        // Ex) `pp39-pypy39_pp73-manylinux_2_17_x86_64`
+        (
+            LanguageTag::PyPy {
+                python_version: (major, minor),
+            },
+            _,
+        ) => {
+            let mut marker = implementation("PyPy");
+            marker.and(python_version(VersionSpecifier::equals_version(
+                Version::new([u64::from(major), u64::from(minor)]),
+            )));
+            marker
+        }
+        // Ex) `graalpy310-graalpy240_310_native-manylinux_2_17_x86_64`
+        (
+            LanguageTag::GraalPy {
+                python_version: (major, minor),
+            },
+            _,
+        ) => {
+            let mut marker = implementation("GraalVM");
+            marker.and(python_version(VersionSpecifier::equals_version(
+                Version::new([u64::from(major), u64::from(minor)]),
+            )));
+            marker
+        }
+        _ => MarkerTree::TRUE,
+    }
+}
+
+/// Returns a `python_version` marker for the given specifier.
+fn python_version(specifier: VersionSpecifier) -> MarkerTree {
+    MarkerTree::expression(MarkerExpression::Version {
+        key: MarkerValueVersion::PythonVersion,
+        specifier,
+    })
+}
+
+/// Returns a `platform_python_implementation` marker for the given implementation.
+fn implementation(implementation: &'static str) -> MarkerTree {
+    MarkerTree::expression(MarkerExpression::String {
+        key: MarkerValueString::PlatformPythonImplementation,
+        operator: MarkerOperator::Equal,
+        value: arcstr::ArcStr::from(implementation),
+    })
+}
+
+/// The wheel coverage of a single locked package across the declared environments.
+#[derive(Debug)]
+pub struct PackageCoverage<'lock> {
+    package: &'lock Package,
+    /// The environments that are covered by at least one locked wheel.
+    wheel: Vec<MarkerTree>,
+    /// The environments that fall back to the source distribution.
+    sdist: Vec<MarkerTree>,
+    /// The environments for which the package has no compatible distribution at all.
+    missing: Vec<MarkerTree>,
+}
+
+impl PackageCoverage<'_> {
+    /// Returns the name of the package.
+    pub fn name(&self) -> &PackageName {
+        self.package.name()
+    }
+
+    /// Returns the version of the package, if any.
+    pub fn version(&self) -> Option<&Version> {
+        self.package.version()
+    }
+
+    /// Returns the environments that are covered by a wheel.
+    pub fn wheel(&self) -> &[MarkerTree] {
+        &self.wheel
+    }
+
+    /// Returns the environments that fall back to the source distribution.
+    pub fn sdist(&self) -> &[MarkerTree] {
+        &self.sdist
+    }
+
+    /// Returns the environments for which the package has no compatible distribution.
+    pub fn missing(&self) -> &[MarkerTree] {
+        &self.missing
+    }
+
+    /// Returns `true` if the package lacks a wheel for any declared environment.
+    pub fn is_incomplete(&self) -> bool {
+        !self.sdist.is_empty() || !self.missing.is_empty()
+    }
+}
@@ -50,6 +50,7 @@ use uv_types::{BuildContext, HashStrategy};
 use uv_workspace::WorkspaceMember;
 
 use crate::fork_strategy::ForkStrategy;
+pub use crate::lock::coverage::{PackageCoverage, WheelCoverage};
 pub(crate) use crate::lock::export::PylockTomlPackage;
 pub use crate::lock::export::RequirementsTxtExport;
 pub use crate::lock::export::{PylockToml, PylockTomlErrorKind};
@@ -64,6 +65,7 @@ use crate::{
     ResolverOutput,
 };
 
+mod coverage;
 mod export;
 mod installable;
 mod map;
@@ -87,6 +89,11 @@ static MAC_MARKERS: LazyLock<UniversalMarker> = LazyLock::new(|| {
     let pep508 = MarkerTree::from_str("os_name == 'posix' and sys_platform == 'darwin'").unwrap();
     UniversalMarker::new(pep508, ConflictMarker::TRUE)
 });
+static EMSCRIPTEN_MARKERS: LazyLock<UniversalMarker> = LazyLock::new(|| {
+    let pep508 =
+        MarkerTree::from_str("os_name == 'posix' and sys_platform == 'emscripten'").unwrap();
+    UniversalMarker::new(pep508, ConflictMarker::TRUE)
+});
 static ARM_MARKERS: LazyLock<UniversalMarker> = LazyLock::new(|| {
     let pep508 =
         MarkerTree::from_str("platform_machine == 'aarch64' or platform_machine == 'arm64' or platform_machine == 'ARM64'")
@@ -345,6 +352,15 @@ impl Lock {
                 }
             }
 
+            if platform_tags.iter().all(PlatformTag::is_pyodide) {
+                if graph.graph[node_index]
+                    .marker()
+                    .is_disjoint(*EMSCRIPTEN_MARKERS)
+                {
+                    return false;
+                }
+            }
+
             if platform_tags.iter().all(PlatformTag::is_arm) {
                 if graph.graph[node_index].marker().is_disjoint(*ARM_MARKERS) {
                     return false;
@@ -2306,6 +2322,56 @@ impl Package {
         }
     }
 
+    /// Convert the [`Package`] to a [`Dist`] for each of its remote distributions, i.e., every
+    /// wheel (regardless of platform) and source distribution that would be fetched from a
+    /// registry or URL.
+    ///
+    /// Packages with local or Git sources have no remote distributions.
+    pub fn to_remote_dists(&self, workspace_root: &Path) -> Result<Vec<Dist>, LockError> {
+        let mut dists = Vec::new();
+        match &self.id.source {
+            Source::Registry(source) => {
+                let wheels = self
+                    .wheels
+                    .iter()
+                    .map(|wheel| wheel.to_registry_wheel(source, workspace_root))
+                    .collect::<Result<Vec<_>, LockError>>()?;
+                for best_wheel_index in 0..wheels.len() {
+                    let reg_built_dist = RegistryBuiltDist {
+                        wheels: wheels.clone(),
+                        best_wheel_index,
+                        sdist: None,
+                    };
+                    dists.push(Dist::Built(BuiltDist::Registry(reg_built_dist)));
+                }
+            }
+            Source::Direct(url, direct) => {
+                for wheel in &self.wheels {
+                    let url = Url::from(ParsedArchiveUrl {
+                        url: url.to_url().map_err(LockErrorKind::InvalidUrl)?,
+                        subdirectory: direct.subdirectory.clone(),
+                        ext: DistExtension::Wheel,
+                    });
+                    let direct_dist = DirectUrlBuiltDist {
+                        filename: wheel.filename.clone(),
+                        location: Box::new(url.clone()),
+                        url: VerbatimUrl::from_url(url),
+                    };
+                    dists.push(Dist::Built(BuiltDist::DirectUrl(direct_dist)));
+                }
+            }
+            Source::Git(..)
+            | Source::Path(..)
+            | Source::Directory(..)
+            | Source::Editable(..)
+            | Source::Virtual(..) => return Ok(dists),
+        }
+        if let Some(sdist) = self.to_source_dist(workspace_root)? {
+            dists.push(Dist::Source(sdist));
+        }
+        Ok(dists)
+    }
+
     /// Generate a [`WheelTagHint`] based on wheel-tag incompatibilities.
     fn tag_hint(&self, tag_policy: TagPolicy<'_>) -> Option<WheelTagHint> {
         let filenames = self
@@ -2738,7 +2804,7 @@ impl Package {
     }
 
     /// Returns all the hashes associated with this [`Package`].
-    fn hashes(&self) -> HashDigests {
+    pub fn hashes(&self) -> HashDigests {
         let mut hashes = Vec::with_capacity(
             usize::from(self.sdist.as_ref().and_then(|sdist| sdist.hash()).is_some())
                 + self
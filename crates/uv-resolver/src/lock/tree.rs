@@ -13,7 +13,7 @@ use uv_configuration::DependencyGroupsWithDefaults;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::Version;
 use uv_pep508::MarkerTree;
-use uv_pypi_types::ResolverMarkerEnvironment;
+use uv_pypi_types::{ProjectStatus, ResolverMarkerEnvironment};
 
 use crate::lock::PackageId;
 use crate::{Lock, PackageMap};
@@ -26,6 +26,8 @@ pub struct TreeDisplay<'env> {
     roots: Vec<NodeIndex>,
     /// The latest known version of each package.
     latest: &'env PackageMap<Version>,
+    /// The status of each package's project on its index, if not active.
+    status: &'env PackageMap<ProjectStatus>,
     /// Maximum display depth of the dependency tree.
     depth: usize,
     /// Whether to de-duplicate the displayed dependencies.
@@ -38,6 +40,7 @@ impl<'env> TreeDisplay<'env> {
         lock: &'env Lock,
         markers: Option<&'env ResolverMarkerEnvironment>,
         latest: &'env PackageMap<Version>,
+        status: &'env PackageMap<ProjectStatus>,
         depth: usize,
         prune: &[PackageName],
         packages: &[PackageName],
@@ -396,6 +399,7 @@ impl<'env> TreeDisplay<'env> {
             graph,
             roots,
             latest,
+            status,
             depth,
             no_dedupe,
         }
@@ -470,6 +474,18 @@ impl<'env> TreeDisplay<'env> {
             line
         };
 
+        // Incorporate the status of the package's project, if known.
+        let line = if let Some(status) = self.status.get(package_id) {
+            let annotation = if let Some(reason) = status.reason.as_ref() {
+                format!("({}: {reason})", status.status)
+            } else {
+                format!("({})", status.status)
+            };
+            format!("{line} {}", annotation.bold().yellow())
+        } else {
+            line
+        };
+
         let mut dependencies = self
             .graph
             .edges_directed(cursor.node(), Direction::Outgoing)
@@ -845,6 +845,13 @@ impl PubGrubReportFormatter<'_> {
                     reason: reason.clone(),
                 });
             }
+            Some(UnavailablePackage::Quarantined(Some(reason))) => {
+                hints.insert(PubGrubHint::QuarantinedPackage {
+                    package: name.clone(),
+                    reason: reason.clone(),
+                });
+            }
+            Some(UnavailablePackage::Quarantined(None)) => {}
             Some(UnavailablePackage::NotFound) => {}
             None => {}
         }
@@ -1019,6 +1026,12 @@ pub(crate) enum PubGrubHint {
         // excluded from `PartialEq` and `Hash`
         reason: String,
     },
+    /// A package was quarantined by the registry, with the given reason.
+    QuarantinedPackage {
+        package: PackageName,
+        // excluded from `PartialEq` and `Hash`
+        reason: String,
+    },
     /// Metadata for a package version could not be parsed.
     InvalidVersionMetadata {
         package: PackageName,
@@ -1158,6 +1171,9 @@ enum PubGrubHintCore {
     InvalidPackageStructure {
         package: PackageName,
     },
+    QuarantinedPackage {
+        package: PackageName,
+    },
     InvalidVersionMetadata {
         package: PackageName,
     },
@@ -1227,6 +1243,7 @@ impl From<PubGrubHint> for PubGrubHintCore {
             PubGrubHint::InvalidPackageStructure { package, .. } => {
                 Self::InvalidPackageStructure { package }
             }
+            PubGrubHint::QuarantinedPackage { package, .. } => Self::QuarantinedPackage { package },
             PubGrubHint::InvalidVersionMetadata { package, .. } => {
                 Self::InvalidVersionMetadata { package }
             }
@@ -1350,6 +1367,16 @@ impl std::fmt::Display for PubGrubHint {
                     textwrap::indent(reason, "  ")
                 )
             }
+            Self::QuarantinedPackage { package, reason } => {
+                write!(
+                    f,
+                    "{}{} `{}` was quarantined by the package registry:\n{}",
+                    "hint".bold().cyan(),
+                    ":".bold(),
+                    package.cyan(),
+                    textwrap::indent(reason, "  ")
+                )
+            }
             Self::InvalidVersionMetadata {
                 package,
                 version,
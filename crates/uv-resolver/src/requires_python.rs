@@ -318,6 +318,7 @@ impl RequiresPython {
                 let minor = u8::try_from(minor).ok()?;
                 Some(AbiTag::CPython {
                     gil_disabled: false,
+                    debug: false,
                     python_version: (major, minor),
                 })
             }
@@ -19,7 +19,10 @@ use uv_git::GitResolver;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifier};
 use uv_pep508::{MarkerEnvironment, MarkerTree, MarkerTreeKind};
-use uv_pypi_types::{Conflicts, HashDigests, ParsedUrlError, VerbatimParsedUrl, Yanked};
+use uv_pypi_types::{
+    Conflicts, HashDigests, ParsedUrlError, ProjectStatus, ProjectStatusMarker, VerbatimParsedUrl,
+    Yanked,
+};
 
 use crate::graph_ops::{marker_reachability, simplify_conflict_markers};
 use crate::pins::FilePins;
@@ -29,6 +32,7 @@ use crate::resolution::AnnotatedDist;
 use crate::resolution_mode::ResolutionStrategy;
 use crate::resolver::{Resolution, ResolutionDependencyEdge, ResolutionPackage};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
+use crate::version_map::VersionMap;
 use crate::{
     InMemoryIndex, MetadataResponse, Options, PythonRequirement, RequiresPython, ResolveError,
     VersionsResponse,
@@ -167,6 +171,32 @@ impl ResolverOutput {
             }
         }
 
+        // Warn on any registry packages whose project is archived or deprecated (PEP 792).
+        let mut statuses = BTreeMap::default();
+        for resolution in resolutions {
+            for package in resolution.nodes.keys() {
+                if package.url.is_some() || statuses.contains_key(&package.name) {
+                    continue;
+                }
+                if let Some(status) = Self::get_status(&package.name, package.index.as_ref(), index)
+                {
+                    statuses.insert(&package.name, status);
+                }
+            }
+        }
+        for (package_name, status) in statuses {
+            if matches!(
+                status.status,
+                ProjectStatusMarker::Archived | ProjectStatusMarker::Deprecated
+            ) {
+                diagnostics.push(ResolutionDiagnostic::ProjectStatus {
+                    package_name: package_name.clone(),
+                    status: status.status,
+                    reason: status.reason.as_deref().map(str::to_string),
+                });
+            }
+        }
+
         let mut seen = FxHashSet::default();
         for resolution in resolutions {
             let marker = resolution.env.try_universal_markers().unwrap_or_default();
@@ -588,6 +618,31 @@ impl ResolverOutput {
         HashDigests::empty()
     }
 
+    /// Return the status of the project on the given index, if known, per PEP 792.
+    fn get_status(
+        name: &PackageName,
+        index: Option<&IndexUrl>,
+        in_memory: &InMemoryIndex,
+    ) -> Option<ProjectStatus> {
+        // Query the implicit and explicit indexes for the status.
+        let implicit_response = in_memory.implicit().get(name);
+        let explicit_response =
+            index.and_then(|index| in_memory.explicit().get(&(name.clone(), index.clone())));
+        [implicit_response, explicit_response]
+            .into_iter()
+            .flatten()
+            .find_map(|response| {
+                let VersionsResponse::Found(version_maps) = &*response else {
+                    return None;
+                };
+                version_maps
+                    .iter()
+                    .filter(|version_map| version_map.index() == index)
+                    .find_map(VersionMap::status)
+                    .cloned()
+            })
+    }
+
     /// Returns an iterator over the distinct packages in the graph.
     fn dists(&self) -> impl Iterator<Item = &AnnotatedDist> {
         self.graph
@@ -134,6 +134,8 @@ pub(crate) enum UnavailablePackage {
     InvalidMetadata(String),
     /// The package has an invalid structure.
     InvalidStructure(String),
+    /// The package was quarantined by the registry (PEP 792), with an optional reason.
+    Quarantined(Option<String>),
 }
 
 impl UnavailablePackage {
@@ -144,6 +146,7 @@ impl UnavailablePackage {
             UnavailablePackage::NotFound => "not found in the package registry",
             UnavailablePackage::InvalidMetadata(_) => "invalid metadata",
             UnavailablePackage::InvalidStructure(_) => "an invalid package format",
+            UnavailablePackage::Quarantined(_) => "quarantined by the package registry",
         }
     }
 
@@ -154,6 +157,7 @@ impl UnavailablePackage {
             UnavailablePackage::NotFound => format!("was {self}"),
             UnavailablePackage::InvalidMetadata(_) => format!("has {self}"),
             UnavailablePackage::InvalidStructure(_) => format!("has {self}"),
+            UnavailablePackage::Quarantined(_) => format!("was {self}"),
         }
     }
 }
@@ -1221,6 +1221,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     .insert(name.clone(), UnavailablePackage::NotFound);
                 &[]
             }
+            VersionsResponse::Quarantined(ref reason) => {
+                self.unavailable_packages.insert(
+                    name.clone(),
+                    UnavailablePackage::Quarantined(reason.clone()),
+                );
+                &[]
+            }
         };
 
         debug!("Searching for a compatible version of {package} ({range})");
@@ -2387,6 +2394,14 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         self.unavailable_packages
                             .insert(package_name.clone(), UnavailablePackage::NotFound);
 
+                        return Ok(None);
+                    }
+                    VersionsResponse::Quarantined(ref reason) => {
+                        self.unavailable_packages.insert(
+                            package_name.clone(),
+                            UnavailablePackage::Quarantined(reason.clone()),
+                        );
+
                         return Ok(None);
                     }
                 };
@@ -9,6 +9,7 @@ use uv_distribution_types::{
 use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_platform_tags::Tags;
+use uv_pypi_types::ProjectStatusMarker;
 use uv_types::{BuildContext, HashStrategy};
 
 use crate::flat_index::FlatIndex;
@@ -30,6 +31,8 @@ pub enum VersionsResponse {
     NoIndex,
     /// The package was not found in the cache and the network is not available.
     Offline,
+    /// The package was quarantined by the registry (PEP 792), with an optional reason.
+    Quarantined(Option<String>),
 }
 
 #[derive(Debug)]
@@ -171,33 +174,58 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
         let flat_index = index.is_none().then_some(&self.flat_index);
 
         match result {
-            Ok(results) => Ok(VersionsResponse::Found(
-                results
+            Ok(results) => {
+                let mut quarantined = None;
+                let version_maps = results
                     .into_iter()
-                    .map(|(index, metadata)| match metadata {
-                        MetadataFormat::Simple(metadata) => VersionMap::from_simple_metadata(
+                    .filter_map(|(index, metadata)| match metadata {
+                        MetadataFormat::Simple(metadata) => {
+                            // Skip any index on which the project is quarantined.
+                            let status = metadata.status();
+                            if status.status == ProjectStatusMarker::Quarantined {
+                                quarantined = Some(status.reason.as_deref().map(str::to_string));
+                                return None;
+                            }
+                            Some(VersionMap::from_simple_metadata(
+                                metadata,
+                                package_name,
+                                index,
+                                self.tags.as_ref(),
+                                &self.requires_python,
+                                &self.allowed_yanks,
+                                &self.hasher,
+                                self.exclude_newer.as_ref(),
+                                flat_index
+                                    .and_then(|flat_index| flat_index.get(package_name))
+                                    .cloned(),
+                                self.build_options,
+                            ))
+                        }
+                        MetadataFormat::Flat(metadata) => Some(VersionMap::from_flat_metadata(
                             metadata,
-                            package_name,
-                            index,
                             self.tags.as_ref(),
-                            &self.requires_python,
-                            &self.allowed_yanks,
                             &self.hasher,
-                            self.exclude_newer.as_ref(),
-                            flat_index
-                                .and_then(|flat_index| flat_index.get(package_name))
-                                .cloned(),
                             self.build_options,
-                        ),
-                        MetadataFormat::Flat(metadata) => VersionMap::from_flat_metadata(
-                            metadata,
-                            self.tags.as_ref(),
-                            &self.hasher,
-                            self.build_options,
-                        ),
+                        )),
                     })
-                    .collect(),
-            )),
+                    .collect::<Vec<_>>();
+
+                // If the project is quarantined on every index on which it was found, treat it as
+                // unavailable (unless it's present in a `--find-links` index).
+                if version_maps.is_empty() {
+                    if let Some(reason) = quarantined {
+                        if let Some(flat_index) = flat_index
+                            .and_then(|flat_index| flat_index.get(package_name))
+                            .cloned()
+                        {
+                            return Ok(VersionsResponse::Found(vec![VersionMap::from(flat_index)]));
+                        }
+                        return Ok(VersionsResponse::Quarantined(reason));
+                    }
+                }
+
+                Ok(VersionsResponse::Found(version_maps))
+            }
             Err(err) => match err.into_kind() {
                 uv_client::ErrorKind::PackageNotFound(_) => {
                     if let Some(flat_index) = flat_index
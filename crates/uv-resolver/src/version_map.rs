@@ -16,7 +16,7 @@ use uv_distribution_types::{
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_platform_tags::{IncompatibleTag, TagCompatibility, Tags};
-use uv_pypi_types::{HashDigest, Yanked};
+use uv_pypi_types::{HashDigest, ProjectStatus, Yanked};
 use uv_types::HashStrategy;
 use uv_warnings::warn_user_once;
 
@@ -98,6 +98,7 @@ impl VersionMap {
                 },
             }
         }
+        let status = simple_metadata.status();
         Self {
             inner: VersionMapInner::Lazy(VersionMapLazy {
                 map,
@@ -107,6 +108,7 @@ impl VersionMap {
                 no_binary: build_options.no_binary_package(package_name),
                 no_build: build_options.no_build_package(package_name),
                 index: index.clone(),
+                status,
                 tags: tags.cloned(),
                 allowed_yanks: allowed_yanks.clone(),
                 hasher: hasher.clone(),
@@ -164,6 +166,14 @@ impl VersionMap {
         }
     }
 
+    /// Return the status of the project on the index where this package came from, per PEP 792.
+    pub(crate) fn status(&self) -> Option<&ProjectStatus> {
+        match &self.inner {
+            VersionMapInner::Eager(_) => None,
+            VersionMapInner::Lazy(lazy) => Some(&lazy.status),
+        }
+    }
+
     /// Return an iterator over the versions and distributions.
     ///
     /// Note that the value returned in this iterator is a [`VersionMapDist`],
@@ -360,6 +370,8 @@ struct VersionMapLazy {
     no_build: bool,
     /// The URL of the index where this package came from.
     index: IndexUrl,
+    /// The status of the project on the index, per PEP 792.
+    status: ProjectStatus,
     /// The set of compatibility tags that determines whether a wheel is usable
     /// in the current environment.
     tags: Option<Tags>,
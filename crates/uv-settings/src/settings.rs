@@ -233,6 +233,20 @@ pub struct GlobalOptions {
         "#
     )]
     pub cache_dir: Option<PathBuf>,
+    /// Paths to read-only cache directories to consult, in order, for entries that are missing
+    /// from the cache directory.
+    ///
+    /// Entries found in a fallback cache are linked or copied into the cache directory on first
+    /// use. Fallback cache directories are never written to, and so can reside on a read-only
+    /// filesystem (e.g., a shared network mount or a container image layer).
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            fallback-cache-dir = ["/opt/uv-cache"]
+        "#
+    )]
+    pub fallback_cache_dir: Option<Vec<PathBuf>>,
     /// Whether to enable experimental, preview features.
     #[option(
         default = "false",
@@ -1792,6 +1806,7 @@ pub struct OptionsWire {
     offline: Option<bool>,
     no_cache: Option<bool>,
     cache_dir: Option<PathBuf>,
+    fallback_cache_dir: Option<Vec<PathBuf>>,
     preview: Option<bool>,
     python_preference: Option<PythonPreference>,
     python_downloads: Option<PythonDownloads>,
@@ -1876,6 +1891,7 @@ impl From<OptionsWire> for Options {
             offline,
             no_cache,
             cache_dir,
+            fallback_cache_dir,
             preview,
             python_preference,
             python_downloads,
@@ -1940,6 +1956,7 @@ impl From<OptionsWire> for Options {
                 offline,
                 no_cache,
                 cache_dir,
+                fallback_cache_dir,
                 preview,
                 python_preference,
                 python_downloads,
@@ -45,6 +45,11 @@ impl EnvVars {
     /// directory for caching instead of the default cache directory.
     pub const UV_CACHE_DIR: &'static str = "UV_CACHE_DIR";
 
+    /// Equivalent to the `--fallback-cache-dir` command-line argument. If set, uv will consult
+    /// these read-only cache directories, separated by the platform's path separator (`:` on
+    /// Unix, `;` on Windows), for entries that are missing from the cache directory.
+    pub const UV_FALLBACK_CACHE_DIR: &'static str = "UV_FALLBACK_CACHE_DIR";
+
     /// Equivalent to the `--no-cache` command-line argument. If set, uv will not use the
     /// cache for any operations.
     pub const UV_NO_CACHE: &'static str = "UV_NO_CACHE";
@@ -136,6 +141,10 @@ impl EnvVars {
     /// directories.
     pub const UV_NO_CONFIG: &'static str = "UV_NO_CONFIG";
 
+    /// If set, uv will not check whether its executable is a bundle created by `uv bundle`, and
+    /// will always run as uv.
+    pub const UV_NO_BUNDLE: &'static str = "UV_NO_BUNDLE";
+
     /// Equivalent to the `--exclude-newer` command-line argument. If set, uv will
     /// exclude distributions published after the specified date.
     pub const UV_EXCLUDE_NEWER: &'static str = "UV_EXCLUDE_NEWER";
@@ -259,6 +268,9 @@ impl EnvVars {
     /// Specifies the directory to place links to installed, managed Python executables.
     pub const UV_PYTHON_BIN_DIR: &'static str = "UV_PYTHON_BIN_DIR";
 
+    /// Specifies the directory to place `python` shims for managed Python installations.
+    pub const UV_PYTHON_SHIM_DIR: &'static str = "UV_PYTHON_SHIM_DIR";
+
     /// Specifies the directory for storing managed Python installations.
     pub const UV_PYTHON_INSTALL_DIR: &'static str = "UV_PYTHON_INSTALL_DIR";
 
@@ -426,6 +438,15 @@ impl EnvVars {
     /// Used to determine if an active Conda environment is the base environment or not.
     pub const CONDA_DEFAULT_ENV: &'static str = "CONDA_DEFAULT_ENV";
 
+    /// Used to discover Python installations managed by pyenv, in `$PYENV_ROOT/versions`.
+    pub const PYENV_ROOT: &'static str = "PYENV_ROOT";
+
+    /// Used to discover Python installations managed by asdf, in `$ASDF_DATA_DIR/installs/python`.
+    pub const ASDF_DATA_DIR: &'static str = "ASDF_DATA_DIR";
+
+    /// Used to discover Python installations managed by mise, in `$MISE_DATA_DIR/installs/python`.
+    pub const MISE_DATA_DIR: &'static str = "MISE_DATA_DIR";
+
     /// If set to `1` before a virtual environment is activated, then the
     /// virtual environment name will not be prepended to the terminal prompt.
     pub const VIRTUAL_ENV_DISABLE_PROMPT: &'static str = "VIRTUAL_ENV_DISABLE_PROMPT";
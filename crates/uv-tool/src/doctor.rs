@@ -0,0 +1,275 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_pep508::PackageName;
+use uv_python::PythonEnvironment;
+use uv_static::EnvVars;
+
+use crate::{entrypoint_paths, Error, InstalledTools, Tool};
+
+/// A problem detected with an installed tool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolIssue {
+    /// The tool environment does not exist.
+    MissingEnvironment(PathBuf),
+    /// The Python interpreter linked to the tool environment no longer exists.
+    MissingInterpreter(PathBuf),
+    /// The tool package is not installed in the tool environment.
+    MissingPackage(PackageName),
+    /// A requirement recorded in the receipt is not installed in the tool environment.
+    MissingRequirement(PackageName),
+    /// An executable recorded in the receipt is missing, or links to a file that does not exist.
+    StaleExecutable(PathBuf),
+    /// An executable provided by the tool package is not recorded in the receipt.
+    UntrackedExecutable(String),
+    /// An executable recorded in the receipt is no longer provided by the tool package.
+    UnknownExecutable(String),
+    /// An executable recorded in the receipt links into the environment of another tool, or was
+    /// replaced by a file that is not managed by uv.
+    ForeignExecutable {
+        path: PathBuf,
+        owner: Option<PackageName>,
+    },
+    /// An executable recorded in the receipt is shadowed by another executable earlier on the
+    /// `PATH`.
+    ShadowedExecutable {
+        name: String,
+        path: PathBuf,
+        owner: Option<PackageName>,
+    },
+}
+
+impl ToolIssue {
+    /// Returns `true` if the issue can be repaired by reinstalling the tool from its receipt.
+    ///
+    /// Shadowed executables are only reported, since reinstalling the tool doesn't change the
+    /// `PATH`.
+    pub fn is_fixable(&self) -> bool {
+        !matches!(
+            self,
+            Self::ForeignExecutable { .. } | Self::ShadowedExecutable { .. }
+        )
+    }
+
+    /// Returns `true` if the issue prevents repairing the tool by reinstalling it.
+    ///
+    /// Reinstalling a tool overwrites its executables, so executables that belong to another tool
+    /// (or that were replaced by a file not managed by uv) are never repaired automatically.
+    pub fn blocks_repair(&self) -> bool {
+        matches!(self, Self::ForeignExecutable { .. })
+    }
+}
+
+impl fmt::Display for ToolIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingEnvironment(path) => write!(
+                f,
+                "Tool environment is missing at `{}`",
+                path.user_display()
+            ),
+            Self::MissingInterpreter(path) => write!(
+                f,
+                "Python interpreter is missing at `{}`",
+                path.user_display()
+            ),
+            Self::MissingPackage(name) => write!(
+                f,
+                "Package `{name}` is not installed in the tool environment"
+            ),
+            Self::MissingRequirement(name) => write!(
+                f,
+                "Requirement `{name}` is not installed in the tool environment"
+            ),
+            Self::StaleExecutable(path) => {
+                write!(f, "Executable `{}` is missing", path.user_display())
+            }
+            Self::UntrackedExecutable(name) => {
+                write!(f, "Executable `{name}` is not recorded in the tool receipt")
+            }
+            Self::UnknownExecutable(name) => write!(
+                f,
+                "Executable `{name}` is recorded in the tool receipt, but is no longer provided"
+            ),
+            Self::ForeignExecutable {
+                path,
+                owner: Some(owner),
+            } => write!(
+                f,
+                "Executable `{}` is owned by tool `{owner}`",
+                path.user_display()
+            ),
+            Self::ForeignExecutable { path, owner: None } => write!(
+                f,
+                "Executable `{}` was replaced by a file not managed by uv",
+                path.user_display()
+            ),
+            Self::ShadowedExecutable {
+                name,
+                path,
+                owner: Some(owner),
+            } => write!(
+                f,
+                "Executable `{name}` is shadowed on the PATH by `{}` (owned by tool `{owner}`)",
+                path.user_display()
+            ),
+            Self::ShadowedExecutable {
+                name,
+                path,
+                owner: None,
+            } => write!(
+                f,
+                "Executable `{name}` is shadowed on the PATH by `{}`",
+                path.user_display()
+            ),
+        }
+    }
+}
+
+impl InstalledTools {
+    /// Check an installed tool for problems, comparing its receipt against the tool environment,
+    /// the installed executables, and the `PATH`.
+    ///
+    /// Note it is generally incorrect to use this without [`Self::acquire_lock`].
+    pub fn check(
+        &self,
+        name: &PackageName,
+        tool: &Tool,
+        cache: &Cache,
+    ) -> Result<Vec<ToolIssue>, Error> {
+        let mut issues = Vec::new();
+        let tool_dir = self.tool_dir(name);
+
+        // Check the executables recorded in the receipt.
+        for entrypoint in tool.entrypoints() {
+            let path = &entrypoint.install_path;
+            match fs_err::symlink_metadata(path) {
+                Ok(metadata) => {
+                    if metadata.file_type().is_symlink() {
+                        let target = fs_err::read_link(path)?;
+                        let owner = self.owner(&target);
+                        if owner.as_ref() != Some(name) {
+                            issues.push(ToolIssue::ForeignExecutable {
+                                path: path.clone(),
+                                owner,
+                            });
+                            continue;
+                        }
+                        if !target.exists() {
+                            issues.push(ToolIssue::StaleExecutable(path.clone()));
+                            continue;
+                        }
+                    }
+                }
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    issues.push(ToolIssue::StaleExecutable(path.clone()));
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            }
+
+            // Check whether another executable with the same name takes precedence on the `PATH`.
+            if let Some(found) = find_on_path(path) {
+                if uv_fs::is_same_file_allow_missing(&found, path) == Some(false) {
+                    let owner = self.owner(&found).filter(|owner| owner != name);
+                    issues.push(ToolIssue::ShadowedExecutable {
+                        name: entrypoint.name.clone(),
+                        path: found,
+                        owner,
+                    });
+                }
+            }
+        }
+
+        // Check the tool environment.
+        let environment = match PythonEnvironment::from_root(&tool_dir, cache) {
+            Ok(environment) => environment,
+            Err(
+                uv_python::Error::MissingEnvironment(_) | uv_python::Error::InvalidEnvironment(_),
+            ) => {
+                issues.push(ToolIssue::MissingEnvironment(tool_dir));
+                return Ok(issues);
+            }
+            Err(uv_python::Error::Query(uv_python::InterpreterError::NotFound(path))) => {
+                issues.push(ToolIssue::MissingInterpreter(path));
+                return Ok(issues);
+            }
+            Err(uv_python::Error::Query(uv_python::InterpreterError::BrokenSymlink(
+                broken_symlink,
+            ))) => {
+                let path = fs_err::read_link(&broken_symlink.path).unwrap_or(broken_symlink.path);
+                issues.push(ToolIssue::MissingInterpreter(path));
+                return Ok(issues);
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        // Check that the receipt is in sync with the packages in the environment.
+        let site_packages = SitePackages::from_environment(&environment)
+            .map_err(|err| Error::EnvironmentRead(tool_dir.clone(), err.to_string()))?;
+        for requirement in tool.requirements() {
+            if requirement.name != *name && site_packages.get_packages(&requirement.name).is_empty()
+            {
+                issues.push(ToolIssue::MissingRequirement(requirement.name.clone()));
+            }
+        }
+        let Some(package) = site_packages.get_packages(name).first().copied() else {
+            issues.push(ToolIssue::MissingPackage(name.clone()));
+            return Ok(issues);
+        };
+
+        // Check that the receipt is in sync with the executables provided by the package.
+        let provided = entrypoint_paths(&site_packages, package.name(), package.version())?
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<BTreeSet<_>>();
+        let recorded = tool
+            .entrypoints()
+            .iter()
+            .map(|entrypoint| entrypoint.name.clone())
+            .collect::<BTreeSet<_>>();
+        for name in provided.difference(&recorded) {
+            issues.push(ToolIssue::UntrackedExecutable(name.clone()));
+        }
+        for name in recorded.difference(&provided) {
+            issues.push(ToolIssue::UnknownExecutable(name.clone()));
+        }
+
+        Ok(issues)
+    }
+
+    /// Return the name of the tool whose environment contains the given path, if any.
+    ///
+    /// Symlinks are resolved, such that an executable linked into a tool environment is attributed
+    /// to that tool.
+    fn owner(&self, path: &Path) -> Option<PackageName> {
+        let canonical_root = fs_err::canonicalize(&self.root).ok();
+        let canonical_path = fs_err::canonicalize(path).ok();
+        let relative = [Some(path), canonical_path.as_deref()]
+            .into_iter()
+            .flatten()
+            .find_map(|path| {
+                [Some(self.root.as_path()), canonical_root.as_deref()]
+                    .into_iter()
+                    .flatten()
+                    .find_map(|root| path.strip_prefix(root).ok())
+            })?;
+        let name = relative.components().next()?.as_os_str().to_str()?;
+        PackageName::from_str(name).ok()
+    }
+}
+
+/// Find the first executable on the `PATH` with the same file name as the given path.
+fn find_on_path(path: &Path) -> Option<PathBuf> {
+    let file_name = path.file_name()?;
+    let paths = std::env::var_os(EnvVars::PATH)?;
+    std::env::split_paths(&paths)
+        .map(|directory| directory.join(file_name))
+        .find(|candidate| uv_fs::which::is_executable(candidate))
+}
@@ -15,6 +15,8 @@ use tracing::{debug, warn};
 
 use uv_install_wheel::read_record_file;
 
+pub use doctor::ToolIssue;
+pub use manifest::ToolManifest;
 pub use receipt::ToolReceipt;
 pub use tool::{Tool, ToolEntrypoint};
 use uv_cache::Cache;
@@ -24,6 +26,8 @@ use uv_python::{Interpreter, PythonEnvironment};
 use uv_state::{StateBucket, StateStore};
 use uv_static::EnvVars;
 
+mod doctor;
+mod manifest;
 mod receipt;
 mod tool;
 
@@ -35,6 +39,8 @@ pub enum Error {
     ReceiptWrite(PathBuf, #[source] Box<toml_edit::ser::Error>),
     #[error("Failed to read `uv-receipt.toml` at {0}")]
     ReceiptRead(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Failed to read tool manifest at {0}")]
+    ManifestRead(PathBuf, #[source] Box<toml::de::Error>),
     #[error(transparent)]
     VirtualEnvError(#[from] uv_virtualenv::Error),
     #[error("Failed to read package entry points {0}")]
@@ -204,8 +210,8 @@ impl InstalledTools {
 
     /// Return the [`PythonEnvironment`] for a given tool, if it exists.
     ///
-    /// Returns `Ok(None)` if the environment does not exist or is linked to a non-existent
-    /// interpreter.
+    /// Returns `Ok(None)` if the environment does not exist, is invalid (e.g., is missing its
+    /// Python executable), or is linked to a non-existent interpreter.
     ///
     /// Note it is generally incorrect to use this without [`Self::acquire_lock`].
     pub fn get_environment(
@@ -224,6 +230,11 @@ impl InstalledTools {
                 Ok(Some(venv))
             }
             Err(uv_python::Error::MissingEnvironment(_)) => Ok(None),
+            Err(uv_python::Error::InvalidEnvironment(err)) => {
+                warn!("Ignoring existing virtual environment: {err}");
+
+                Ok(None)
+            }
             Err(uv_python::Error::Query(uv_python::InterpreterError::NotFound(
                 interpreter_path,
             ))) => {
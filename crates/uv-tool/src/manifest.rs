@@ -0,0 +1,67 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::Deserialize;
+use toml_edit::{DocumentMut, Item, Table};
+
+use uv_pep508::PackageName;
+
+use crate::Tool;
+
+/// A manifest describing a set of tools, e.g., as produced by `uv tool export`.
+///
+/// Each tool is represented by the same table as in its `uv-receipt.toml`, keyed by the tool name.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ToolManifest {
+    #[serde(default)]
+    tools: BTreeMap<PackageName, Tool>,
+}
+
+impl ToolManifest {
+    /// Create a [`ToolManifest`] from a set of tools.
+    pub fn from_tools(tools: impl IntoIterator<Item = (PackageName, Tool)>) -> Self {
+        Self {
+            tools: tools.into_iter().collect(),
+        }
+    }
+
+    /// Read a [`ToolManifest`] from the given path.
+    pub fn from_path(path: &Path) -> Result<Self, crate::Error> {
+        let contents = fs_err::read_to_string(path)?;
+        toml::from_str(&contents)
+            .map_err(|err| crate::Error::ManifestRead(path.to_owned(), Box::new(err)))
+    }
+
+    /// Returns the TOML representation of this manifest.
+    pub fn to_toml(&self) -> Result<String, toml_edit::ser::Error> {
+        let mut tools = Table::new();
+        tools.set_implicit(true);
+        for (name, tool) in &self.tools {
+            // Omit the entry points, since their install paths are specific to this machine. They're
+            // determined when the tool is installed.
+            let mut tool = tool.to_toml()?;
+            tool.remove("entrypoints");
+            tools.insert(name.as_ref(), Item::Table(tool));
+        }
+
+        let mut doc = DocumentMut::new();
+        doc.insert("tools", Item::Table(tools));
+
+        Ok(doc.to_string())
+    }
+
+    /// Returns `true` if the manifest doesn't include any tools.
+    pub fn is_empty(&self) -> bool {
+        self.tools.is_empty()
+    }
+
+    /// Returns the tools in the manifest.
+    pub fn tools(&self) -> &BTreeMap<PackageName, Tool> {
+        &self.tools
+    }
+
+    /// Consume the manifest, returning its tools.
+    pub fn into_tools(self) -> BTreeMap<PackageName, Tool> {
+        self.tools
+    }
+}
@@ -27,6 +27,8 @@ pub struct Tool {
     entrypoints: Vec<ToolEntrypoint>,
     /// The [`ToolOptions`] used to install this tool.
     options: ToolOptions,
+    /// The locked resolution of the tool environment, as a `pylock.toml` document.
+    lock: Option<toml::Table>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -41,9 +43,11 @@ struct ToolWire {
     #[serde(default)]
     build_constraint_dependencies: Vec<Requirement>,
     python: Option<String>,
+    #[serde(default)]
     entrypoints: Vec<ToolEntrypoint>,
     #[serde(default)]
     options: ToolOptions,
+    lock: Option<toml::Table>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
@@ -70,6 +74,7 @@ impl From<Tool> for ToolWire {
             python: tool.python,
             entrypoints: tool.entrypoints,
             options: tool.options,
+            lock: tool.lock,
         }
     }
 }
@@ -93,6 +98,7 @@ impl TryFrom<ToolWire> for Tool {
             python: tool.python,
             entrypoints: tool.entrypoints,
             options: tool.options,
+            lock: tool.lock,
         })
     }
 }
@@ -157,6 +163,26 @@ fn each_element_on_its_line_array(elements: impl Iterator<Item = impl Into<Value
     array
 }
 
+/// Reset the document position of a table parsed from another document, along with those of its
+/// descendants.
+///
+/// Tables are rendered in order of their document position, so tables parsed from another document
+/// would otherwise be interleaved with the tables of the document they're inserted into.
+fn reset_positions(table: &mut Table) {
+    table.set_position(0);
+    for (_, item) in table.iter_mut() {
+        match item {
+            Item::Table(table) => reset_positions(table),
+            Item::ArrayOfTables(tables) => {
+                for table in tables.iter_mut() {
+                    reset_positions(table);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 impl Tool {
     /// Create a new `Tool`.
     pub fn new(
@@ -178,6 +204,7 @@ impl Tool {
             python,
             entrypoints,
             options,
+            lock: None,
         }
     }
 
@@ -187,6 +214,13 @@ impl Tool {
         Self { options, ..self }
     }
 
+    /// Create a new [`Tool`] with the given lock, i.e., a `pylock.toml` document describing the
+    /// resolved tool environment.
+    #[must_use]
+    pub fn with_lock(self, lock: Option<toml::Table>) -> Self {
+        Self { lock, ..self }
+    }
+
     /// Returns the TOML table for this tool.
     pub(crate) fn to_toml(&self) -> Result<Table, toml_edit::ser::Error> {
         let mut table = Table::new();
@@ -304,6 +338,16 @@ impl Tool {
             table.insert("options", Item::Table(serialized.into_table()));
         }
 
+        if let Some(ref lock) = self.lock {
+            let lock = toml::to_string(lock)
+                .map_err(|err| toml_edit::ser::Error::Custom(err.to_string()))?
+                .parse::<toml_edit::DocumentMut>()
+                .map_err(|err| toml_edit::ser::Error::Custom(err.to_string()))?;
+            let mut lock = lock.as_table().clone();
+            reset_positions(&mut lock);
+            table.insert("lock", Item::Table(lock));
+        }
+
         Ok(table)
     }
 
@@ -334,6 +378,10 @@ impl Tool {
     pub fn options(&self) -> &ToolOptions {
         &self.options
     }
+
+    pub fn lock(&self) -> Option<&toml::Table> {
+        self.lock.as_ref()
+    }
 }
 
 impl ToolEntrypoint {
@@ -527,6 +527,7 @@ async fn build_package(
         .cache(cache.clone())
         .index_locations(index_locations)
         .index_strategy(index_strategy)
+        .preview(preview)
         .keyring(keyring_provider)
         .markers(interpreter.markers())
         .platform(interpreter.platform())
@@ -0,0 +1,511 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::ffi::OsStr;
+use std::fmt::Write;
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use futures::{StreamExt, TryStreamExt};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tracing::debug;
+
+use uv_cache::{ArchiveId, Cache, CacheBucket, WheelCache};
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{Concurrency, PreviewMode};
+use uv_dispatch::{BuildDispatch, SharedState};
+use uv_distribution::DistributionDatabase;
+use uv_distribution_types::{BuiltDist, CachedDist, Dist, HashPolicy, Index, Name, SourceDist};
+use uv_fs::Simplified;
+use uv_python::{
+    EnvironmentPreference, PythonInstallation, PythonPreference, PythonRequest, PythonVariant,
+    VersionRequest,
+};
+use uv_resolver::{FlatIndex, Lock};
+use uv_types::{BuildIsolation, HashStrategy};
+use uv_warnings::warn_user;
+use uv_workspace::WorkspaceCache;
+
+use crate::commands::reporters::PrepareReporter;
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+use crate::settings::{NetworkSettings, ResolverSettings};
+
+/// The name of the manifest within a cache bundle.
+const MANIFEST: &str = "uv-cache-bundle.json";
+
+/// The manifest of a cache bundle.
+///
+/// Links to archives are recorded in the manifest, rather than as entries in the bundle, since
+/// they're represented differently across platforms (see [`Cache::create_link`]).
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    /// The version of the bundle format.
+    version: u32,
+    /// The links to archives in the bundle, keyed by their path relative to the cache root, with
+    /// `/` as the separator.
+    links: BTreeMap<String, ArchiveId>,
+}
+
+impl Manifest {
+    /// The current version of the bundle format.
+    const VERSION: u32 = 1;
+}
+
+/// Export the distributions referenced by a lockfile to a cache bundle.
+pub(crate) async fn cache_export(
+    lock_path: &Path,
+    output_file: &Path,
+    python: Option<&str>,
+    settings: &ResolverSettings,
+    network_settings: &NetworkSettings,
+    python_preference: PythonPreference,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    let ResolverSettings {
+        build_options,
+        config_setting,
+        dependency_metadata,
+        exclude_newer,
+        fork_strategy: _,
+        index_locations,
+        index_strategy,
+        keyring_provider,
+        link_mode,
+        no_build_isolation: _,
+        no_build_isolation_package: _,
+        prerelease: _,
+        resolution: _,
+        sources,
+        upgrade: _,
+    } = settings;
+
+    // Read the lockfile.
+    let lock_path = std::path::absolute(lock_path)?;
+    let contents = fs_err::tokio::read_to_string(&lock_path).await?;
+    let lock = toml::from_str::<Lock>(&contents)
+        .with_context(|| format!("Failed to parse: `{}`", lock_path.user_display()))?;
+    let root = lock_path
+        .parent()
+        .expect("lockfile path has a parent directory");
+
+    // Collect the remote distributions referenced by the lockfile. Source distributions for
+    // packages without wheels are built, such that the bundle includes a wheel for the current
+    // platform; other source distributions are only downloaded.
+    let mut dists = Vec::new();
+    let mut git = Vec::new();
+    let mut sdist_only = Vec::new();
+    for package in lock.packages() {
+        if package.as_git_ref()?.is_some() {
+            git.push(package.name());
+            continue;
+        }
+        let remote = package.to_remote_dists(root)?;
+        let has_wheels = remote.iter().any(|dist| matches!(dist, Dist::Built(_)));
+        if !has_wheels && !remote.is_empty() {
+            sdist_only.push(package.name());
+        }
+        let hashes = package.hashes();
+        for dist in remote {
+            let build = !has_wheels || matches!(dist, Dist::Built(_));
+            dists.push((dist, hashes.clone(), build));
+        }
+    }
+
+    if !git.is_empty() {
+        warn_user!(
+            "Git dependencies are not included in cache bundles: {}",
+            git.iter().map(|name| name.cyan()).join(", ")
+        );
+    }
+
+    if !sdist_only.is_empty() {
+        warn_user!(
+            "Build dependencies are not included in cache bundles, so packages without wheels can only be installed offline on a platform compatible with the current interpreter: {}",
+            sdist_only.iter().map(|name| name.cyan()).join(", ")
+        );
+    }
+
+    // Find an interpreter with which to build any source distributions.
+    let python_request = python.map(PythonRequest::parse).unwrap_or_else(|| {
+        PythonRequest::Version(VersionRequest::Range(
+            lock.requires_python().specifiers().clone(),
+            PythonVariant::Default,
+        ))
+    });
+    let interpreter = PythonInstallation::find(
+        &python_request,
+        EnvironmentPreference::Any,
+        python_preference,
+        cache,
+    )?
+    .into_interpreter();
+    let tags = interpreter.tags()?;
+
+    // Add all authenticated sources to the cache.
+    for index in index_locations.allowed_indexes() {
+        if let Some(credentials) = index.credentials() {
+            let credentials = Arc::new(credentials);
+            uv_auth::store_credentials(index.raw_url(), credentials.clone());
+            if let Some(root_url) = index.root_url() {
+                uv_auth::store_credentials(&root_url, credentials.clone());
+            }
+        }
+    }
+
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .keyring(*keyring_provider)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::try_from(client_builder)?
+        .cache(cache.clone())
+        .index_locations(index_locations)
+        .index_strategy(*index_strategy)
+        .preview(preview)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Read the build constraints from the lockfile.
+    let build_constraints = lock.build_constraints(root);
+
+    // TODO(charlie): These are all default values. We should consider whether we want to make them
+    // optional on the downstream APIs.
+    let build_hasher = HashStrategy::default();
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), cache);
+        let entries = client
+            .fetch_all(index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(entries, Some(tags), &build_hasher, build_options)
+    };
+
+    // Create a build dispatch.
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        cache,
+        build_constraints,
+        &interpreter,
+        index_locations,
+        &flat_index,
+        dependency_metadata,
+        SharedState::default(),
+        *index_strategy,
+        config_setting,
+        BuildIsolation::Isolated,
+        *link_mode,
+        build_options,
+        &build_hasher,
+        *exclude_newer,
+        *sources,
+        WorkspaceCache::default(),
+        concurrency,
+        preview,
+    );
+
+    // Download (or build) each distribution, collecting the cache entries that it populated.
+    let reporter: Arc<dyn uv_installer::PrepareReporter> = Arc::new(
+        PrepareReporter::from(printer)
+            .with_length(dists.iter().filter(|(.., build)| *build).count() as u64),
+    );
+    let database = DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads)
+        .with_reporter(reporter.clone().into_distribution_reporter());
+
+    let wheels = futures::stream::iter(dists)
+        .map(|(dist, hashes, build)| {
+            let database = &database;
+            let reporter = &reporter;
+            async move {
+                // For source distributions that aren't built, fetching the metadata is sufficient
+                // to populate the cache with the source distribution itself.
+                if !build {
+                    database
+                        .get_or_build_wheel_metadata(&dist, HashPolicy::Validate(hashes.as_slice()))
+                        .await
+                        .with_context(|| format!("Failed to fetch: `{dist}`"))?;
+                    return Ok::<_, anyhow::Error>((dist, None));
+                }
+                let wheel = database
+                    .get_or_build_wheel(&dist, tags, HashPolicy::Validate(hashes.as_slice()))
+                    .await
+                    .with_context(|| format!("Failed to fetch: `{dist}`"))?;
+                let archive = wheel.target().to_path_buf();
+                reporter.on_progress(&CachedDist::from(wheel));
+                Ok((dist, Some(archive)))
+            }
+        })
+        .buffer_unordered(concurrency.downloads)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    reporter.on_complete();
+
+    let mut contents = Contents::default();
+    for (dist, archive) in &wheels {
+        contents.add_dist(dist, cache)?;
+        if let Some(id) = archive
+            .as_deref()
+            .and_then(Path::file_name)
+            .and_then(OsStr::to_str)
+            .and_then(|name| ArchiveId::from_str(name).ok())
+        {
+            contents.archives.insert(id.to_string());
+        }
+    }
+
+    // Write the bundle to a temporary file, then move it into place.
+    let output_file = std::path::absolute(output_file)?;
+    let parent = output_file
+        .parent()
+        .expect("output path has a parent directory");
+    fs_err::tokio::create_dir_all(parent).await?;
+    let temp_file = tempfile::NamedTempFile::new_in(parent)?;
+    contents
+        .write(fs_err::tokio::File::create(temp_file.path()).await?, cache)
+        .await
+        .with_context(|| format!("Failed to write bundle to: {}", output_file.user_display()))?;
+    temp_file
+        .persist(&output_file)
+        .with_context(|| format!("Failed to write bundle to: {}", output_file.user_display()))?;
+
+    let size = fs_err::metadata(&output_file)?.len();
+    let (bytes, unit) = human_readable_bytes(size);
+    writeln!(
+        printer.stderr(),
+        "Exported {} {} to {} ({})",
+        wheels.len(),
+        if wheels.len() == 1 {
+            "distribution"
+        } else {
+            "distributions"
+        },
+        output_file.user_display().cyan(),
+        format!("{bytes:.1}{unit}").green()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Import a cache bundle into the cache.
+pub(crate) async fn cache_import(
+    bundle: &Path,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Unpack the bundle into a temporary directory within the cache, such that its entries can be
+    // moved (or hard-linked) into place.
+    let temp_dir = tempfile::tempdir_in(cache.root())?;
+    let reader = fs_err::tokio::File::open(bundle).await?;
+    uv_extract::stream::untar_zst(reader, temp_dir.path())
+        .await
+        .with_context(|| format!("Failed to unpack bundle: {}", bundle.user_display()))?;
+
+    let manifest = fs_err::read(temp_dir.path().join(MANIFEST))
+        .map_err(anyhow::Error::from)
+        .and_then(|contents| Ok(serde_json::from_slice::<Manifest>(&contents)?))
+        .with_context(|| format!("Invalid cache bundle: {}", bundle.user_display()))?;
+    if manifest.version != Manifest::VERSION {
+        anyhow::bail!(
+            "Unsupported cache bundle version {} (expected {}): {}",
+            manifest.version,
+            Manifest::VERSION,
+            bundle.user_display()
+        );
+    }
+    fs_err::remove_file(temp_dir.path().join(MANIFEST))?;
+
+    // Import the entries, then recreate the links to any imported archives.
+    let mut imported = cache.import(temp_dir.path()).with_context(|| {
+        format!(
+            "Failed to import bundle into: {}",
+            cache.root().user_display()
+        )
+    })?;
+    for (relative, id) in &manifest.links {
+        // Ignore any links outside the cache.
+        if relative
+            .split('/')
+            .any(|component| matches!(component, "" | "." | ".."))
+            || !matches!(
+                AsRef::<Path>::as_ref(id).components().collect::<Vec<_>>()[..],
+                [Component::Normal(_)]
+            )
+        {
+            continue;
+        }
+        let path = relative
+            .split('/')
+            .fold(cache.root().to_path_buf(), |path, component| {
+                path.join(component)
+            });
+        if fs_err::symlink_metadata(&path).is_ok() || !cache.archive(id).is_dir() {
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        cache.create_link(id, &path)?;
+        imported += 1;
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Imported {imported} cache {} into {}",
+        if imported == 1 { "entry" } else { "entries" },
+        cache.root().user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// The entries to include in a cache bundle.
+#[derive(Debug, Default)]
+struct Contents {
+    /// The files to include, relative to the cache root.
+    files: BTreeSet<PathBuf>,
+    /// The archives to include.
+    archives: BTreeSet<String>,
+    /// The links to archives, relative to the cache root.
+    links: BTreeMap<String, ArchiveId>,
+}
+
+impl Contents {
+    /// Add the cache entries for the given distribution.
+    fn add_dist(&mut self, dist: &Dist, cache: &Cache) -> Result<()> {
+        match dist {
+            // For wheels, include the pointer to the unzipped archive.
+            Dist::Built(BuiltDist::Registry(wheels)) => {
+                let wheel = wheels.best_wheel();
+                let shard = cache.shard(
+                    CacheBucket::Wheels,
+                    WheelCache::Index(&wheel.index).wheel_dir(wheel.name().as_ref()),
+                );
+                let key = wheel.filename.cache_key();
+                self.add_path(&shard.join(format!("{key}.http")), cache)?;
+                self.add_path(&shard.join(format!("{key}.rev")), cache)?;
+            }
+            Dist::Built(BuiltDist::DirectUrl(wheel)) => {
+                let shard = cache.shard(
+                    CacheBucket::Wheels,
+                    WheelCache::Url(&wheel.url).wheel_dir(wheel.name().as_ref()),
+                );
+                let key = wheel.filename.cache_key();
+                self.add_path(&shard.join(format!("{key}.http")), cache)?;
+            }
+            // For source distributions, include the entire shard, which contains the source
+            // distribution itself, along with any wheels built from it.
+            Dist::Source(SourceDist::Registry(sdist)) => {
+                let shard = cache.shard(
+                    CacheBucket::SourceDistributions,
+                    WheelCache::Index(&sdist.index)
+                        .wheel_dir(sdist.name.as_ref())
+                        .join(sdist.version.to_string()),
+                );
+                self.add_path(&shard, cache)?;
+            }
+            Dist::Source(SourceDist::DirectUrl(sdist)) => {
+                let shard = cache.shard(
+                    CacheBucket::SourceDistributions,
+                    WheelCache::Url(&sdist.url).root(),
+                );
+                self.add_path(&shard, cache)?;
+            }
+            Dist::Built(BuiltDist::Path(_))
+            | Dist::Source(SourceDist::Git(_) | SourceDist::Path(_) | SourceDist::Directory(_)) => {
+            }
+        }
+        Ok(())
+    }
+
+    /// Add the file or directory at the given path, recording any links to archives.
+    fn add_path(&mut self, path: &Path, cache: &Cache) -> Result<()> {
+        if fs_err::symlink_metadata(path).is_err() {
+            return Ok(());
+        }
+        for entry in walkdir::WalkDir::new(path) {
+            let entry = entry?;
+            if entry.file_type().is_dir() {
+                continue;
+            }
+            if entry
+                .path()
+                .extension()
+                .is_some_and(|extension| extension == "lock")
+            {
+                continue;
+            }
+            let Ok(relative) = entry.path().strip_prefix(cache.root()) else {
+                continue;
+            };
+            if let Some(id) = cache.archive_link(entry.path()) {
+                debug!("Including cache link: {}", relative.display());
+                self.archives.insert(id.to_string());
+                self.links.insert(to_portable(relative)?, id);
+            } else if entry.file_type().is_file() {
+                self.files.insert(relative.to_path_buf());
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the bundle, as a Zstandard-compressed tar archive.
+    async fn write(self, file: fs_err::tokio::File, cache: &Cache) -> Result<()> {
+        let encoder = async_compression::tokio::write::ZstdEncoder::new(file);
+        let mut builder = tokio_tar::Builder::new(encoder);
+
+        let manifest = serde_json::to_vec_pretty(&Manifest {
+            version: Manifest::VERSION,
+            links: self.links,
+        })?;
+        let mut header = tokio_tar::Header::new_gnu();
+        header.set_size(manifest.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, MANIFEST, manifest.as_slice())
+            .await?;
+
+        for relative in &self.files {
+            builder
+                .append_path_with_name(cache.root().join(relative), relative)
+                .await?;
+        }
+
+        for id in &self.archives {
+            let path = cache.bucket(CacheBucket::Archive).join(id);
+            if !path.is_dir() {
+                continue;
+            }
+            let relative = Path::new(CacheBucket::Archive.to_str()).join(id);
+            builder.append_dir_all(&relative, &path).await?;
+        }
+
+        let mut encoder = builder.into_inner().await?;
+        encoder.shutdown().await?;
+        Ok(())
+    }
+}
+
+/// Convert a path relative to the cache root to a `/`-separated string.
+fn to_portable(relative: &Path) -> Result<String> {
+    relative
+        .components()
+        .map(|component| match component {
+            Component::Normal(component) => component.to_str(),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(|components| components.join("/"))
+        .with_context(|| format!("Invalid cache path: {}", relative.display()))
+}
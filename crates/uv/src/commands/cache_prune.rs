@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
@@ -9,8 +10,15 @@ use uv_fs::Simplified;
 use crate::commands::{human_readable_bytes, ExitStatus};
 use crate::printer::Printer;
 
-/// Prune all unreachable objects from the cache.
-pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+/// Prune all unreachable objects from the cache, optionally evicting the least recently used
+/// entries.
+pub(crate) fn cache_prune(
+    ci: bool,
+    max_size: Option<u64>,
+    unused_for: Option<Duration>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
     if !cache.root().exists() {
         writeln!(
             printer.stderr(),
@@ -37,6 +45,11 @@ pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<E
         .prune(ci)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
 
+    // Evict the least recently used entries, if requested.
+    summary += cache
+        .evict(max_size, unused_for)
+        .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
+
     // Write a summary of the number of files and directories removed.
     match (summary.num_files, summary.num_dirs) {
         (0, 0) => {
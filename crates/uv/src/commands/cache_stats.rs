@@ -0,0 +1,182 @@
+use std::fmt::Write;
+use std::time::SystemTime;
+
+use anyhow::{Context, Result};
+use jiff::Timestamp;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cache::{BucketStats, Cache};
+use uv_cli::CacheStatsFormat;
+use uv_fs::Simplified;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+#[derive(Serialize)]
+struct PrintData {
+    path: String,
+    total_bytes: u64,
+    num_files: u64,
+    last_accessed: Option<Timestamp>,
+    buckets: Vec<PrintBucket>,
+    unreferenced_archives: u64,
+    unreferenced_bytes: u64,
+}
+
+#[derive(Serialize)]
+struct PrintBucket {
+    name: String,
+    total_bytes: u64,
+    num_files: u64,
+    last_accessed: Option<Timestamp>,
+    packages: Vec<PrintPackage>,
+}
+
+#[derive(Serialize)]
+struct PrintPackage {
+    name: String,
+    total_bytes: u64,
+}
+
+/// Show the size of the cache, broken down by bucket and package.
+pub(crate) fn cache_stats(
+    top: usize,
+    output_format: CacheStatsFormat,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let stats = cache
+        .stats()
+        .with_context(|| format!("Failed to read cache at: {}", cache.root().user_display()))?;
+
+    match output_format {
+        CacheStatsFormat::Json => {
+            let data = PrintData {
+                path: cache.root().user_display().to_string(),
+                total_bytes: stats.total_bytes(),
+                num_files: stats.num_files(),
+                last_accessed: stats.last_accessed().and_then(timestamp),
+                buckets: stats
+                    .buckets
+                    .iter()
+                    .map(|(bucket, bucket_stats)| PrintBucket {
+                        name: bucket.to_string(),
+                        total_bytes: bucket_stats.total_bytes,
+                        num_files: bucket_stats.num_files,
+                        last_accessed: bucket_stats.last_accessed.and_then(timestamp),
+                        packages: bucket_stats
+                            .top_packages(top)
+                            .into_iter()
+                            .map(|(name, total_bytes)| PrintPackage {
+                                name: name.to_string(),
+                                total_bytes,
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+                unreferenced_archives: stats.unreferenced_archives,
+                unreferenced_bytes: stats.unreferenced_bytes,
+            };
+            writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
+        }
+        CacheStatsFormat::Text => {
+            writeln!(
+                printer.stderr(),
+                "Cache at: {}",
+                cache.root().user_display().cyan()
+            )?;
+
+            for (bucket, bucket_stats) in &stats.buckets {
+                if bucket_stats.num_files == 0 {
+                    continue;
+                }
+                writeln!(
+                    printer.stdout(),
+                    "{} {}",
+                    bucket.to_string().bold(),
+                    summary(bucket_stats)
+                )?;
+                for (name, total_bytes) in bucket_stats.top_packages(top) {
+                    writeln!(
+                        printer.stdout(),
+                        "- {name} ({})",
+                        format_bytes(total_bytes).green()
+                    )?;
+                }
+            }
+
+            writeln!(
+                printer.stdout(),
+                "{} ({}, {} file{})",
+                "Total".bold(),
+                format_bytes(stats.total_bytes()).green(),
+                stats.num_files(),
+                if stats.num_files() == 1 { "" } else { "s" }
+            )?;
+
+            if stats.unreferenced_archives > 0 {
+                writeln!(
+                    printer.stdout(),
+                    "{} ({}, {} archive{}; run `{}` to remove)",
+                    "Unreferenced".bold(),
+                    format_bytes(stats.unreferenced_bytes).green(),
+                    stats.unreferenced_archives,
+                    if stats.unreferenced_archives == 1 {
+                        ""
+                    } else {
+                        "s"
+                    },
+                    "uv cache prune".green()
+                )?;
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Format the size, file count, and last access time of a cache bucket.
+fn summary(stats: &BucketStats) -> String {
+    let last_accessed = stats
+        .last_accessed
+        .and_then(timestamp)
+        .map(|timestamp| {
+            format!(
+                ", last accessed {}",
+                timestamp.strftime("%Y-%m-%d %H:%M:%S UTC")
+            )
+        })
+        .unwrap_or_default();
+    format!(
+        "({}, {} file{}{last_accessed})",
+        format_bytes(stats.total_bytes).green(),
+        stats.num_files,
+        if stats.num_files == 1 { "" } else { "s" }
+    )
+}
+
+/// Format a byte count for display.
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
+
+/// Convert a [`SystemTime`] to a [`Timestamp`], truncated to the second.
+fn timestamp(time: SystemTime) -> Option<Timestamp> {
+    let timestamp = Timestamp::try_from(time).ok()?;
+    Timestamp::from_second(timestamp.as_second()).ok()
+}
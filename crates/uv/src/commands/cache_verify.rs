@@ -0,0 +1,193 @@
+use std::ffi::OsStr;
+use std::fmt::{Display, Write};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use serde::de::IgnoredAny;
+
+use uv_cache::{rm_rf, Cache, CacheBucket, Removal};
+use uv_client::{DataWithCachePolicy, OwnedArchive, SimpleMetadata};
+use uv_fs::Simplified;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// Verify the integrity of the cache, removing any corrupt entries.
+pub(crate) fn cache_verify(dry_run: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Verifying cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    // Verify each unzipped wheel against its `RECORD`.
+    let mut archives = Vec::new();
+    match fs_err::read_dir(cache.bucket(CacheBucket::Archive)) {
+        Ok(entries) => {
+            for entry in entries {
+                let path = entry?.path();
+                if !path.is_dir() {
+                    continue;
+                }
+                if let Err(err) = uv_install_wheel::verify_record(&path) {
+                    report(&path, &err, printer)?;
+                    archives.push(path);
+                }
+            }
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => {
+            return Err(err).with_context(|| {
+                format!("Failed to read cache at: {}", cache.root().user_display())
+            })
+        }
+    }
+
+    // Verify that each serialized entry can be deserialized.
+    let mut entries = Vec::new();
+    for bucket in [
+        CacheBucket::Wheels,
+        CacheBucket::SourceDistributions,
+        CacheBucket::FlatIndex,
+        CacheBucket::Interpreter,
+        CacheBucket::Simple,
+    ] {
+        let bucket_path = cache.bucket(bucket);
+        if !bucket_path.is_dir() {
+            continue;
+        }
+        for entry in walkdir::WalkDir::new(&bucket_path) {
+            let entry = entry.with_context(|| {
+                format!("Failed to read cache at: {}", cache.root().user_display())
+            })?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            if let Err(err) = verify_entry(bucket, entry.path()) {
+                report(entry.path(), &err, printer)?;
+                entries.push(entry.into_path());
+            }
+        }
+    }
+
+    let num_corrupt = archives.len() + entries.len();
+    if num_corrupt == 0 {
+        writeln!(printer.stderr(), "No corrupt entries found")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    if dry_run {
+        writeln!(
+            printer.stderr(),
+            "Found {num_corrupt} corrupt {}",
+            if num_corrupt == 1 { "entry" } else { "entries" }
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Remove the corrupt entries, along with any links to corrupt archives.
+    let mut summary = Removal::default();
+    summary += cache
+        .remove_archives(&archives)
+        .with_context(|| format!("Failed to clean cache at: {}", cache.root().user_display()))?;
+    for entry in &entries {
+        summary += rm_rf(entry).with_context(|| {
+            format!("Failed to clean cache at: {}", cache.root().user_display())
+        })?;
+    }
+
+    write!(
+        printer.stderr(),
+        "Removed {num_corrupt} corrupt {}",
+        if num_corrupt == 1 { "entry" } else { "entries" }
+    )?;
+
+    // If any, write a summary of the total byte count removed.
+    if summary.total_bytes > 0 {
+        let bytes = if summary.total_bytes < 1024 {
+            format!("{}B", summary.total_bytes)
+        } else {
+            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+            format!("{bytes:.1}{unit}")
+        };
+        write!(printer.stderr(), " ({})", bytes.green())?;
+    }
+
+    writeln!(printer.stderr())?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Verify that the serialized cache entry at the given path can be deserialized.
+///
+/// Entries that aren't serialized by uv (like lockfiles and built wheels) are ignored.
+fn verify_entry(bucket: CacheBucket, path: &Path) -> Result<()> {
+    // The source distribution bucket also contains unpacked source trees, which may contain
+    // arbitrary files; only consider the entries written by uv.
+    if bucket == CacheBucket::SourceDistributions
+        && !path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .is_some_and(|name| {
+                matches!(name, "metadata.msgpack" | "revision.http" | "revision.rev")
+            })
+    {
+        return Ok(());
+    }
+
+    match path.extension().and_then(OsStr::to_str) {
+        // Index responses, archived with `rkyv` alongside their HTTP cache policy.
+        Some("rkyv") => {
+            let data = DataWithCachePolicy::from_reader(fs_err::File::open(path)?)?.data;
+            OwnedArchive::<SimpleMetadata>::new(data)?;
+        }
+        // Archive pointers and revisions, encoded with `MsgPack` alongside their HTTP cache
+        // policy.
+        Some("http") => {
+            let data = DataWithCachePolicy::from_reader(fs_err::File::open(path)?)?.data;
+            rmp_serde::from_slice::<IgnoredAny>(&data)?;
+        }
+        // Built wheel metadata and interpreter information, encoded with `MsgPack`.
+        Some("msgpack")
+            if matches!(
+                bucket,
+                CacheBucket::SourceDistributions | CacheBucket::Interpreter
+            ) =>
+        {
+            rmp_serde::from_slice::<IgnoredAny>(&fs_err::read(path)?)?;
+        }
+        // Wheel metadata and flat index responses, encoded with `MsgPack` alongside their HTTP
+        // cache policy.
+        Some("msgpack") => {
+            let data = DataWithCachePolicy::from_reader(fs_err::File::open(path)?)?.data;
+            rmp_serde::from_slice::<IgnoredAny>(&data)?;
+        }
+        // Archive pointers and revisions for local files, encoded with `MsgPack`.
+        Some("rev") => {
+            rmp_serde::from_slice::<IgnoredAny>(&fs_err::read(path)?)?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Report a corrupt cache entry.
+fn report(path: &Path, err: &dyn Display, printer: Printer) -> Result<()> {
+    writeln!(
+        printer.stderr(),
+        "{}: {} ({err})",
+        "Corrupt cache entry".red().bold(),
+        path.user_display().cyan()
+    )?;
+    Ok(())
+}
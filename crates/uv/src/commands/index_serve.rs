@@ -0,0 +1,1120 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::Infallible;
+use std::fmt::Write;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+use anyhow::{bail, Context, Result};
+use futures::TryStreamExt;
+use http_body_util::combinators::UnsyncBoxBody;
+use http_body_util::{BodyExt, Full, StreamBody};
+use hyper::body::{Bytes, Frame, Incoming};
+use hyper::header::{HeaderValue, ACCEPT, CONTENT_LENGTH, CONTENT_TYPE, LOCATION, VARY};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use itertools::Itertools;
+use jiff::Timestamp;
+use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+use tempfile::{TempDir, TempPath};
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpListener;
+use tokio_util::io::ReaderStream;
+use tracing::debug;
+use walkdir::WalkDir;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use uv_cache::{Cache, CacheBucket};
+use uv_client::SimpleHtml;
+use uv_distribution_filename::{DistFilename, WheelFilename};
+use uv_extract::hash::Hasher;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_pypi_types::{
+    CoreMetadata, File, HashAlgorithm, HashDigest, Hashes, ProjectStatus, ResolutionMetadata,
+    SimpleJson,
+};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The version of the simple repository API implemented by the server, per PEP 691 and PEP 700.
+const API_VERSION: &str = "1.1";
+
+/// The media type for JSON responses, per PEP 691.
+const JSON_CONTENT_TYPE: &str = "application/vnd.pypi.simple.v1+json";
+
+/// The media type for HTML responses, per PEP 691.
+const HTML_CONTENT_TYPE: &str = "application/vnd.pypi.simple.v1+html";
+
+/// The maximum size of an upload request, including the distribution and its metadata.
+///
+/// PyPI limits distributions to 100 MB by default, but allows larger limits per project.
+const MAX_UPLOAD_SIZE: u64 = 1024 * 1024 * 1024;
+
+/// The interval after which the source is re-scanned for added or removed distributions.
+///
+/// Uploads are visible immediately, since the source is re-scanned after each upload.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+type Body = UnsyncBoxBody<Bytes, std::io::Error>;
+
+/// Serve a directory of distributions, or the wheels in the cache, as a local package index.
+///
+/// If `dir` is `None`, serves the wheels in the cache.
+pub(crate) async fn index_serve(
+    dir: Option<PathBuf>,
+    host: &str,
+    port: u16,
+    upload: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let source = if let Some(dir) = dir {
+        if !dir.is_dir() {
+            bail!("Directory not found: `{}`", dir.user_display());
+        }
+        Source::Directory(std::path::absolute(dir)?)
+    } else {
+        Source::Cache {
+            cache: cache.clone(),
+            scratch: tempfile::tempdir_in(cache.root())?,
+        }
+    };
+
+    let index = Arc::new(Index {
+        source,
+        upload,
+        printer,
+        projects: Mutex::default(),
+        refreshing: AtomicBool::new(false),
+        files: Mutex::default(),
+        archives: Mutex::default(),
+    });
+
+    // Scan the source up-front, to surface any errors before binding.
+    let num_projects = index
+        .blocking(|index| Ok(index.refresh()?.projects.len()))
+        .await?;
+
+    let listener = TcpListener::bind((host, port))
+        .await
+        .with_context(|| format!("Failed to listen on `{host}:{port}`"))?;
+    let addr = listener.local_addr()?;
+
+    writeln!(
+        printer.stderr(),
+        "Serving {num_projects} {} from {} at {}",
+        if num_projects == 1 {
+            "project"
+        } else {
+            "projects"
+        },
+        index.source.root().user_display().cyan(),
+        format!("http://{addr}/simple/").cyan()
+    )?;
+    if upload {
+        writeln!(
+            printer.stderr(),
+            "Accepting uploads at {}",
+            format!("http://{addr}/legacy/").cyan()
+        )?;
+    }
+
+    let shutdown = tokio::signal::ctrl_c();
+    tokio::pin!(shutdown);
+
+    loop {
+        let (stream, remote) = tokio::select! {
+            result = listener.accept() => match result {
+                Ok(connection) => connection,
+                Err(err) => {
+                    debug!("Failed to accept connection: {err}");
+                    continue;
+                }
+            },
+            _ = &mut shutdown => break,
+        };
+
+        let index = Arc::clone(&index);
+        tokio::spawn(async move {
+            let service = service_fn(move |request| {
+                let index = Arc::clone(&index);
+                async move { Ok::<_, Infallible>(index.handle(request).await) }
+            });
+            if let Err(err) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                debug!("Failed to serve connection from {remote}: {err}");
+            }
+        });
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// The source of the distributions served by the index.
+enum Source {
+    /// A flat directory of wheels and source distributions.
+    Directory(PathBuf),
+    /// The wheels in the cache, along with a scratch directory in which to re-archive unzipped
+    /// wheels.
+    Cache { cache: Cache, scratch: TempDir },
+}
+
+impl Source {
+    /// The root directory of the source.
+    fn root(&self) -> &Path {
+        match self {
+            Self::Directory(dir) => dir,
+            Self::Cache { cache, .. } => cache.root(),
+        }
+    }
+}
+
+/// A distribution served by the index.
+#[derive(Debug, Clone)]
+struct Distribution {
+    filename: DistFilename,
+    location: Location,
+}
+
+/// The location of a distribution on disk.
+#[derive(Debug, Clone)]
+enum Location {
+    /// A wheel or source distribution archive.
+    File(PathBuf),
+    /// An unzipped wheel in the cache, which must be re-archived before it can be served.
+    Archive(PathBuf),
+}
+
+impl Location {
+    fn path(&self) -> &Path {
+        match self {
+            Self::File(path) | Self::Archive(path) => path,
+        }
+    }
+}
+
+/// A scan of the distributions in the source.
+#[derive(Debug)]
+struct Projects {
+    /// The time at which the source was scanned.
+    scanned: Instant,
+    /// The distributions, grouped by package name and sorted by filename.
+    projects: BTreeMap<PackageName, Vec<Distribution>>,
+    /// The distributions, keyed by filename.
+    files: FxHashMap<String, Distribution>,
+}
+
+/// The computed properties of a distribution, as served by the index.
+#[derive(Debug)]
+struct FileInfo {
+    /// The path to the archive on disk.
+    path: PathBuf,
+    /// The size of the archive, in bytes.
+    size: u64,
+    /// The SHA-256 digest of the archive.
+    sha256: String,
+    /// The time at which the distribution was last modified.
+    modified: SystemTime,
+    /// The core metadata of the distribution, for wheels.
+    metadata: Option<WheelMetadata>,
+}
+
+/// The core metadata of a wheel, served alongside the wheel per PEP 658.
+#[derive(Debug)]
+struct WheelMetadata {
+    /// The contents of the `METADATA` file.
+    contents: Vec<u8>,
+    /// The SHA-256 digest of the `METADATA` file.
+    sha256: String,
+    /// The `Requires-Python` specifiers from the `METADATA` file, if any.
+    requires_python: Option<VersionSpecifiers>,
+}
+
+struct Index {
+    source: Source,
+    upload: bool,
+    printer: Printer,
+    /// The most recent scan of the source.
+    projects: Mutex<Option<Arc<Projects>>>,
+    /// Whether the source is being re-scanned in the background.
+    refreshing: AtomicBool,
+    /// The computed [`FileInfo`] for each distribution, keyed by its location in the source.
+    ///
+    /// Entries are invalidated if the size or modification time of the location changes.
+    files: Mutex<FxHashMap<PathBuf, (u64, SystemTime, Arc<FileInfo>)>>,
+    /// The wheel filename for each unzipped wheel in the cache, or `None` if the filename
+    /// couldn't be determined.
+    archives: Mutex<FxHashMap<PathBuf, Option<WheelFilename>>>,
+}
+
+impl Index {
+    /// Run a blocking operation against the index on the blocking thread pool.
+    async fn blocking<T: Send + 'static>(
+        self: &Arc<Self>,
+        f: impl FnOnce(&Self) -> Result<T> + Send + 'static,
+    ) -> Result<T> {
+        let index = Arc::clone(self);
+        tokio::task::spawn_blocking(move || f(&index)).await?
+    }
+
+    /// Handle a single request, converting any errors into an internal server error.
+    async fn handle(self: Arc<Self>, request: Request<Incoming>) -> Response<Body> {
+        let method = request.method().clone();
+        let path = request.uri().path().to_string();
+        let response = match self.route(request).await {
+            Ok(response) => response,
+            Err(err) => {
+                warn_user!("Failed to handle request for `{path}`: {err:#}");
+                text(StatusCode::INTERNAL_SERVER_ERROR, format!("{err:#}"))
+            }
+        };
+        debug!("{method} {path} -> {}", response.status());
+        response
+    }
+
+    async fn route(self: &Arc<Self>, request: Request<Incoming>) -> Result<Response<Body>> {
+        let path = request.uri().path().to_string();
+
+        if request.method() == Method::POST {
+            if !matches!(path.as_str(), "/" | "/legacy/") {
+                return Ok(text(StatusCode::NOT_FOUND, "Not found"));
+            }
+            if !self.upload {
+                return Ok(text(
+                    StatusCode::METHOD_NOT_ALLOWED,
+                    "Uploads are not enabled; restart the index with `--upload` to accept uploads",
+                ));
+            }
+            return self.upload(request).await;
+        }
+
+        if !matches!(*request.method(), Method::GET | Method::HEAD) {
+            return Ok(text(StatusCode::METHOD_NOT_ALLOWED, "Method not allowed"));
+        }
+
+        if matches!(path.as_str(), "/" | "/simple") {
+            return redirect("/simple/");
+        }
+
+        let format = Format::from_accept(request.headers().get(ACCEPT));
+
+        if path == "/simple/" {
+            let projects = self.projects().await?;
+            return match format {
+                Format::Json => json(&IndexPage {
+                    meta: Meta::default(),
+                    projects: projects
+                        .projects
+                        .keys()
+                        .map(|name| ProjectEntry { name })
+                        .collect(),
+                }),
+                Format::Html(content_type) => Ok(html(
+                    content_type,
+                    SimpleHtml::render_index(projects.projects.keys()),
+                )),
+            };
+        }
+
+        if let Some(name) = path.strip_prefix("/simple/") {
+            let Some(name) = name.strip_suffix('/') else {
+                return redirect(&format!("{path}/"));
+            };
+            let Ok(package) = PackageName::from_str(name) else {
+                return Ok(text(StatusCode::NOT_FOUND, "Not found"));
+            };
+            // Redirect to the normalized name, per PEP 503.
+            if package.as_str() != name {
+                return redirect(&format!("/simple/{package}/"));
+            }
+
+            let projects = self.projects().await?;
+            let project = {
+                let package = package.clone();
+                self.blocking(move |index| index.project(&projects, &package))
+                    .await?
+            };
+            let Some((files, versions)) = project else {
+                return Ok(text(StatusCode::NOT_FOUND, "Not found"));
+            };
+            return match format {
+                Format::Json => json(&ProjectPage {
+                    meta: Meta::default(),
+                    name: &package,
+                    simple: SimpleJson {
+                        files,
+                        project_status: ProjectStatus::default(),
+                        tracks: Vec::new(),
+                        alternate_locations: Vec::new(),
+                    },
+                    versions,
+                }),
+                Format::Html(content_type) => {
+                    Ok(html(content_type, SimpleHtml::render(&package, &files)))
+                }
+            };
+        }
+
+        if let Some(filename) = path.strip_prefix("/files/") {
+            let filename = percent_encoding::percent_decode_str(filename)
+                .decode_utf8()?
+                .to_string();
+            let (filename, metadata) = match filename.strip_suffix(".metadata") {
+                Some(filename) => (filename.to_string(), true),
+                None => (filename, false),
+            };
+
+            let projects = self.projects().await?;
+            let Some(info) = self
+                .blocking(move |index| index.find(&projects, &filename))
+                .await?
+            else {
+                return Ok(text(StatusCode::NOT_FOUND, "Not found"));
+            };
+
+            // Serve the core metadata, per PEP 658.
+            if metadata {
+                let Some(metadata) = &info.metadata else {
+                    return Ok(text(StatusCode::NOT_FOUND, "Not found"));
+                };
+                return Ok(bytes(
+                    StatusCode::OK,
+                    "application/octet-stream",
+                    metadata.contents.clone(),
+                ));
+            }
+
+            let file = fs_err::tokio::File::open(&info.path).await?;
+            let body = StreamBody::new(ReaderStream::new(file).map_ok(Frame::data));
+            let mut response = Response::new(body.boxed_unsync());
+            response.headers_mut().insert(
+                CONTENT_TYPE,
+                HeaderValue::from_static("application/octet-stream"),
+            );
+            response
+                .headers_mut()
+                .insert(CONTENT_LENGTH, HeaderValue::from(info.size));
+            return Ok(response);
+        }
+
+        Ok(text(StatusCode::NOT_FOUND, "Not found"))
+    }
+
+    /// Accept an upload via the legacy upload API, as used by `uv publish` and `twine`.
+    async fn upload(self: &Arc<Self>, request: Request<Incoming>) -> Result<Response<Body>> {
+        let Some(boundary) = request
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .and_then(|content_type| multer::parse_boundary(content_type).ok())
+        else {
+            return Ok(text(
+                StatusCode::BAD_REQUEST,
+                "Expected a `multipart/form-data` request",
+            ));
+        };
+
+        let Source::Directory(dir) = &self.source else {
+            return Ok(text(
+                StatusCode::METHOD_NOT_ALLOWED,
+                "Uploads are not supported when serving the cache",
+            ));
+        };
+
+        // Reject oversized uploads up front, if the size is known.
+        if request
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|length| length.to_str().ok()?.parse::<u64>().ok())
+            .is_some_and(|length| length > MAX_UPLOAD_SIZE)
+        {
+            return Ok(text(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                format!("Uploads are limited to {MAX_UPLOAD_SIZE} bytes"),
+            ));
+        }
+
+        let constraints = multer::Constraints::new()
+            .size_limit(multer::SizeLimit::new().whole_stream(MAX_UPLOAD_SIZE));
+        let mut multipart = multer::Multipart::with_constraints(
+            request.into_body().into_data_stream(),
+            boundary,
+            constraints,
+        );
+        let upload = match Upload::read(&mut multipart, dir).await {
+            Ok(upload) => upload,
+            Err(err) => {
+                return match err.downcast::<multer::Error>() {
+                    Ok(err @ multer::Error::StreamSizeExceeded { .. }) => {
+                        Ok(text(StatusCode::PAYLOAD_TOO_LARGE, err.to_string()))
+                    }
+                    Ok(err) => Ok(text(StatusCode::BAD_REQUEST, err.to_string())),
+                    Err(err) => Err(err),
+                };
+            }
+        };
+
+        let dir = dir.clone();
+        self.blocking(move |index| {
+            let value = |name: &str| upload.values.get(name).map(String::as_str);
+
+            if value(":action").is_some_and(|action| action != "file_upload") {
+                return Ok(text(StatusCode::BAD_REQUEST, "Unsupported action"));
+            }
+            let Some(Content {
+                filename: raw_filename,
+                file,
+                sha256,
+            }) = upload.content
+            else {
+                return Ok(text(StatusCode::BAD_REQUEST, "Missing `content` field"));
+            };
+            let raw_filename = raw_filename.as_str();
+
+            // Validate the filename, which must not traverse outside the directory.
+            let filename = value("name")
+                .and_then(|name| PackageName::from_str(name).ok())
+                .map_or_else(
+                    || DistFilename::try_from_normalized_filename(raw_filename),
+                    |name| DistFilename::try_from_filename(raw_filename, &name),
+                );
+            if filename.is_none() || raw_filename.contains(['/', '\\']) {
+                return Ok(text(
+                    StatusCode::BAD_REQUEST,
+                    format!("Invalid distribution filename: {raw_filename}"),
+                ));
+            }
+
+            // Validate the digest, if provided.
+            if let Some(expected) = value("sha256_digest") {
+                if !expected.eq_ignore_ascii_case(&sha256) {
+                    return Ok(text(
+                        StatusCode::BAD_REQUEST,
+                        format!(
+                            "Digest mismatch for {raw_filename}: expected `{expected}`, found `{sha256}`"
+                        ),
+                    ));
+                }
+            }
+
+            // Uploading an identical file is a no-op; uploading a different file with the same
+            // name is a conflict.
+            let target = dir.join(raw_filename);
+            match sha256_file(&target) {
+                Ok(existing) if existing == sha256 => {
+                    return Ok(text(StatusCode::OK, "File already exists"));
+                }
+                Ok(_) => {
+                    return Ok(text(
+                        StatusCode::CONFLICT,
+                        format!("File already exists: {raw_filename}"),
+                    ));
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+
+            if let Err(err) = file.persist_noclobber(&target) {
+                if err.error.kind() == std::io::ErrorKind::AlreadyExists {
+                    return Ok(text(
+                        StatusCode::CONFLICT,
+                        format!("File already exists: {raw_filename}"),
+                    ));
+                }
+                return Err(err.error.into());
+            }
+
+            writeln!(
+                index.printer.stderr(),
+                "Uploaded {}",
+                raw_filename.cyan()
+            )?;
+
+            // Re-scan the source, such that the upload is visible immediately.
+            index.refresh()?;
+
+            Ok(text(StatusCode::OK, "OK"))
+        })
+        .await
+    }
+
+    /// Return the most recent scan of the source.
+    ///
+    /// If the scan is older than [`REFRESH_INTERVAL`], the source is re-scanned in the background,
+    /// such that requests never wait on a scan (which, when serving the cache, walks the entire
+    /// cache).
+    async fn projects(self: &Arc<Self>) -> Result<Arc<Projects>> {
+        let projects = self.projects.lock().unwrap().clone();
+        let Some(projects) = projects else {
+            return self.blocking(Self::refresh).await;
+        };
+        if projects.scanned.elapsed() > REFRESH_INTERVAL
+            && !self.refreshing.swap(true, Ordering::AcqRel)
+        {
+            let index = Arc::clone(self);
+            tokio::task::spawn_blocking(move || {
+                if let Err(err) = index.refresh() {
+                    warn_user!(
+                        "Failed to scan `{}`: {err:#}",
+                        index.source.root().user_display()
+                    );
+                }
+                index.refreshing.store(false, Ordering::Release);
+            });
+        }
+        Ok(projects)
+    }
+
+    /// Re-scan the source, replacing the most recent scan.
+    fn refresh(&self) -> Result<Arc<Projects>> {
+        let projects = self.scan()?;
+        let files = projects
+            .values()
+            .flatten()
+            .map(|distribution| (distribution.filename.to_string(), distribution.clone()))
+            .collect();
+        let projects = Arc::new(Projects {
+            scanned: Instant::now(),
+            projects,
+            files,
+        });
+        *self.projects.lock().unwrap() = Some(Arc::clone(&projects));
+        Ok(projects)
+    }
+
+    /// Collect the distributions in the source, grouped by package name and sorted by filename.
+    fn scan(&self) -> Result<BTreeMap<PackageName, Vec<Distribution>>> {
+        let mut projects: BTreeMap<PackageName, BTreeMap<String, Distribution>> = BTreeMap::new();
+        let mut insert = |filename: DistFilename, location: Location| {
+            projects
+                .entry(filename.name().clone())
+                .or_default()
+                .entry(filename.to_string())
+                .or_insert(Distribution { filename, location });
+        };
+
+        match &self.source {
+            Source::Directory(dir) => {
+                for entry in fs_err::read_dir(dir)? {
+                    let entry = entry?;
+                    if !entry.file_type()?.is_file() {
+                        continue;
+                    }
+                    let Some(filename) = entry
+                        .file_name()
+                        .to_str()
+                        .and_then(DistFilename::try_from_normalized_filename)
+                    else {
+                        continue;
+                    };
+                    insert(filename, Location::File(entry.path()));
+                }
+            }
+            Source::Cache { cache, .. } => {
+                // Wheels built from source distributions are stored as-is, alongside the unpacked
+                // source trees, which we skip.
+                let sdists = cache.bucket(CacheBucket::SourceDistributions);
+                if sdists.is_dir() {
+                    for entry in WalkDir::new(&sdists)
+                        .sort_by_file_name()
+                        .into_iter()
+                        .filter_entry(|entry| entry.file_name() != "src")
+                    {
+                        let entry = entry?;
+                        if !entry.file_type().is_file() {
+                            continue;
+                        }
+                        let Some(filename) = entry
+                            .file_name()
+                            .to_str()
+                            .and_then(|filename| WheelFilename::from_str(filename).ok())
+                        else {
+                            continue;
+                        };
+                        insert(
+                            DistFilename::WheelFilename(filename),
+                            Location::File(entry.into_path()),
+                        );
+                    }
+                }
+
+                // Wheels downloaded from an index are stored unzipped.
+                let mut archives = match fs_err::read_dir(cache.bucket(CacheBucket::Archive)) {
+                    Ok(entries) => entries
+                        .map(|entry| entry.map(|entry| entry.path()))
+                        .collect::<Result<Vec<_>, _>>()?,
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+                    Err(err) => return Err(err.into()),
+                };
+                archives.sort();
+                for archive in archives {
+                    if let Some(filename) = self.archive_filename(&archive) {
+                        insert(
+                            DistFilename::WheelFilename(filename),
+                            Location::Archive(archive),
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(projects
+            .into_iter()
+            .map(|(name, distributions)| (name, distributions.into_values().collect()))
+            .collect())
+    }
+
+    /// Return the files and versions for a project, or `None` if the project doesn't exist.
+    fn project(
+        &self,
+        projects: &Projects,
+        name: &PackageName,
+    ) -> Result<Option<(Vec<File>, BTreeSet<Version>)>> {
+        let Some(distributions) = projects.projects.get(name) else {
+            return Ok(None);
+        };
+
+        let mut files = Vec::with_capacity(distributions.len());
+        let mut versions = BTreeSet::new();
+        for distribution in distributions {
+            // Skip any distributions that were removed since the last scan.
+            let Some(info) = self.info(distribution)? else {
+                continue;
+            };
+            let filename = distribution.filename.to_string();
+            files.push(File {
+                core_metadata: info.metadata.as_ref().map(|metadata| {
+                    CoreMetadata::Hashes(Hashes {
+                        sha256: Some(metadata.sha256.as_str().into()),
+                        ..Hashes::default()
+                    })
+                }),
+                hashes: Hashes {
+                    sha256: Some(info.sha256.as_str().into()),
+                    ..Hashes::default()
+                },
+                requires_python: info
+                    .metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.requires_python.clone())
+                    .map(Ok),
+                size: Some(info.size),
+                upload_time: Timestamp::try_from(info.modified).ok(),
+                url: format!("../../files/{filename}").into(),
+                filename: filename.into(),
+                yanked: None,
+            });
+            versions.insert(distribution.filename.version().clone());
+        }
+
+        Ok(Some((files, versions)))
+    }
+
+    /// Find the distribution with the given filename.
+    fn find(&self, projects: &Projects, filename: &str) -> Result<Option<Arc<FileInfo>>> {
+        let Some(distribution) = projects.files.get(filename) else {
+            return Ok(None);
+        };
+        self.info(distribution)
+    }
+
+    /// Compute the [`FileInfo`] for a distribution, re-archiving it if necessary.
+    ///
+    /// Returns `None` if the distribution no longer exists.
+    fn info(&self, distribution: &Distribution) -> Result<Option<Arc<FileInfo>>> {
+        let location = distribution.location.path();
+        let stat = match fs_err::metadata(location) {
+            Ok(stat) => stat,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let modified = stat.modified()?;
+
+        if let Some((size, timestamp, info)) = self.files.lock().unwrap().get(location) {
+            if *size == stat.len() && *timestamp == modified {
+                return Ok(Some(Arc::clone(info)));
+            }
+        }
+
+        let path = match (&distribution.location, &self.source) {
+            (Location::File(path), _) => path.clone(),
+            (Location::Archive(archive), Source::Cache { scratch, .. }) => {
+                let target = scratch.path().join(distribution.filename.to_string());
+                if !target.is_file() {
+                    debug!(
+                        "Re-archiving `{}` as {}",
+                        archive.user_display(),
+                        distribution.filename
+                    );
+                    zip_archive(archive, &target)?;
+                }
+                target
+            }
+            (Location::Archive(archive), Source::Directory(_)) => {
+                bail!("Unexpected archive: `{}`", archive.user_display())
+            }
+        };
+
+        let size = fs_err::metadata(&path)?.len();
+        let sha256 = sha256_file(&path)?;
+
+        let metadata = if let DistFilename::WheelFilename(filename) = &distribution.filename {
+            let mut archive = ZipArchive::new(fs_err::File::open(&path)?)?;
+            match uv_metadata::read_archive_metadata(filename, &mut archive) {
+                Ok(contents) => Some(WheelMetadata {
+                    sha256: sha256(&contents),
+                    requires_python: ResolutionMetadata::parse_metadata(&contents)
+                        .ok()
+                        .and_then(|metadata| metadata.requires_python),
+                    contents,
+                }),
+                Err(err) => {
+                    warn_user!(
+                        "Failed to read metadata from `{}`: {err}",
+                        path.user_display()
+                    );
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let info = Arc::new(FileInfo {
+            path,
+            size,
+            sha256,
+            modified,
+            metadata,
+        });
+        self.files.lock().unwrap().insert(
+            location.to_path_buf(),
+            (stat.len(), modified, Arc::clone(&info)),
+        );
+        Ok(Some(info))
+    }
+
+    /// Determine the filename of an unzipped wheel in the cache, memoizing the result.
+    fn archive_filename(&self, archive: &Path) -> Option<WheelFilename> {
+        if let Some(filename) = self.archives.lock().unwrap().get(archive) {
+            return filename.clone();
+        }
+        let filename = match wheel_filename(archive) {
+            Ok(filename) => Some(filename),
+            Err(err) => {
+                debug!(
+                    "Skipping cached archive `{}`: {err}",
+                    archive.user_display()
+                );
+                None
+            }
+        };
+        self.archives
+            .lock()
+            .unwrap()
+            .insert(archive.to_path_buf(), filename.clone());
+        filename
+    }
+}
+
+/// Determine the filename of the wheel from which an unzipped wheel was unzipped, based on the
+/// name of its `.dist-info` directory and the tags in its `WHEEL` file.
+fn wheel_filename(archive: &Path) -> Result<WheelFilename> {
+    let dist_info = fs_err::read_dir(archive)?
+        .filter_map(Result::ok)
+        .filter_map(|entry| entry.file_name().to_str().map(ToString::to_string))
+        .find(|name| name.ends_with(".dist-info"))
+        .context("Missing `.dist-info` directory")?;
+    let (name, version) = dist_info
+        .strip_suffix(".dist-info")
+        .and_then(|stem| stem.rsplit_once('-'))
+        .with_context(|| format!("Invalid `.dist-info` directory: `{dist_info}`"))?;
+
+    // Compress the tag set into a single tag, per PEP 425, which is only possible if the tag set is
+    // the Cartesian product of its components.
+    let wheel = fs_err::read_to_string(archive.join(&dist_info).join("WHEEL"))?;
+    let mut tags = FxHashSet::default();
+    let mut python_tags = Vec::new();
+    let mut abi_tags = Vec::new();
+    let mut platform_tags = Vec::new();
+    for tag in wheel
+        .lines()
+        .filter_map(|line| line.strip_prefix("Tag:"))
+        .map(str::trim)
+    {
+        let Some((python_tag, abi_tag, platform_tag)) = tag.splitn(3, '-').collect_tuple() else {
+            bail!("Invalid tag: `{tag}`");
+        };
+        for (component, components) in [
+            (python_tag, &mut python_tags),
+            (abi_tag, &mut abi_tags),
+            (platform_tag, &mut platform_tags),
+        ] {
+            if !components.contains(&component) {
+                components.push(component);
+            }
+        }
+        tags.insert(tag);
+    }
+    if tags.is_empty() {
+        bail!("Missing tags in `WHEEL` file");
+    }
+    if python_tags.len() * abi_tags.len() * platform_tags.len() != tags.len() {
+        bail!("Tags can't be compressed into a single wheel filename");
+    }
+
+    Ok(WheelFilename::from_str(&format!(
+        "{name}-{version}-{}-{}-{}.whl",
+        python_tags.join("."),
+        abi_tags.join("."),
+        platform_tags.join(".")
+    ))?)
+}
+
+/// Re-archive an unzipped wheel.
+///
+/// Entries are written in a stable order and without timestamps, such that the archive (and thus
+/// its hash) is reproducible.
+fn zip_archive(archive: &Path, target: &Path) -> Result<()> {
+    let parent = target.parent().context("Missing parent directory")?;
+    let temp = tempfile::NamedTempFile::new_in(parent)?;
+    let mut writer = ZipWriter::new(temp.as_file());
+    for entry in WalkDir::new(archive).sort_by_file_name() {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry.path().strip_prefix(archive)?;
+        let name = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .join("/");
+
+        // Preserve the executable bit, e.g., for scripts.
+        #[cfg(unix)]
+        let executable = {
+            use std::os::unix::fs::PermissionsExt;
+            entry.metadata()?.permissions().mode() & 0o111 != 0
+        };
+        #[cfg(not(unix))]
+        let executable = false;
+
+        let options = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Deflated)
+            .last_modified_time(zip::DateTime::default())
+            .unix_permissions(if executable { 0o755 } else { 0o644 });
+        writer.start_file(name, options)?;
+        std::io::copy(&mut fs_err::File::open(entry.path())?, &mut writer)?;
+    }
+    writer.finish()?;
+    temp.persist(target)?;
+    Ok(())
+}
+
+/// The fields of a request to the legacy upload API.
+#[derive(Default)]
+struct Upload {
+    /// The text fields, e.g., `name` and `sha256_digest`.
+    values: FxHashMap<String, String>,
+    /// The distribution, from the `content` field.
+    content: Option<Content>,
+}
+
+/// An uploaded distribution, streamed to a temporary file.
+struct Content {
+    /// The filename of the distribution, as provided by the client.
+    filename: String,
+    /// The temporary file containing the distribution.
+    file: TempPath,
+    /// The SHA-256 digest of the distribution.
+    sha256: String,
+}
+
+impl Upload {
+    /// Read the fields of a `multipart/form-data` request body.
+    ///
+    /// The distribution is streamed to a temporary file in the given directory, such that it can
+    /// be persisted without copying, and without buffering it in memory.
+    async fn read(multipart: &mut multer::Multipart<'_>, dir: &Path) -> Result<Self> {
+        let mut upload = Self::default();
+        while let Some(mut field) = multipart.next_field().await? {
+            let Some(name) = field.name().map(ToString::to_string) else {
+                continue;
+            };
+            if name == "content" {
+                let Some(filename) = field.file_name().map(ToString::to_string) else {
+                    continue;
+                };
+                let (file, path) = tempfile::NamedTempFile::new_in(dir)?.into_parts();
+                let mut file = tokio::fs::File::from_std(file);
+                let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+                while let Some(chunk) = field.chunk().await? {
+                    hasher.update(&chunk);
+                    file.write_all(&chunk).await?;
+                }
+                file.flush().await?;
+                upload.content = Some(Content {
+                    filename,
+                    file: path,
+                    sha256: HashDigest::from(hasher).digest.to_string(),
+                });
+            } else {
+                upload.values.insert(name, field.text().await?);
+            }
+        }
+        Ok(upload)
+    }
+}
+
+/// Compute the hex-encoded SHA-256 digest of the given bytes.
+fn sha256(data: &[u8]) -> String {
+    let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+    hasher.update(data);
+    HashDigest::from(hasher).digest.to_string()
+}
+
+/// Compute the hex-encoded SHA-256 digest of the file at the given path.
+fn sha256_file(path: &Path) -> std::io::Result<String> {
+    let mut reader = fs_err::File::open(path)?;
+    let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = reader.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(HashDigest::from(hasher).digest.to_string())
+}
+
+/// The format of a simple repository API response.
+#[derive(Debug, Copy, Clone)]
+enum Format {
+    Json,
+    Html(&'static str),
+}
+
+impl Format {
+    /// Select the response format based on the `Accept` header of a request, per PEP 691.
+    ///
+    /// Defaults to HTML, for clients that don't support content negotiation.
+    fn from_accept(accept: Option<&HeaderValue>) -> Self {
+        let mut best: Option<(f32, Self)> = None;
+        for media_range in accept
+            .and_then(|accept| accept.to_str().ok())
+            .unwrap_or_default()
+            .split(',')
+        {
+            let mut params = media_range.split(';');
+            let format = match params.next().unwrap_or_default().trim() {
+                "application/vnd.pypi.simple.v1+json"
+                | "application/vnd.pypi.simple.latest+json" => Self::Json,
+                "application/vnd.pypi.simple.v1+html"
+                | "application/vnd.pypi.simple.latest+html" => Self::Html(HTML_CONTENT_TYPE),
+                "text/html" | "*/*" => Self::Html("text/html"),
+                _ => continue,
+            };
+            let quality = params
+                .filter_map(|param| param.trim().strip_prefix("q="))
+                .find_map(|quality| quality.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            if quality > 0.0 && best.is_none_or(|(best, _)| quality > best) {
+                best = Some((quality, format));
+            }
+        }
+        best.map_or(Self::Html("text/html"), |(_, format)| format)
+    }
+}
+
+/// The `meta` key of a JSON response, per PEP 691.
+#[derive(Debug, Serialize)]
+struct Meta {
+    #[serde(rename = "api-version")]
+    api_version: &'static str,
+}
+
+impl Default for Meta {
+    fn default() -> Self {
+        Self {
+            api_version: API_VERSION,
+        }
+    }
+}
+
+/// The JSON response for the project list, per PEP 691.
+#[derive(Debug, Serialize)]
+struct IndexPage<'a> {
+    meta: Meta,
+    projects: Vec<ProjectEntry<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct ProjectEntry<'a> {
+    name: &'a PackageName,
+}
+
+/// The JSON response for a single project, per PEP 691 and PEP 700.
+#[derive(Debug, Serialize)]
+struct ProjectPage<'a> {
+    meta: Meta,
+    name: &'a PackageName,
+    #[serde(flatten)]
+    simple: SimpleJson,
+    versions: BTreeSet<Version>,
+}
+
+fn bytes(status: StatusCode, content_type: &'static str, body: impl Into<Bytes>) -> Response<Body> {
+    let mut response = Response::new(
+        Full::new(body.into())
+            .map_err(|never| match never {})
+            .boxed_unsync(),
+    );
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+    response
+}
+
+fn text(status: StatusCode, body: impl Into<String>) -> Response<Body> {
+    bytes(status, "text/plain; charset=utf-8", body.into())
+}
+
+fn html(content_type: &'static str, body: String) -> Response<Body> {
+    let mut response = bytes(StatusCode::OK, content_type, body);
+    response
+        .headers_mut()
+        .insert(VARY, HeaderValue::from_static("Accept"));
+    response
+}
+
+fn json(body: &impl Serialize) -> Result<Response<Body>> {
+    let mut response = bytes(StatusCode::OK, JSON_CONTENT_TYPE, serde_json::to_vec(body)?);
+    response
+        .headers_mut()
+        .insert(VARY, HeaderValue::from_static("Accept"));
+    Ok(response)
+}
+
+fn redirect(location: &str) -> Result<Response<Body>> {
+    let mut response = text(StatusCode::MOVED_PERMANENTLY, "Moved permanently");
+    response
+        .headers_mut()
+        .insert(LOCATION, HeaderValue::from_str(location)?);
+    Ok(response)
+}
@@ -8,10 +8,14 @@ use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
 
 pub(crate) use build_frontend::build_frontend;
+pub(crate) use cache_bundle::{cache_export, cache_import};
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
 pub(crate) use cache_prune::cache_prune;
+pub(crate) use cache_stats::cache_stats;
+pub(crate) use cache_verify::cache_verify;
 pub(crate) use help::help;
+pub(crate) use index_serve::index_serve;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
 pub(crate) use pip::freeze::pip_freeze;
@@ -22,6 +26,7 @@ pub(crate) use pip::sync::pip_sync;
 pub(crate) use pip::tree::pip_tree;
 pub(crate) use pip::uninstall::pip_uninstall;
 pub(crate) use project::add::add;
+pub(crate) use project::bundle::{bundle, bundle_run, is_bundle};
 pub(crate) use project::export::export;
 pub(crate) use project::init::{init, InitKind, InitProjectKind};
 pub(crate) use project::lock::lock;
@@ -36,11 +41,19 @@ pub(crate) use python::find::find_script as python_find_script;
 pub(crate) use python::install::install as python_install;
 pub(crate) use python::list::list as python_list;
 pub(crate) use python::pin::pin as python_pin;
+pub(crate) use python::shim::exec as python_shim_exec;
+pub(crate) use python::shim::list as python_shim_list;
+pub(crate) use python::shim::rebuild as python_shim_rebuild;
+pub(crate) use python::shim::shim_from_trampoline as python_shim_from_trampoline;
 pub(crate) use python::uninstall::uninstall as python_uninstall;
+pub(crate) use python::upgrade::upgrade as python_upgrade;
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
 pub(crate) use tool::dir::dir as tool_dir;
+pub(crate) use tool::doctor::doctor as tool_doctor;
+pub(crate) use tool::export::export as tool_export;
 pub(crate) use tool::install::install as tool_install;
+pub(crate) use tool::install::install_manifest as tool_install_manifest;
 pub(crate) use tool::list::list as tool_list;
 pub(crate) use tool::run::run as tool_run;
 pub(crate) use tool::run::ToolRunCommand;
@@ -62,11 +75,15 @@ use crate::printer::Printer;
 
 pub(crate) mod build_backend;
 mod build_frontend;
+mod cache_bundle;
 mod cache_clean;
 mod cache_dir;
 mod cache_prune;
+mod cache_stats;
+mod cache_verify;
 mod diagnostics;
 mod help;
+mod index_serve;
 pub(crate) mod pip;
 mod project;
 mod publish;
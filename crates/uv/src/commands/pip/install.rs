@@ -361,6 +361,7 @@ pub(crate) async fn pip_install(
         .cache(cache.clone())
         .index_locations(&index_locations)
         .index_strategy(index_strategy)
+        .preview(preview)
         .torch_backend(torch_backend.clone())
         .markers(interpreter.markers())
         .platform(interpreter.platform())
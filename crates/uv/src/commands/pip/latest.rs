@@ -6,6 +6,7 @@ use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{IndexCapabilities, IndexMetadataRef, IndexUrl};
 use uv_normalize::PackageName;
 use uv_platform_tags::Tags;
+use uv_pypi_types::ProjectStatus;
 use uv_resolver::{ExcludeNewer, PrereleaseMode, RequiresPython};
 use uv_warnings::warn_user_once;
 
@@ -148,4 +149,42 @@ impl LatestClient<'_> {
         }
         Ok(latest)
     }
+    /// Find the status of a package's project on an index, per PEP 792.
+    ///
+    /// Returns `None` if the package wasn't found, or if the index doesn't implement the Simple
+    /// Repository API.
+    pub(crate) async fn find_status(
+        &self,
+        package: &PackageName,
+        index: Option<&IndexUrl>,
+        download_concurrency: &Semaphore,
+    ) -> anyhow::Result<Option<ProjectStatus>, uv_client::Error> {
+        debug!("Fetching project status of: `{package}`");
+
+        let archives = match self
+            .client
+            .package_metadata(
+                package,
+                index.map(IndexMetadataRef::from),
+                self.capabilities,
+                download_concurrency,
+            )
+            .await
+        {
+            Ok(archives) => archives,
+            Err(err) => {
+                return match err.into_kind() {
+                    uv_client::ErrorKind::PackageNotFound(_) => Ok(None),
+                    uv_client::ErrorKind::NoIndex(_) => Ok(None),
+                    uv_client::ErrorKind::Offline(_) => Ok(None),
+                    kind => Err(kind.into()),
+                }
+            }
+        };
+
+        Ok(archives.into_iter().find_map(|(_, archive)| match archive {
+            MetadataFormat::Simple(archive) => Some(archive.status()),
+            MetadataFormat::Flat(_) => None,
+        }))
+    }
 }
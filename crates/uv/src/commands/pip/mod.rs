@@ -50,6 +50,7 @@ pub(crate) fn resolution_tags<'env>(
             interpreter.implementation_tuple(),
             python_platform.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (Some(python_platform), None) => Cow::Owned(Tags::from_env(
             &python_platform.platform(),
@@ -58,6 +59,7 @@ pub(crate) fn resolution_tags<'env>(
             interpreter.implementation_tuple(),
             python_platform.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (None, Some(python_version)) => Cow::Owned(Tags::from_env(
             interpreter.platform(),
@@ -66,6 +68,7 @@ pub(crate) fn resolution_tags<'env>(
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (None, None) => Cow::Borrowed(interpreter.tags()?),
     })
@@ -85,6 +88,7 @@ pub(crate) fn resolution_environment(
             interpreter.implementation_tuple(),
             python_platform.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (Some(python_platform), None) => Cow::Owned(Tags::from_env(
             &python_platform.platform(),
@@ -93,6 +97,7 @@ pub(crate) fn resolution_environment(
             interpreter.implementation_tuple(),
             python_platform.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (None, Some(python_version)) => Cow::Owned(Tags::from_env(
             interpreter.platform(),
@@ -101,6 +106,7 @@ pub(crate) fn resolution_environment(
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (None, None) => Cow::Borrowed(interpreter.tags()?),
     };
@@ -334,6 +334,7 @@ pub(crate) async fn add(
             let client = RegistryClientBuilder::try_from(client_builder)?
                 .index_locations(&settings.resolver.index_locations)
                 .index_strategy(settings.resolver.index_strategy)
+                .preview(preview)
                 .markers(target.interpreter().markers())
                 .platform(target.interpreter().platform())
                 .build();
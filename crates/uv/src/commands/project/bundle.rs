@@ -0,0 +1,1062 @@
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fmt::Write;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_cache_info::CacheInfo;
+use uv_cache_key::cache_digest;
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    Concurrency, DependencyGroups, EditableMode, ExtrasSpecification, HashCheckingMode,
+    InstallOptions, PreviewMode, TargetTriple,
+};
+use uv_dispatch::BuildDispatch;
+use uv_distribution::DistributionDatabase;
+use uv_distribution_filename::WheelFilename;
+use uv_distribution_types::{CachedDist, CachedRegistryDist, Dist, Index, Name, ResolvedDist};
+use uv_extract::hash::Hasher;
+use uv_fs::Simplified;
+use uv_installer::Preparer;
+use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
+use uv_pep440::Version;
+use uv_platform_tags::Os;
+use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests};
+use uv_python::downloads::{ManagedPythonDownload, PythonDownloadRequest};
+use uv_python::{
+    EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
+    PythonInstallationKey, PythonPreference, PythonRequest,
+};
+use uv_resolver::{FlatIndex, Installable};
+use uv_settings::PythonInstallMirrors;
+use uv_static::EnvVars;
+use uv_types::{BuildIsolation, HashStrategy};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceCache};
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::operations;
+use crate::commands::pip::{resolution_markers, resolution_tags};
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock::{LockMode, LockOperation};
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::sync::{read_resolution, store_credentials_from_target};
+use crate::commands::project::{PlatformState, ProjectError, ProjectInterpreter, UniversalState};
+use crate::commands::reporters::{PrepareReporter, PythonDownloadReporter};
+use crate::commands::run::run_to_completion;
+use crate::commands::{diagnostics, ExitStatus};
+use crate::printer::Printer;
+use crate::settings::{NetworkSettings, ResolverSettings};
+
+/// The magic number that marks the end of a bundle executable.
+///
+/// A bundle consists of the launcher (a uv executable), followed by a ZIP payload, followed by the
+/// length of the payload as a little-endian `u64`, followed by the magic number.
+const MAGIC_NUMBER: &[u8; 4] = b"UVBD";
+
+/// The size of the trailer that follows the payload.
+const TRAILER_SIZE: u64 = 12;
+
+/// The names of uv's own executables, which are never bundles.
+const UV_EXECUTABLES: [&str; 3] = ["uv", "uvx", "uvw"];
+
+/// The name of the manifest within the bundle payload.
+const MANIFEST_NAME: &str = "uv-bundle.json";
+
+/// The directory within the bundle payload that contains the unpacked wheels.
+const WHEELS_DIR: &str = "wheels";
+
+/// The metadata stored alongside the wheels in a bundle.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct BundleManifest {
+    /// The name of the bundled project.
+    name: PackageName,
+    /// The version of the bundled project.
+    version: Version,
+    /// The console script to invoke when the bundle is run.
+    entry_point: String,
+    /// The Python version for which the wheels were selected, e.g., `3.12`.
+    python: String,
+    /// The SHA-256 digest of the bundled wheels, used to key the environment in the cache.
+    digest: String,
+    /// The platforms included in the bundle, in order of preference.
+    platforms: Vec<BundlePlatform>,
+}
+
+/// The wheels for a single platform within a bundle.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct BundlePlatform {
+    /// The platform for which the wheels were selected, or `None` for the platform on which the
+    /// bundle was built.
+    #[serde(default)]
+    target: Option<TargetTriple>,
+    /// The key of the managed Python download to use on this platform, if recorded.
+    #[serde(default)]
+    python_download: Option<String>,
+    /// The wheels to install.
+    wheels: Vec<WheelFilename>,
+}
+
+impl BundleManifest {
+    /// Read the manifest from a bundle payload.
+    fn read(payload: &mut PayloadReader) -> Result<Self> {
+        let mut archive = zip::ZipArchive::new(payload)?;
+        let mut file = archive
+            .by_name(MANIFEST_NAME)
+            .context("Bundle payload is missing its manifest")?;
+        let mut contents = String::new();
+        file.read_to_string(&mut contents)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Return the Python request to use when running the bundle on the current platform.
+    ///
+    /// If a managed Python download was recorded for the current platform, it takes precedence
+    /// over the bare version request.
+    fn python_request(&self) -> PythonRequest {
+        let Ok(host) = PythonDownloadRequest::from_env() else {
+            return PythonRequest::parse(&self.python);
+        };
+        self.platforms
+            .iter()
+            .filter_map(|platform| platform.python_download.as_deref())
+            .filter_map(|key| PythonInstallationKey::from_str(key).ok())
+            .find(|key| host.satisfied_by_key(key))
+            .map(|key| PythonRequest::parse(&key.to_string()))
+            .unwrap_or_else(|| PythonRequest::parse(&self.python))
+    }
+}
+
+/// A reader over the payload appended to a bundle executable.
+struct PayloadReader {
+    file: fs_err::File,
+    start: u64,
+    len: u64,
+    position: u64,
+}
+
+impl PayloadReader {
+    /// Open the payload of a bundle executable, returning `None` if the file is not a bundle.
+    fn open(path: &Path) -> io::Result<Option<Self>> {
+        let mut file = fs_err::File::open(path)?;
+        let size = file.metadata()?.len();
+        let Some(end) = size.checked_sub(TRAILER_SIZE) else {
+            return Ok(None);
+        };
+
+        let mut trailer = [0u8; TRAILER_SIZE as usize];
+        file.seek(SeekFrom::Start(end))?;
+        file.read_exact(&mut trailer)?;
+        if &trailer[8..] != MAGIC_NUMBER {
+            return Ok(None);
+        }
+
+        let len = u64::from_le_bytes(trailer[..8].try_into().expect("slice is eight bytes"));
+        let Some(start) = end.checked_sub(len) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Bundle payload length exceeds the size of the executable",
+            ));
+        };
+        file.seek(SeekFrom::Start(start))?;
+
+        Ok(Some(Self {
+            file,
+            start,
+            len,
+            position: 0,
+        }))
+    }
+}
+
+impl Read for PayloadReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.len.saturating_sub(self.position);
+        if remaining == 0 {
+            return Ok(0);
+        }
+        let max =
+            usize::try_from(remaining).map_or(buf.len(), |remaining| remaining.min(buf.len()));
+        let read = self.file.read(&mut buf[..max])?;
+        self.position += read as u64;
+        Ok(read)
+    }
+}
+
+impl Seek for PayloadReader {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        let Some(position) = position else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Invalid seek to a negative position",
+            ));
+        };
+        self.file.seek(SeekFrom::Start(self.start + position))?;
+        self.position = position;
+        Ok(position)
+    }
+}
+
+/// Returns `true` if the file at the given path ends with the bundle magic number.
+fn has_magic_number(path: &Path) -> io::Result<bool> {
+    let mut file = fs_err::File::open(path)?;
+    let size = file.metadata()?.len();
+    if size < TRAILER_SIZE {
+        return Ok(false);
+    }
+    let mut magic = [0u8; MAGIC_NUMBER.len()];
+    file.seek(SeekFrom::Start(size - MAGIC_NUMBER.len() as u64))?;
+    file.read_exact(&mut magic)?;
+    Ok(&magic == MAGIC_NUMBER)
+}
+
+/// Returns `true` if the file name at the given path is that of one of uv's own executables.
+fn is_uv_executable(path: &Path) -> bool {
+    path.file_stem().is_some_and(|stem| {
+        UV_EXECUTABLES
+            .iter()
+            .any(|name| stem.eq_ignore_ascii_case(name))
+    })
+}
+
+/// Returns `true` if the executable at the given path was created by `uv bundle`.
+///
+/// This runs on every invocation of uv, so uv's own executables are recognized by name, and
+/// otherwise only the magic number at the end of the file is read. The payload is validated when
+/// the bundle is run.
+///
+/// A bundle that is invoked through the `UV` environment variable runs as uv, such that the bundled
+/// application can invoke uv in the same way as any other process spawned by uv.
+pub(crate) fn is_bundle(executable: &Path) -> bool {
+    if std::env::var_os(EnvVars::UV_NO_BUNDLE).is_some() {
+        return false;
+    }
+    if std::env::var_os(EnvVars::UV).is_some_and(|uv| Path::new(&uv) == executable) {
+        return false;
+    }
+    if is_uv_executable(executable) {
+        return false;
+    }
+    has_magic_number(executable).unwrap_or(false)
+}
+
+/// Bundle the project into a single-file executable.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn bundle(
+    project_dir: &Path,
+    output_file: Option<PathBuf>,
+    entry_point: Option<String>,
+    package: Option<PackageName>,
+    python_platforms: Vec<TargetTriple>,
+    python_download: bool,
+    launcher: Option<PathBuf>,
+    locked: bool,
+    frozen: bool,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    network_settings: NetworkSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user!("`uv bundle` is experimental and may change without warning. Pass `--preview` to disable this warning.");
+    }
+
+    // Identify the project.
+    let workspace_cache = WorkspaceCache::default();
+    let project = if let Some(package) = package.as_ref() {
+        VirtualProject::Project(
+            Workspace::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+                .await?
+                .with_current_project(package.clone())
+                .with_context(|| format!("Package `{package}` not found in workspace"))?,
+        )
+    } else {
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+            .await?
+    };
+    let VirtualProject::Project(project) = project else {
+        bail!("Virtual workspace roots cannot be bundled; use `--package` to select a workspace member");
+    };
+
+    // Determine the launcher, which must be a plain uv executable.
+    let launcher = match launcher {
+        Some(launcher) => launcher,
+        None => std::env::current_exe()?,
+    };
+    if has_magic_number(&launcher)? {
+        bail!(
+            "The launcher `{}` is itself a bundle; provide a uv executable with `--launcher`",
+            launcher.user_display()
+        );
+    }
+
+    // Find an interpreter for the project.
+    let interpreter = ProjectInterpreter::discover(
+        project.workspace(),
+        project_dir,
+        python.as_deref().map(PythonRequest::parse),
+        &network_settings,
+        python_preference,
+        python_downloads,
+        &install_mirrors,
+        false,
+        no_config,
+        Some(false),
+        cache,
+        printer,
+    )
+    .await?
+    .into_interpreter();
+
+    // Determine the lock mode.
+    let mode = if frozen {
+        LockMode::Frozen
+    } else if locked {
+        LockMode::Locked(&interpreter)
+    } else {
+        LockMode::Write(&interpreter)
+    };
+
+    // Initialize any shared state.
+    let state = UniversalState::default();
+
+    // Lock the project.
+    let lock = match LockOperation::new(
+        mode,
+        &settings,
+        &network_settings,
+        &state,
+        Box::new(DefaultResolveLogger),
+        concurrency,
+        cache,
+        printer,
+        preview,
+    )
+    .execute(LockTarget::Workspace(project.workspace()))
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let target = InstallTarget::Project {
+        workspace: project.workspace(),
+        name: project.project_name(),
+        lock: &lock,
+    };
+
+    // The wheels are selected for the Python minor version of the interpreter.
+    let python_version = format!(
+        "{}.{}{}",
+        interpreter.python_major(),
+        interpreter.python_minor(),
+        if interpreter.gil_disabled() { "t" } else { "" }
+    );
+
+    // If no platforms were requested, bundle for the current platform.
+    let python_platforms = if python_platforms.is_empty() {
+        vec![None]
+    } else {
+        python_platforms.into_iter().map(Some).collect()
+    };
+
+    // Build or download the wheels for each platform.
+    let state = PlatformState::default();
+    let mut platforms = Vec::with_capacity(python_platforms.len());
+    let mut wheels = BTreeMap::new();
+    for python_platform in python_platforms {
+        let prepared = match prepare_wheels(
+            target,
+            &interpreter,
+            python_platform.as_ref(),
+            &settings,
+            &network_settings,
+            &state,
+            concurrency,
+            cache,
+            printer,
+            preview,
+        )
+        .await
+        {
+            Ok(prepared) => prepared,
+            Err(ProjectError::Operation(err)) => {
+                return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                    .report(err)
+                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let python_download = if python_download {
+            let key = managed_python_download(
+                &python_version,
+                python_platform,
+                install_mirrors.python_downloads_json_url.as_deref(),
+            );
+            if key.is_none() {
+                warn_user!(
+                    "No managed Python download is available for Python {python_version} on {}",
+                    platform_display(python_platform)
+                );
+            }
+            key
+        } else {
+            None
+        };
+
+        let mut filenames = Vec::with_capacity(prepared.len());
+        for wheel in prepared {
+            filenames.push(wheel.filename().clone());
+            wheels.insert(wheel.filename().clone(), wheel.path().to_path_buf());
+        }
+        filenames.sort();
+
+        platforms.push(BundlePlatform {
+            target: python_platform,
+            python_download,
+            wheels: filenames,
+        });
+    }
+
+    // Find the wheel for the project itself, which declares the entry points.
+    let Some((project_wheel, project_path)) = wheels
+        .iter()
+        .find(|(filename, _)| filename.name == *project.project_name())
+    else {
+        bail!(
+            "The project `{}` is not installable; bundling requires a `[build-system]`",
+            project.project_name()
+        );
+    };
+    let version = project_wheel.version.clone();
+
+    // Determine the entry point.
+    let scripts = console_scripts(project_path)?;
+    let entry_point = match entry_point {
+        Some(entry_point) => {
+            if !scripts.contains(&entry_point) {
+                bail!(
+                    "The project `{}` does not declare a `{}` script (available: {})",
+                    project.project_name(),
+                    entry_point,
+                    scripts.iter().map(|script| format!("`{script}`")).join(", ")
+                );
+            }
+            entry_point
+        }
+        None => match scripts.as_slice() {
+            [script] => script.clone(),
+            [] => bail!(
+                "The project `{}` does not declare any scripts in `[project.scripts]`",
+                project.project_name()
+            ),
+            _ => bail!(
+                "The project `{}` declares multiple scripts; use `--entry-point` to select one of: {}",
+                project.project_name(),
+                scripts.iter().map(|script| format!("`{script}`")).join(", ")
+            ),
+        },
+    };
+
+    // Determine the output path, matching the launcher's executable extension.
+    let default_output_file = output_file.is_none();
+    let output_file = output_file.unwrap_or_else(|| {
+        let mut name = project.project_name().to_string();
+        if launcher
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("exe"))
+        {
+            name.push_str(".exe");
+        }
+        project.project_root().join("dist").join(name)
+    });
+    if is_uv_executable(&output_file) {
+        if default_output_file {
+            bail!(
+                "The bundle cannot be written to `{}`, as the name is reserved for uv's own executables; use `--output-file` to choose another name",
+                output_file.user_display()
+            );
+        }
+        bail!(
+            "The bundle cannot be written to `{}`, as the name is reserved for uv's own executables",
+            output_file.user_display()
+        );
+    }
+
+    let manifest = BundleManifest {
+        name: project.project_name().clone(),
+        version,
+        entry_point,
+        python: python_version,
+        digest: wheels_digest(&wheels)?,
+        platforms,
+    };
+    write_bundle(&launcher, &output_file, &manifest, &wheels)?;
+
+    writeln!(
+        printer.stderr(),
+        "Successfully bundled {} into {} ({} wheel{} for {} platform{})",
+        format!("{}=={}", manifest.name, manifest.version).bold(),
+        output_file.user_display().bold(),
+        wheels.len(),
+        if wheels.len() == 1 { "" } else { "s" },
+        manifest.platforms.len(),
+        if manifest.platforms.len() == 1 {
+            ""
+        } else {
+            "s"
+        },
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Build or download the wheels required to install the project on the given platform.
+async fn prepare_wheels(
+    target: InstallTarget<'_>,
+    interpreter: &Interpreter,
+    python_platform: Option<&TargetTriple>,
+    settings: &ResolverSettings,
+    network_settings: &NetworkSettings,
+    state: &PlatformState,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<Vec<CachedDist>, ProjectError> {
+    let ResolverSettings {
+        build_options,
+        config_setting,
+        dependency_metadata,
+        exclude_newer,
+        fork_strategy: _,
+        index_locations,
+        index_strategy,
+        keyring_provider,
+        link_mode,
+        no_build_isolation: _,
+        no_build_isolation_package: _,
+        prerelease: _,
+        resolution: _,
+        sources,
+        upgrade: _,
+    } = settings;
+
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .keyring(*keyring_provider)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+
+    // Determine the markers and tags to use for the target platform.
+    let marker_env = resolution_markers(None, python_platform, interpreter);
+    let tags = resolution_tags(None, python_platform, interpreter)?;
+    let tags = &*tags;
+
+    // Bundles include the project's production dependencies only.
+    let extras = ExtrasSpecification::default().with_defaults(DefaultExtras::default());
+    let dev = DependencyGroups::from_args(false, false, false, vec![], vec![], true, vec![], false)
+        .with_defaults(DefaultGroups::default());
+
+    // Read the lockfile, as when syncing; workspace members are always installed as non-editable.
+    let resolution = read_resolution(
+        target,
+        interpreter,
+        &marker_env,
+        tags,
+        &extras,
+        &dev,
+        EditableMode::NonEditable,
+        &InstallOptions::default(),
+        build_options,
+    )?;
+
+    // Add all authenticated sources to the cache.
+    for index in index_locations.allowed_indexes() {
+        if let Some(credentials) = index.credentials() {
+            let credentials = Arc::new(credentials);
+            uv_auth::store_credentials(index.raw_url(), credentials.clone());
+            if let Some(root_url) = index.root_url() {
+                uv_auth::store_credentials(&root_url, credentials.clone());
+            }
+        }
+    }
+
+    // Populate credentials from the target.
+    store_credentials_from_target(target);
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::try_from(client_builder)?
+        .cache(cache.clone())
+        .index_locations(index_locations)
+        .index_strategy(*index_strategy)
+        .preview(preview)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Read the build constraints from the lockfile.
+    let build_constraints = target.build_constraints();
+
+    // TODO(charlie): These are all default values. We should consider whether we want to make them
+    // optional on the downstream APIs.
+    let build_hasher = HashStrategy::default();
+
+    // Extract the hashes from the lockfile.
+    let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), cache);
+        let entries = client
+            .fetch_all(index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options)
+    };
+
+    // Create a build dispatch.
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        cache,
+        build_constraints,
+        interpreter,
+        index_locations,
+        &flat_index,
+        dependency_metadata,
+        state.clone().into_inner(),
+        *index_strategy,
+        config_setting,
+        BuildIsolation::Isolated,
+        *link_mode,
+        build_options,
+        &build_hasher,
+        *exclude_newer,
+        *sources,
+        WorkspaceCache::default(),
+        concurrency,
+        preview,
+    );
+
+    let distributions = resolution
+        .distributions()
+        .filter_map(|dist| match dist {
+            ResolvedDist::Installable { dist, .. } => Some(dist.clone()),
+            ResolvedDist::Installed { .. } => None,
+        })
+        .collect::<Vec<Arc<Dist>>>();
+
+    let preparer = Preparer::new(
+        cache,
+        tags,
+        &hasher,
+        build_options,
+        DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads),
+    )
+    .with_reporter(Arc::new(
+        PrepareReporter::from(printer).with_length(distributions.len() as u64),
+    ));
+    let wheels = preparer
+        .prepare(distributions, state.in_flight(), &resolution)
+        .await
+        .map_err(operations::Error::from)?;
+
+    // Source distributions are built for the current platform, which may not match the target.
+    if let Some(wheel) = wheels
+        .iter()
+        .find(|wheel| !wheel.filename().is_compatible(tags))
+    {
+        return Err(ProjectError::Anyhow(anyhow::anyhow!(
+            "`{}` has no wheel compatible with {}; source distributions can only be bundled for the current platform",
+            wheel.name(),
+            platform_display(python_platform.copied())
+        )));
+    }
+
+    Ok(wheels)
+}
+
+/// Find the managed Python download matching the given version on the given platform.
+fn managed_python_download(
+    python_version: &str,
+    python_platform: Option<TargetTriple>,
+    python_downloads_json_url: Option<&str>,
+) -> Option<String> {
+    let request = if let Some(python_platform) = python_platform {
+        let platform = python_platform.platform();
+        let (os, libc) = match platform.os() {
+            Os::Manylinux { .. } => ("linux", "gnu"),
+            Os::Musllinux { .. } => ("linux", "musl"),
+            Os::Windows => ("windows", "none"),
+            Os::Macos { .. } => ("macos", "none"),
+            _ => return None,
+        };
+        let request = PythonRequest::parse(&format!(
+            "cpython-{python_version}-{os}-{}-{libc}",
+            platform.arch()
+        ));
+        PythonDownloadRequest::from_request(&request)?
+    } else {
+        PythonDownloadRequest::from_request(&PythonRequest::parse(python_version))?
+            .fill()
+            .ok()?
+    };
+    ManagedPythonDownload::from_request(&request, python_downloads_json_url)
+        .ok()
+        .map(|download| download.key().to_string())
+}
+
+/// Return a user-facing description of a target platform.
+fn platform_display(python_platform: Option<TargetTriple>) -> String {
+    python_platform
+        .and_then(|python_platform| serde_json::to_value(python_platform).ok())
+        .and_then(|value| value.as_str().map(|name| format!("`{name}`")))
+        .unwrap_or_else(|| "the current platform".to_string())
+}
+
+/// Read the names of the console scripts declared by an unpacked wheel.
+fn console_scripts(wheel: &Path) -> Result<Vec<String>> {
+    for entry in fs_err::read_dir(wheel)? {
+        let path = entry?.path();
+        if !path
+            .extension()
+            .is_some_and(|extension| extension == "dist-info")
+        {
+            continue;
+        }
+
+        let Ok(contents) = fs_err::read_to_string(path.join("entry_points.txt")) else {
+            return Ok(Vec::new());
+        };
+
+        let mut scripts = Vec::new();
+        let mut in_section = false;
+        for line in contents.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            if let Some(section) = line
+                .strip_prefix('[')
+                .and_then(|line| line.strip_suffix(']'))
+            {
+                in_section = section.trim() == "console_scripts";
+            } else if in_section {
+                if let Some((name, _)) = line.split_once('=') {
+                    scripts.push(name.trim().to_string());
+                }
+            }
+        }
+        return Ok(scripts);
+    }
+    Ok(Vec::new())
+}
+
+/// Compute a digest over the contents of the unpacked wheels.
+fn wheels_digest(wheels: &BTreeMap<WheelFilename, PathBuf>) -> Result<String> {
+    let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+    for (filename, root) in wheels {
+        hasher.update(filename.to_string().as_bytes());
+        for entry in walkdir::WalkDir::new(root).sort_by_file_name() {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let relative = entry.path().strip_prefix(root)?;
+            hasher.update(relative.portable_display().to_string().as_bytes());
+            hasher.update(&fs_err::read(entry.path())?);
+        }
+    }
+    Ok(HashDigest::from(hasher).digest.to_string())
+}
+
+/// Write the bundle executable: the launcher, followed by the payload and trailer.
+fn write_bundle(
+    launcher: &Path,
+    output_file: &Path,
+    manifest: &BundleManifest,
+    wheels: &BTreeMap<WheelFilename, PathBuf>,
+) -> Result<()> {
+    let parent = output_file
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    fs_err::create_dir_all(parent)?;
+
+    // Write the payload to a temporary file, such that the offsets within the archive are relative
+    // to the start of the payload.
+    let mut payload = tempfile::tempfile_in(parent)?;
+    {
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+        let mut writer = zip::ZipWriter::new(&mut payload);
+
+        writer.start_file(MANIFEST_NAME, options)?;
+        serde_json::to_writer_pretty(&mut writer, manifest)?;
+
+        for (filename, root) in wheels {
+            for entry in walkdir::WalkDir::new(root).sort_by_file_name() {
+                let entry = entry?;
+                if !entry.file_type().is_file() {
+                    continue;
+                }
+                let relative = entry.path().strip_prefix(root)?;
+                let name = format!("{WHEELS_DIR}/{filename}/{}", relative.portable_display());
+
+                #[cfg(unix)]
+                let options = {
+                    use std::os::unix::fs::PermissionsExt;
+                    options.unix_permissions(entry.metadata()?.permissions().mode())
+                };
+
+                writer.start_file(name, options)?;
+                io::copy(&mut fs_err::File::open(entry.path())?, &mut writer)?;
+            }
+        }
+
+        writer.finish()?;
+    }
+    let len = payload.stream_position()?;
+    payload.rewind()?;
+
+    // Assemble the executable alongside the output, then move it into place.
+    let mut executable = tempfile::NamedTempFile::new_in(parent)?;
+    io::copy(&mut fs_err::File::open(launcher)?, &mut executable)?;
+    io::copy(&mut payload, &mut executable)?;
+    io::Write::write_all(&mut executable, &len.to_le_bytes())?;
+    io::Write::write_all(&mut executable, MAGIC_NUMBER)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs_err::set_permissions(executable.path(), std::fs::Permissions::from_mode(0o755))?;
+    }
+
+    executable
+        .persist(output_file)
+        .with_context(|| format!("Failed to write bundle to `{}`", output_file.user_display()))?;
+
+    Ok(())
+}
+
+/// Unpack the given wheels from a bundle payload into the target directory.
+///
+/// The payload is read directly from the executable; the wheels for other platforms are skipped.
+fn unpack_wheels(payload: PayloadReader, wheels: &[WheelFilename], target: &Path) -> Result<()> {
+    let prefixes = wheels
+        .iter()
+        .map(|wheel| Path::new(WHEELS_DIR).join(wheel.to_string()))
+        .collect::<Vec<_>>();
+
+    let mut archive = zip::ZipArchive::new(io::BufReader::new(payload))?;
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index)?;
+        let Some(name) = file.enclosed_name() else {
+            bail!(
+                "Bundle payload contains an unsafe file name: `{}`",
+                file.name()
+            );
+        };
+        if !prefixes.iter().any(|prefix| name.starts_with(prefix)) {
+            continue;
+        }
+
+        let path = target.join(name);
+        if file.is_dir() {
+            fs_err::create_dir_all(&path)?;
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        io::copy(&mut file, &mut fs_err::File::create(&path)?)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            if let Some(mode) = file.unix_mode() {
+                fs_err::set_permissions(&path, std::fs::Permissions::from_mode(mode & 0o777))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Run the application contained in a bundle.
+///
+/// On first run, the bundled wheels are unpacked into the cache and installed into a cached
+/// virtual environment. Subsequent runs invoke the entry point directly.
+pub(crate) async fn bundle_run(
+    bundle: &Path,
+    args: Vec<OsString>,
+    install_mirrors: PythonInstallMirrors,
+    network_settings: &NetworkSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let Some(mut payload) = PayloadReader::open(bundle)? else {
+        bail!(
+            "`{}` is not a bundle created by `uv bundle`",
+            bundle.user_display()
+        );
+    };
+    let manifest = BundleManifest::read(&mut payload)?;
+
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+    let reporter = PythonDownloadReporter::single(printer);
+
+    // Find (or download) a Python interpreter for the bundle.
+    let python_request = manifest.python_request();
+    debug!("Using Python request `{python_request}` for bundle");
+    let interpreter = PythonInstallation::find_or_download(
+        Some(&python_request),
+        EnvironmentPreference::OnlySystem,
+        python_preference,
+        python_downloads,
+        &client_builder,
+        cache,
+        Some(&reporter),
+        install_mirrors.python_install_mirror.as_deref(),
+        install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_downloads_json_url.as_deref(),
+    )
+    .await?
+    .into_interpreter();
+
+    // Select the first platform whose wheels are compatible with the interpreter.
+    let tags = interpreter.tags()?;
+    let Some(platform) = manifest.platforms.iter().find(|platform| {
+        platform
+            .wheels
+            .iter()
+            .all(|wheel| wheel.is_compatible(tags))
+    }) else {
+        bail!(
+            "The bundle for `{}` does not contain wheels compatible with Python {} on this platform",
+            manifest.name,
+            interpreter.python_version()
+        );
+    };
+
+    // Search for an existing environment in the cache, keyed by the interpreter and the bundle.
+    let interpreter_hash = cache_digest(&interpreter.sys_executable());
+    let bundle_hash = format!("{}-{}", manifest.name, cache_digest(&manifest.digest));
+    let cache_entry = cache.entry(CacheBucket::Environments, &interpreter_hash, &bundle_hash);
+
+    // Avoid unpacking the bundle concurrently from multiple invocations.
+    let _lock = cache
+        .entry(
+            CacheBucket::Environments,
+            &interpreter_hash,
+            format!("{bundle_hash}.lock"),
+        )
+        .lock()
+        .await?;
+    let environment = if let Some(environment) = cache
+        .resolve_link(cache_entry.path())
+        .ok()
+        .and_then(|root| PythonEnvironment::from_root(root, cache).ok())
+    {
+        environment
+    } else {
+        writeln!(
+            printer.stderr(),
+            "Unpacking {} into the cache",
+            format!("{}=={}", manifest.name, manifest.version).bold()
+        )?;
+
+        // Unpack the wheels for the selected platform into the cache.
+        let wheels_dir = tempfile::tempdir_in(cache.root())?;
+        unpack_wheels(payload, &platform.wheels, wheels_dir.path())?;
+
+        // Create the environment, then install the wheels for the selected platform.
+        let temp_dir = cache.venv_dir()?;
+        let venv = uv_virtualenv::create_venv(
+            temp_dir.path(),
+            interpreter,
+            uv_virtualenv::Prompt::None,
+            false,
+            false,
+            true,
+            false,
+        )?;
+
+        let wheels = platform
+            .wheels
+            .iter()
+            .map(|filename| {
+                CachedDist::Registry(CachedRegistryDist {
+                    filename: filename.clone(),
+                    path: wheels_dir
+                        .path()
+                        .join(WHEELS_DIR)
+                        .join(filename.to_string())
+                        .into_boxed_path(),
+                    hashes: HashDigests::empty(),
+                    cache_info: CacheInfo::default(),
+                })
+            })
+            .collect::<Vec<_>>();
+        uv_installer::Installer::new(&venv)
+            .with_cache(cache)
+            .with_installer_metadata(installer_metadata)
+            .install_blocking(wheels)?;
+
+        // Move the environment to its content-addressed location.
+        let id = cache
+            .persist(temp_dir.into_path(), cache_entry.path())
+            .await?;
+        PythonEnvironment::from_root(cache.archive(&id), cache)?
+    };
+
+    // Invoke the entry point, propagating its exit status.
+    let executable = environment.scripts().join(format!(
+        "{}{}",
+        manifest.entry_point,
+        std::env::consts::EXE_SUFFIX
+    ));
+    debug!(
+        "Running bundled entry point: `{}`",
+        executable.user_display()
+    );
+    let mut command = tokio::process::Command::new(&executable);
+    command.args(args);
+    let handle = command
+        .spawn()
+        .with_context(|| format!("Failed to spawn: `{}`", executable.user_display()))?;
+
+    run_to_completion(handle).await
+}
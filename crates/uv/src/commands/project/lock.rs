@@ -2,9 +2,10 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use anyhow::Context;
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 use tracing::debug;
@@ -20,16 +21,21 @@ use uv_distribution_types::{
     DependencyMetadata, HashGeneration, Index, IndexLocations, NameRequirementSpecification,
     Requirement, UnresolvedRequirementSpecification,
 };
+use uv_fs::Simplified;
 use uv_git::ResolvedRepositoryReference;
 use uv_normalize::{GroupName, PackageName};
 use uv_pep440::Version;
+use uv_pep508::MarkerTree;
 use uv_pypi_types::{Conflicts, SupportedEnvironments};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
-use uv_requirements::upgrade::{read_lock_requirements, LockedRequirements};
+use uv_requirements::upgrade::{
+    read_lock_requirements, read_pylock_requirements, LockedRequirements,
+};
 use uv_requirements::ExtrasResolver;
 use uv_resolver::{
-    FlatIndex, InMemoryIndex, Lock, Options, OptionsBuilder, PythonRequirement, RequiresPython,
-    ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
+    FlatIndex, InMemoryIndex, Lock, Options, OptionsBuilder, PylockToml, PythonRequirement,
+    RequiresPython, ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
+    WheelCoverage,
 };
 use uv_scripts::{Pep723ItemRef, Pep723Script};
 use uv_settings::PythonInstallMirrors;
@@ -80,6 +86,9 @@ pub(crate) async fn lock(
     locked: bool,
     frozen: bool,
     dry_run: DryRun,
+    from_pylock: Option<PathBuf>,
+    wheel_coverage: bool,
+    require_wheel_coverage: bool,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettings,
@@ -120,6 +129,24 @@ pub(crate) async fn lock(
         None => None,
     };
 
+    // If requested, read the pinned versions to import from a `pylock.toml` file.
+    let pylock = if let Some(from_pylock) = from_pylock.as_ref() {
+        if preview.is_disabled() {
+            warn_user!("The `--from-pylock` option is experimental and may change without warning. Pass `--preview` to disable this warning.");
+        }
+
+        let content = fs_err::tokio::read_to_string(from_pylock).await?;
+        let pylock = toml::from_str::<PylockToml>(&content).with_context(|| {
+            format!(
+                "Not a valid pylock.toml file: {}",
+                from_pylock.user_display()
+            )
+        })?;
+        Some(pylock)
+    } else {
+        None
+    };
+
     // Find the project requirements.
     let workspace_cache = WorkspaceCache::default();
     let workspace;
@@ -195,11 +222,42 @@ pub(crate) async fn lock(
         printer,
         preview,
     )
+    .with_pylock(pylock.as_ref())
+    .with_required_wheel_coverage(require_wheel_coverage)
     .execute(target)
     .await
     {
         Ok(lock) => {
-            if dry_run.enabled() {
+            if let (Some(pylock), Some(from_pylock)) = (pylock.as_ref(), from_pylock.as_ref()) {
+                // When importing a `pylock.toml`, report on the pinned versions, rather than the
+                // changes relative to the existing lockfile.
+                let mut kept = 0usize;
+                let mut resolved = 0usize;
+                for event in ImportEvent::detect_changes(pylock, lock.lock(), dry_run) {
+                    match event {
+                        ImportEvent::Keep(..) => kept += 1,
+                        ImportEvent::Resolve(..) => resolved += 1,
+                        ImportEvent::Add(..) | ImportEvent::Remove(..) => {}
+                    }
+                    writeln!(printer.stderr(), "{event}")?;
+                }
+                let s = if kept + resolved == 1 { "" } else { "s" };
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    format!(
+                        "{} {} pinned package{s} from `{}` ({kept} kept, {resolved} re-resolved)",
+                        if dry_run.enabled() {
+                            "Would import"
+                        } else {
+                            "Imported"
+                        },
+                        kept + resolved,
+                        from_pylock.user_display(),
+                    )
+                    .bold()
+                )?;
+            } else if dry_run.enabled() {
                 // In `--dry-run` mode, show all changes.
                 let mut changed = false;
                 if let LockResult::Changed(previous, lock) = &lock {
@@ -223,6 +281,10 @@ pub(crate) async fn lock(
                 }
             }
 
+            if wheel_coverage {
+                report_wheel_coverage(lock.lock(), printer)?;
+            }
+
             Ok(ExitStatus::Success)
         }
         Err(ProjectError::Operation(err)) => {
@@ -250,6 +312,8 @@ pub(super) enum LockMode<'env> {
 pub(super) struct LockOperation<'env> {
     mode: LockMode<'env>,
     constraints: Vec<NameRequirementSpecification>,
+    pylock: Option<&'env PylockToml>,
+    require_wheel_coverage: bool,
     settings: &'env ResolverSettings,
     network_settings: &'env NetworkSettings,
     state: &'env UniversalState,
@@ -276,6 +340,8 @@ impl<'env> LockOperation<'env> {
         Self {
             mode,
             constraints: vec![],
+            pylock: None,
+            require_wheel_coverage: false,
             settings,
             network_settings,
             state,
@@ -297,6 +363,21 @@ impl<'env> LockOperation<'env> {
         self
     }
 
+    /// Set the `pylock.toml` file whose pinned versions should be imported by the
+    /// [`LockOperation`], in lieu of those in the existing lockfile.
+    #[must_use]
+    pub(super) fn with_pylock(mut self, pylock: Option<&'env PylockToml>) -> Self {
+        self.pylock = pylock;
+        self
+    }
+
+    /// Require that every locked package has a wheel for each of the declared environments.
+    #[must_use]
+    pub(super) fn with_required_wheel_coverage(mut self, require_wheel_coverage: bool) -> Self {
+        self.require_wheel_coverage = require_wheel_coverage;
+        self
+    }
+
     /// Perform a [`LockOperation`].
     pub(super) async fn execute(self, target: LockTarget<'_>) -> Result<LockResult, ProjectError> {
         match self.mode {
@@ -306,6 +387,9 @@ impl<'env> LockOperation<'env> {
                     .read()
                     .await?
                     .ok_or_else(|| ProjectError::MissingLockfile)?;
+                if self.require_wheel_coverage {
+                    check_wheel_coverage(&existing)?;
+                }
                 Ok(LockResult::Unchanged(existing))
             }
             LockMode::Locked(interpreter) => {
@@ -320,6 +404,7 @@ impl<'env> LockOperation<'env> {
                     target,
                     interpreter,
                     Some(existing),
+                    self.pylock,
                     self.constraints,
                     self.settings,
                     self.network_settings,
@@ -337,6 +422,10 @@ impl<'env> LockOperation<'env> {
                     return Err(ProjectError::LockMismatch(Box::new(result.into_lock())));
                 }
 
+                if self.require_wheel_coverage {
+                    check_wheel_coverage(result.lock())?;
+                }
+
                 Ok(result)
             }
             LockMode::Write(interpreter) | LockMode::DryRun(interpreter) => {
@@ -358,6 +447,7 @@ impl<'env> LockOperation<'env> {
                     target,
                     interpreter,
                     existing,
+                    self.pylock,
                     self.constraints,
                     self.settings,
                     self.network_settings,
@@ -370,6 +460,12 @@ impl<'env> LockOperation<'env> {
                 )
                 .await?;
 
+                // If requested, ensure that the lockfile doesn't fall back to any source
+                // distributions, prior to writing it to disk.
+                if self.require_wheel_coverage {
+                    check_wheel_coverage(result.lock())?;
+                }
+
                 // If the lockfile changed, write it to disk.
                 if !matches!(self.mode, LockMode::DryRun(_)) {
                     if let LockResult::Changed(_, lock) = &result {
@@ -383,11 +479,122 @@ impl<'env> LockOperation<'env> {
     }
 }
 
+/// Report the wheel coverage of each locked package across the declared environments.
+fn report_wheel_coverage(lock: &Lock, printer: Printer) -> anyhow::Result<()> {
+    let coverage = WheelCoverage::from_lock(lock);
+    if coverage.environments().is_empty() {
+        warn_user!(
+            "No environments are declared in `tool.uv.environments` or `tool.uv.required-environments`; skipping wheel coverage report"
+        );
+        return Ok(());
+    }
+
+    let display = |environment: &MarkerTree| {
+        environment
+            .try_to_string()
+            .unwrap_or_else(|| "*".to_string())
+    };
+
+    for package in coverage.packages() {
+        if let Some(version) = package.version() {
+            writeln!(printer.stdout(), "{}=={version}", package.name().bold())?;
+        } else {
+            writeln!(printer.stdout(), "{}", package.name().bold())?;
+        }
+        for environment in package.wheel() {
+            writeln!(
+                printer.stdout(),
+                "  {} {}",
+                "wheel:".green(),
+                display(environment)
+            )?;
+        }
+        for environment in package.sdist() {
+            writeln!(
+                printer.stdout(),
+                "  {} {}",
+                "sdist:".yellow(),
+                display(environment)
+            )?;
+        }
+        for environment in package.missing() {
+            writeln!(
+                printer.stdout(),
+                "  {} {}",
+                "none:".red(),
+                display(environment)
+            )?;
+        }
+    }
+
+    let complete = coverage
+        .packages()
+        .iter()
+        .filter(|package| !package.is_incomplete())
+        .count();
+    let total = coverage.packages().len();
+    let environments = coverage.environments().len();
+    let s = if total == 1 { "" } else { "s" };
+    let es = if environments == 1 { "" } else { "s" };
+    let have = if complete == 1 { "has" } else { "have" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "{complete} of {total} package{s} {have} wheels for all {environments} declared environment{es}"
+        )
+        .bold()
+    )?;
+
+    Ok(())
+}
+
+/// Return an error if any locked package lacks a wheel for any of the declared environments, or if
+/// no environments are declared.
+fn check_wheel_coverage(lock: &Lock) -> Result<(), ProjectError> {
+    let coverage = WheelCoverage::from_lock(lock);
+    if coverage.environments().is_empty() {
+        return Err(ProjectError::MissingWheelCoverageEnvironments);
+    }
+    let incomplete = coverage
+        .packages()
+        .iter()
+        .filter(|package| package.is_incomplete())
+        .map(|package| {
+            let environments = package
+                .sdist()
+                .iter()
+                .chain(package.missing())
+                .map(|environment| {
+                    format!(
+                        "`{}`",
+                        environment
+                            .try_to_string()
+                            .unwrap_or_else(|| "*".to_string())
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            if let Some(version) = package.version() {
+                format!("`{}=={version}` ({environments})", package.name())
+            } else {
+                format!("`{}` ({environments})", package.name())
+            }
+        })
+        .collect::<Vec<_>>();
+    if incomplete.is_empty() {
+        Ok(())
+    } else {
+        Err(ProjectError::MissingWheelCoverage(incomplete.join(", ")))
+    }
+}
+
 /// Lock the project requirements into a lockfile.
 async fn do_lock(
     target: LockTarget<'_>,
     interpreter: &Interpreter,
     existing_lock: Option<Lock>,
+    pylock: Option<&PylockToml>,
     external: Vec<NameRequirementSpecification>,
     settings: &ResolverSettings,
     network_settings: &NetworkSettings,
@@ -601,6 +808,7 @@ async fn do_lock(
         .cache(cache.clone())
         .index_locations(index_locations)
         .index_strategy(*index_strategy)
+        .preview(preview)
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -672,41 +880,48 @@ async fn do_lock(
 
     // If any of the resolution-determining settings changed, invalidate the lock.
     let existing_lock = if let Some(existing_lock) = existing_lock {
-        match ValidatedLock::validate(
-            existing_lock,
-            target.install_path(),
-            packages,
-            &members,
-            &requirements,
-            &dependency_groups,
-            &constraints,
-            &overrides,
-            &build_constraints,
-            &conflicts,
-            environments,
-            required_environments,
-            dependency_metadata,
-            interpreter,
-            &requires_python,
-            index_locations,
-            upgrade,
-            &options,
-            &hasher,
-            state.index(),
-            &database,
-            printer,
-        )
-        .await
-        {
-            Ok(result) => Some(result),
-            Err(ProjectError::Lock(err)) if err.is_resolution() => {
-                // Resolver errors are not recoverable, as such errors can leave the resolver in a
-                // broken state. Specifically, tasks that fail with an error can be left as pending.
-                return Err(ProjectError::Lock(err));
-            }
-            Err(err) => {
-                warn_user!("Failed to validate existing lockfile: {err}");
-                None
+        if pylock.is_some() {
+            // If we're importing a `pylock.toml`, the existing lockfile is only retained to report
+            // on changes.
+            debug!("Ignoring existing lockfile due to `--from-pylock`");
+            Some(ValidatedLock::Unusable(existing_lock))
+        } else {
+            match ValidatedLock::validate(
+                existing_lock,
+                target.install_path(),
+                packages,
+                &members,
+                &requirements,
+                &dependency_groups,
+                &constraints,
+                &overrides,
+                &build_constraints,
+                &conflicts,
+                environments,
+                required_environments,
+                dependency_metadata,
+                interpreter,
+                &requires_python,
+                index_locations,
+                upgrade,
+                &options,
+                &hasher,
+                state.index(),
+                &database,
+                printer,
+            )
+            .await
+            {
+                Ok(result) => Some(result),
+                Err(ProjectError::Lock(err)) if err.is_resolution() => {
+                    // Resolver errors are not recoverable, as such errors can leave the resolver in a
+                    // broken state. Specifically, tasks that fail with an error can be left as pending.
+                    return Err(ProjectError::Lock(err));
+                }
+                Err(err) => {
+                    warn_user!("Failed to validate existing lockfile: {err}");
+                    None
+                }
             }
         }
     } else {
@@ -733,11 +948,16 @@ async fn do_lock(
                 ValidatedLock::Unusable(_) => None,
             });
 
-            // If an existing lockfile exists, build up a set of preferences.
-            let LockedRequirements { preferences, git } = versions_lock
-                .map(|lock| read_lock_requirements(lock, target.install_path(), upgrade))
-                .transpose()?
-                .unwrap_or_default();
+            // If a `pylock.toml` is being imported, use its pinned versions as preferences.
+            // Otherwise, if an existing lockfile exists, build up a set of preferences.
+            let LockedRequirements { preferences, git } = if let Some(pylock) = pylock {
+                read_pylock_requirements(pylock, upgrade)?
+            } else {
+                versions_lock
+                    .map(|lock| read_lock_requirements(lock, target.install_path(), upgrade))
+                    .transpose()?
+                    .unwrap_or_default()
+            };
 
             // Populate the Git resolver.
             for ResolvedRepositoryReference { reference, sha } in git {
@@ -1397,3 +1617,163 @@ impl std::fmt::Display for LockEvent<'_> {
         }
     }
 }
+
+/// The outcome of importing a pinned package from a `pylock.toml` file.
+#[derive(Debug, Clone)]
+pub(crate) enum ImportEvent<'lock> {
+    /// The pinned versions were retained in the lockfile.
+    Keep(DryRun, PackageName, BTreeSet<Option<&'lock Version>>),
+    /// The pinned versions were replaced during resolution.
+    Resolve(
+        DryRun,
+        PackageName,
+        BTreeSet<Option<&'lock Version>>,
+        BTreeSet<Option<&'lock Version>>,
+    ),
+    /// The package was not pinned, but was added during resolution.
+    Add(DryRun, PackageName, BTreeSet<Option<&'lock Version>>),
+    /// The package was pinned, but is no longer required.
+    Remove(DryRun, PackageName, BTreeSet<Option<&'lock Version>>),
+}
+
+impl<'lock> ImportEvent<'lock> {
+    /// Compare the pinned versions in a `pylock.toml` against the lockfile that was derived from it.
+    pub(crate) fn detect_changes(
+        pylock: &'lock PylockToml,
+        lock: &'lock Lock,
+        dry_run: DryRun,
+    ) -> impl Iterator<Item = Self> {
+        // Identify the package-versions in the `pylock.toml`.
+        let mut pinned_packages: FxHashMap<&PackageName, BTreeSet<Option<&Version>>> =
+            pylock.packages.iter().fold(
+                FxHashMap::with_capacity_and_hasher(pylock.packages.len(), FxBuildHasher),
+                |mut acc, package| {
+                    acc.entry(&package.name)
+                        .or_default()
+                        .insert(package.version.as_ref());
+                    acc
+                },
+            );
+
+        // Identify the package-versions in the lockfile.
+        let mut locked_packages: FxHashMap<&PackageName, BTreeSet<Option<&Version>>> =
+            lock.packages().iter().fold(
+                FxHashMap::with_capacity_and_hasher(lock.packages().len(), FxBuildHasher),
+                |mut acc, package| {
+                    acc.entry(package.name())
+                        .or_default()
+                        .insert(package.version());
+                    acc
+                },
+            );
+
+        // Omit the workspace members, which are re-resolved from source regardless.
+        let root = lock.root().map(uv_resolver::Package::name);
+
+        let names = pinned_packages
+            .keys()
+            .chain(locked_packages.keys())
+            .map(|name| (*name).clone())
+            .collect::<BTreeSet<_>>();
+
+        names.into_iter().filter_map(move |name| {
+            let pinned_versions = pinned_packages.remove(&name);
+            let locked_versions = locked_packages.remove(&name);
+            if root == Some(&name) || lock.members().contains(&name) {
+                return None;
+            }
+            match (pinned_versions, locked_versions) {
+                (Some(pinned_versions), Some(locked_versions)) => {
+                    // A pin without a version (e.g., a local directory) is retained as long as the
+                    // package is still present.
+                    if pinned_versions
+                        .iter()
+                        .flatten()
+                        .all(|version| locked_versions.contains(&Some(*version)))
+                    {
+                        Some(Self::Keep(dry_run, name, locked_versions))
+                    } else {
+                        Some(Self::Resolve(
+                            dry_run,
+                            name,
+                            pinned_versions,
+                            locked_versions,
+                        ))
+                    }
+                }
+                (Some(pinned_versions), None) => Some(Self::Remove(dry_run, name, pinned_versions)),
+                (None, Some(locked_versions)) => Some(Self::Add(dry_run, name, locked_versions)),
+                (None, None) => {
+                    unreachable!("The key `{name}` should exist in at least one of the maps");
+                }
+            }
+        })
+    }
+}
+
+impl std::fmt::Display for ImportEvent<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        /// Format a set of versions for inclusion in the import report.
+        fn format_versions(versions: &BTreeSet<Option<&Version>>) -> String {
+            versions
+                .iter()
+                .map(|version| {
+                    version
+                        .map(|version| format!("v{version}"))
+                        .unwrap_or_else(|| "(dynamic)".to_string())
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+
+        match self {
+            Self::Keep(dry_run, name, versions) => {
+                write!(
+                    f,
+                    "{} {name} {}",
+                    if dry_run.enabled() { "Keep" } else { "Kept" }.bold(),
+                    format_versions(versions)
+                )
+            }
+            Self::Resolve(dry_run, name, pinned_versions, locked_versions) => {
+                write!(
+                    f,
+                    "{} {name} {} -> {}",
+                    if dry_run.enabled() {
+                        "Re-resolve"
+                    } else {
+                        "Re-resolved"
+                    }
+                    .yellow()
+                    .bold(),
+                    format_versions(pinned_versions),
+                    format_versions(locked_versions)
+                )
+            }
+            Self::Add(dry_run, name, versions) => {
+                write!(
+                    f,
+                    "{} {name} {}",
+                    if dry_run.enabled() { "Add" } else { "Added" }
+                        .green()
+                        .bold(),
+                    format_versions(versions)
+                )
+            }
+            Self::Remove(dry_run, name, versions) => {
+                write!(
+                    f,
+                    "{} {name} {}",
+                    if dry_run.enabled() {
+                        "Remove"
+                    } else {
+                        "Removed"
+                    }
+                    .red()
+                    .bold(),
+                    format_versions(versions)
+                )
+            }
+        }
+    }
+}
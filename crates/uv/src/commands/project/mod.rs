@@ -35,7 +35,7 @@ use uv_python::{
 use uv_requirements::upgrade::{read_lock_requirements, LockedRequirements};
 use uv_requirements::{NamedRequirementsResolver, RequirementsSpecification};
 use uv_resolver::{
-    FlatIndex, Lock, OptionsBuilder, Preference, PythonRequirement, RequiresPython,
+    FlatIndex, Lock, OptionsBuilder, Preference, PylockToml, PythonRequirement, RequiresPython,
     ResolverEnvironment, ResolverOutput,
 };
 use uv_scripts::Pep723ItemRef;
@@ -57,6 +57,7 @@ use crate::settings::{
 };
 
 pub(crate) mod add;
+pub(crate) mod bundle;
 pub(crate) mod environment;
 pub(crate) mod export;
 pub(crate) mod init;
@@ -93,6 +94,12 @@ pub(crate) enum ProjectError {
     #[error("The current Python platform is not compatible with the lockfile's supported environments: {0}")]
     LockedPlatformIncompatibility(String),
 
+    #[error("`--require-wheel-coverage` was provided, but the following packages lack a wheel for one or more of the declared environments: {0}")]
+    MissingWheelCoverage(String),
+
+    #[error("`--require-wheel-coverage` was provided, but no environments are declared in `tool.uv.environments` or `tool.uv.required-environments`")]
+    MissingWheelCoverageEnvironments,
+
     #[error(transparent)]
     Conflict(#[from] ConflictError),
 
@@ -1617,6 +1624,7 @@ pub(crate) async fn resolve_names(
         .cache(cache.clone())
         .index_locations(index_locations)
         .index_strategy(*index_strategy)
+        .preview(preview)
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -1787,6 +1795,7 @@ pub(crate) async fn resolve_environment(
         .cache(cache.clone())
         .index_locations(index_locations)
         .index_strategy(*index_strategy)
+        .preview(preview)
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -1968,6 +1977,7 @@ pub(crate) async fn sync_environment(
         .cache(cache.clone())
         .index_locations(index_locations)
         .index_strategy(index_strategy)
+        .preview(preview)
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -2059,6 +2069,9 @@ pub(crate) struct EnvironmentUpdate {
     pub(crate) environment: PythonEnvironment,
     /// The [`Changelog`] of changes made to the environment.
     pub(crate) changelog: Changelog,
+    /// The resolution used to update the environment, as a [`PylockToml`], if the environment
+    /// was re-resolved.
+    pub(crate) lock: Option<PylockToml>,
 }
 
 impl EnvironmentUpdate {
@@ -2159,6 +2172,7 @@ pub(crate) async fn update_environment(
                 return Ok(EnvironmentUpdate {
                     environment: venv,
                     changelog: Changelog::default(),
+                    lock: None,
                 });
             }
             SatisfiesResult::Unsatisfied(requirement) => {
@@ -2183,6 +2197,7 @@ pub(crate) async fn update_environment(
         .cache(cache.clone())
         .index_locations(index_locations)
         .index_strategy(*index_strategy)
+        .preview(preview)
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -2279,10 +2294,20 @@ pub(crate) async fn update_environment(
     )
     .await
     {
-        Ok(resolution) => Resolution::from(resolution),
+        Ok(resolution) => resolution,
         Err(err) => return Err(err.into()),
     };
 
+    // Capture the resolution in `pylock.toml` format, e.g., to record it in a tool receipt.
+    let lock = match PylockToml::from_resolution(&resolution, &[], venv.root()) {
+        Ok(lock) => Some(lock),
+        Err(err) => {
+            debug!("Failed to convert resolution to `pylock.toml`: {err}");
+            None
+        }
+    };
+    let resolution = Resolution::from(resolution);
+
     // Sync the environment.
     let changelog = pip::operations::install(
         &resolution,
@@ -2315,6 +2340,7 @@ pub(crate) async fn update_environment(
     Ok(EnvironmentUpdate {
         environment: venv,
         changelog,
+        lock,
     })
 }
 
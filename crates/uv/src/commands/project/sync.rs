@@ -6,13 +6,14 @@ use std::sync::Arc;
 use anyhow::{Context, Result};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use tracing::debug;
 
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DryRun, EditableMode,
-    ExtrasSpecification, ExtrasSpecificationWithDefaults, HashCheckingMode, InstallOptions,
-    PreviewMode,
+    BuildOptions, Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DryRun,
+    EditableMode, ExtrasSpecification, ExtrasSpecificationWithDefaults, HashCheckingMode,
+    InstallOptions, PreviewMode,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution_types::{
@@ -22,8 +23,11 @@ use uv_fs::Simplified;
 use uv_installer::SitePackages;
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_pep508::{MarkerTree, VersionOrUrl};
-use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl, ParsedUrl};
-use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_platform_tags::Tags;
+use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl, ParsedUrl, ResolverMarkerEnvironment};
+use uv_python::{
+    Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest, Target,
+};
 use uv_resolver::{FlatIndex, Installable, Lock};
 use uv_scripts::{Pep723ItemRef, Pep723Script};
 use uv_settings::PythonInstallMirrors;
@@ -40,7 +44,8 @@ use crate::commands::project::lock::{LockMode, LockOperation, LockResult};
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     default_dependency_groups, detect_conflicts, script_specification, update_environment,
-    PlatformState, ProjectEnvironment, ProjectError, ScriptEnvironment, UniversalState,
+    PlatformState, ProjectEnvironment, ProjectError, ProjectInterpreter, ScriptEnvironment,
+    UniversalState,
 };
 use crate::commands::{diagnostics, ExitStatus};
 use crate::printer::Printer;
@@ -54,6 +59,7 @@ pub(crate) async fn sync(
     frozen: bool,
     dry_run: DryRun,
     active: Option<bool>,
+    target_dir: Option<Target>,
     all_packages: bool,
     package: Option<PackageName>,
     extras: ExtrasSpecification,
@@ -126,9 +132,44 @@ pub(crate) async fn sync(
         SyncTarget::Script(..) => DefaultExtras::default(),
     };
 
+    // Workspace members are always installed as non-editable into a `--target` directory, which
+    // is expected to be relocated (e.g., deployed as an AWS Lambda layer).
+    let editable = if target_dir.is_some() {
+        EditableMode::NonEditable
+    } else {
+        editable
+    };
+
     // Discover or create the virtual environment.
-    let environment = match &target {
-        SyncTarget::Project(project) => SyncEnvironment::Project(
+    let environment = match (&target, target_dir) {
+        (SyncTarget::Project(project), Some(target_dir)) => {
+            // Use the project interpreter, without creating or modifying the project environment.
+            let interpreter = ProjectInterpreter::discover(
+                project.workspace(),
+                project_dir,
+                python.as_deref().map(PythonRequest::parse),
+                &network_settings,
+                python_preference,
+                python_downloads,
+                &install_mirrors,
+                false,
+                no_config,
+                Some(false),
+                cache,
+                printer,
+            )
+            .await?
+            .into_interpreter();
+
+            debug!(
+                "Using `--target` directory at {}",
+                target_dir.root().user_display()
+            );
+            SyncEnvironment::Target(
+                PythonEnvironment::from_interpreter(interpreter).with_target(target_dir)?,
+            )
+        }
+        (SyncTarget::Project(project), None) => SyncEnvironment::Project(
             ProjectEnvironment::get_or_init(
                 project.workspace(),
                 python.as_deref().map(PythonRequest::parse),
@@ -145,7 +186,7 @@ pub(crate) async fn sync(
             )
             .await?,
         ),
-        SyncTarget::Script(script) => SyncEnvironment::Script(
+        (SyncTarget::Script(script), _) => SyncEnvironment::Script(
             ScriptEnvironment::get_or_init(
                 Pep723ItemRef::Script(script),
                 python.as_deref().map(PythonRequest::parse),
@@ -166,6 +207,23 @@ pub(crate) async fn sync(
 
     // Notify the user of any environment changes.
     match &environment {
+        SyncEnvironment::Target(environment) if dry_run.enabled() => {
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Would install into target directory at: {}",
+                    environment
+                        .interpreter()
+                        .target()
+                        .map(Target::root)
+                        .unwrap_or_else(|| environment.root())
+                        .user_display()
+                        .bold()
+                )
+                .dimmed()
+            )?;
+        }
         SyncEnvironment::Project(ProjectEnvironment::Existing(environment))
             if dry_run.enabled() =>
         {
@@ -549,6 +607,8 @@ enum SyncEnvironment {
     Project(ProjectEnvironment),
     /// A Python environment for a script.
     Script(ScriptEnvironment),
+    /// A `--target` directory, installed into with the project's interpreter.
+    Target(PythonEnvironment),
 }
 
 impl Deref for SyncEnvironment {
@@ -558,6 +618,7 @@ impl Deref for SyncEnvironment {
         match self {
             Self::Project(environment) => Deref::deref(environment),
             Self::Script(environment) => Deref::deref(environment),
+            Self::Target(environment) => environment,
         }
     }
 }
@@ -606,71 +667,23 @@ pub(super) async fn do_sync(
         .keyring(keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
-    // Validate that the Python version is supported by the lockfile.
-    if !target
-        .lock()
-        .requires_python()
-        .contains(venv.interpreter().python_version())
-    {
-        return Err(ProjectError::LockedPythonIncompatibility(
-            venv.interpreter().python_version().clone(),
-            target.lock().requires_python().clone(),
-        ));
-    }
-
-    // Validate that the set of requested extras and development groups are compatible.
-    detect_conflicts(target.lock(), extras, dev)?;
-
-    // Validate that the set of requested extras and development groups are defined in the lockfile.
-    target.validate_extras(extras)?;
-    target.validate_groups(dev)?;
-
-    // Determine the markers to use for resolution.
+    // Determine the markers and tags to use for resolution.
     let marker_env = venv.interpreter().resolver_marker_environment();
-
-    // Validate that the platform is supported by the lockfile.
-    let environments = target.lock().supported_environments();
-    if !environments.is_empty() {
-        if !environments
-            .iter()
-            .any(|env| env.evaluate(&marker_env, &[]))
-        {
-            return Err(ProjectError::LockedPlatformIncompatibility(
-                // For error reporting, we use the "simplified"
-                // supported environments, because these correspond to
-                // what the end user actually wrote. The non-simplified
-                // environments, by contrast, are explicitly
-                // constrained by `requires-python`.
-                target
-                    .lock()
-                    .simplified_supported_environments()
-                    .into_iter()
-                    .filter_map(MarkerTree::contents)
-                    .map(|env| format!("`{env}`"))
-                    .join(", "),
-            ));
-        }
-    }
-
-    // Determine the tags to use for resolution.
     let tags = venv.interpreter().tags()?;
 
     // Read the lockfile.
-    let resolution = target.to_resolution(
+    let resolution = read_resolution(
+        target,
+        venv.interpreter(),
         &marker_env,
         tags,
         extras,
         dev,
-        build_options,
+        editable,
         &install_options,
+        build_options,
     )?;
 
-    // Always skip virtual projects, which shouldn't be built or installed.
-    let resolution = apply_no_virtual_project(resolution);
-
-    // If necessary, convert editable to non-editable distributions.
-    let resolution = apply_editable_mode(resolution, editable);
-
     // Add all authenticated sources to the cache.
     for index in index_locations.allowed_indexes() {
         if let Some(credentials) = index.credentials() {
@@ -690,6 +703,7 @@ pub(super) async fn do_sync(
         .cache(cache.clone())
         .index_locations(index_locations)
         .index_strategy(index_strategy)
+        .preview(preview)
         .markers(venv.interpreter().markers())
         .platform(venv.interpreter().platform())
         .build();
@@ -776,8 +790,82 @@ pub(super) async fn do_sync(
     Ok(())
 }
 
+/// Read the [`Resolution`] to install into the given environment from a lockfile.
+///
+/// Validates that the lockfile supports the environment, along with the requested extras and
+/// dependency groups.
+pub(super) fn read_resolution(
+    target: InstallTarget<'_>,
+    interpreter: &Interpreter,
+    marker_env: &ResolverMarkerEnvironment,
+    tags: &Tags,
+    extras: &ExtrasSpecificationWithDefaults,
+    dev: &DependencyGroupsWithDefaults,
+    editable: EditableMode,
+    install_options: &InstallOptions,
+    build_options: &BuildOptions,
+) -> Result<Resolution, ProjectError> {
+    // Validate that the Python version is supported by the lockfile.
+    if !target
+        .lock()
+        .requires_python()
+        .contains(interpreter.python_version())
+    {
+        return Err(ProjectError::LockedPythonIncompatibility(
+            interpreter.python_version().clone(),
+            target.lock().requires_python().clone(),
+        ));
+    }
+
+    // Validate that the set of requested extras and development groups are compatible.
+    detect_conflicts(target.lock(), extras, dev)?;
+
+    // Validate that the set of requested extras and development groups are defined in the lockfile.
+    target.validate_extras(extras)?;
+    target.validate_groups(dev)?;
+
+    // Validate that the platform is supported by the lockfile.
+    let environments = target.lock().supported_environments();
+    if !environments.is_empty() {
+        if !environments.iter().any(|env| env.evaluate(marker_env, &[])) {
+            return Err(ProjectError::LockedPlatformIncompatibility(
+                // For error reporting, we use the "simplified"
+                // supported environments, because these correspond to
+                // what the end user actually wrote. The non-simplified
+                // environments, by contrast, are explicitly
+                // constrained by `requires-python`.
+                target
+                    .lock()
+                    .simplified_supported_environments()
+                    .into_iter()
+                    .filter_map(MarkerTree::contents)
+                    .map(|env| format!("`{env}`"))
+                    .join(", "),
+            ));
+        }
+    }
+
+    // Read the lockfile.
+    let resolution = target.to_resolution(
+        marker_env,
+        tags,
+        extras,
+        dev,
+        build_options,
+        install_options,
+    )?;
+
+    // Always skip virtual projects, which shouldn't be built or installed.
+    let resolution = apply_no_virtual_project(resolution);
+
+    // If necessary, convert editable to non-editable distributions.
+    let resolution = apply_editable_mode(resolution, editable);
+
+    Ok(resolution)
+}
+
 /// Filter out any virtual workspace members.
-fn apply_no_virtual_project(resolution: Resolution) -> Resolution {
+pub(super) fn apply_no_virtual_project(resolution: Resolution) -> Resolution {
     resolution.filter(|dist| {
         let ResolvedDist::Installable { dist, .. } = dist else {
             return true;
@@ -796,7 +884,7 @@ fn apply_no_virtual_project(resolution: Resolution) -> Resolution {
 }
 
 /// If necessary, convert any editable requirements to non-editable.
-fn apply_editable_mode(resolution: Resolution, editable: EditableMode) -> Resolution {
+pub(super) fn apply_editable_mode(resolution: Resolution, editable: EditableMode) -> Resolution {
     match editable {
         // No modifications are necessary for editable mode; retain any editable distributions.
         EditableMode::Editable => resolution,
@@ -837,7 +925,7 @@ fn apply_editable_mode(resolution: Resolution, editable: EditableMode) -> Resolu
 ///
 /// These credentials can come from any of `tool.uv.sources`, `tool.uv.dev-dependencies`,
 /// `project.dependencies`, and `project.optional-dependencies`.
-fn store_credentials_from_target(target: InstallTarget<'_>) {
+pub(super) fn store_credentials_from_target(target: InstallTarget<'_>) {
     // Iterate over any idnexes in the target.
     for index in target.indexes() {
         if let Some(credentials) = index.credentials() {
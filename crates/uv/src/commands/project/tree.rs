@@ -8,6 +8,7 @@ use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
 use uv_client::RegistryClientBuilder;
 use uv_configuration::{Concurrency, DependencyGroups, PreviewMode, TargetTriple};
+use uv_distribution_filename::DistFilename;
 use uv_distribution_types::IndexCapabilities;
 use uv_normalize::DefaultGroups;
 use uv_pep508::PackageName;
@@ -44,6 +45,7 @@ pub(crate) async fn tree(
     no_dedupe: bool,
     invert: bool,
     outdated: bool,
+    status: bool,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
     python: Option<String>,
@@ -166,8 +168,8 @@ pub(crate) async fn tree(
         )
     });
 
-    // If necessary, look up the latest version of each package.
-    let latest = if outdated {
+    // If necessary, look up the latest version and project status of each package.
+    let (latest, statuses) = if outdated || status {
         // Filter to packages that are derived from a registry.
         let packages = lock
             .packages()
@@ -184,7 +186,7 @@ pub(crate) async fn tree(
             .collect::<Result<Vec<_>, _>>()?;
 
         if packages.is_empty() {
-            PackageMap::default()
+            (PackageMap::default(), PackageMap::default())
         } else {
             let ResolverSettings {
                 index_locations,
@@ -230,37 +232,51 @@ pub(crate) async fn tree(
 
             let reporter = LatestVersionReporter::from(printer).with_length(packages.len() as u64);
 
-            // Fetch the latest version for each package.
+            // Fetch the latest version and project status for each package.
             let download_concurrency = &download_concurrency;
             let mut fetches = futures::stream::iter(packages)
                 .map(|(package, index)| async move {
                     // This probably already doesn't work for `--find-links`?
-                    let Some(filename) = client
-                        .find_latest(package.name(), Some(&index), download_concurrency)
-                        .await?
-                    else {
-                        return Ok(None);
+                    let latest = if outdated {
+                        client
+                            .find_latest(package.name(), Some(&index), download_concurrency)
+                            .await?
+                            .map(DistFilename::into_version)
+                    } else {
+                        None
                     };
-                    Ok::<Option<_>, Error>(Some((package, filename.into_version())))
+                    let project_status = if status {
+                        client
+                            .find_status(package.name(), Some(&index), download_concurrency)
+                            .await?
+                            .filter(|status| !status.is_active())
+                    } else {
+                        None
+                    };
+                    Ok::<_, Error>((package, latest, project_status))
                 })
                 .buffer_unordered(concurrency.downloads);
 
-            let mut map = PackageMap::default();
-            while let Some(entry) = fetches.next().await.transpose()? {
-                let Some((package, version)) = entry else {
+            let mut latest = PackageMap::default();
+            let mut statuses = PackageMap::default();
+            while let Some((package, version, project_status)) = fetches.next().await.transpose()? {
+                if let Some(version) = version {
+                    reporter.on_fetch_version(package.name(), &version);
+                    if package.version().is_some_and(|package| version > *package) {
+                        latest.insert(package.clone(), version);
+                    }
+                } else {
                     reporter.on_fetch_progress();
-                    continue;
-                };
-                reporter.on_fetch_version(package.name(), &version);
-                if package.version().is_some_and(|package| version > *package) {
-                    map.insert(package.clone(), version);
+                }
+                if let Some(project_status) = project_status {
+                    statuses.insert(package.clone(), project_status);
                 }
             }
             reporter.on_fetch_complete();
-            map
+            (latest, statuses)
         }
     } else {
-        PackageMap::default()
+        (PackageMap::default(), PackageMap::default())
     };
 
     // Render the tree.
@@ -268,6 +284,7 @@ pub(crate) async fn tree(
         &lock,
         markers.as_ref(),
         &latest,
+        &statuses,
         depth.into(),
         &prune,
         &package,
@@ -11,7 +11,7 @@ use tracing::{debug, info};
 use url::Url;
 use uv_auth::Credentials;
 use uv_cache::Cache;
-use uv_client::{AuthIntegration, BaseClient, BaseClientBuilder, RegistryClientBuilder};
+use uv_client::{AuthIntegration, BaseClient, BaseClientBuilder, HostTls, RegistryClientBuilder};
 use uv_configuration::{KeyringProviderType, TrustedPublishing};
 use uv_distribution_types::{Index, IndexCapabilities, IndexLocations, IndexUrl};
 use uv_publish::{
@@ -33,6 +33,7 @@ pub(crate) async fn publish(
     username: Option<String>,
     password: Option<String>,
     check_url: Option<IndexUrl>,
+    host_tls: Vec<HostTls>,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -62,6 +63,7 @@ pub(crate) async fn publish(
         .keyring(keyring_provider)
         .native_tls(network_settings.native_tls)
         .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .host_tls(host_tls.clone())
         // Don't try cloning the request to make an unauthenticated request first.
         .auth_integration(AuthIntegration::OnlyAuthenticated)
         // Set a very high timeout for uploads, connections are often 10x slower on upload than
@@ -99,6 +101,7 @@ pub(crate) async fn publish(
             .connectivity(network_settings.connectivity)
             .allow_insecure_host(network_settings.allow_insecure_host.clone())
             .index_locations(&index_locations)
+            .host_tls(host_tls)
             .keyring(keyring_provider);
         Some(CheckUrlClient {
             index_url: index_url.clone(),
@@ -12,6 +12,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, trace};
 
 use uv_configuration::PreviewMode;
+use uv_distribution_filename::SourceDistExtension;
 use uv_fs::Simplified;
 use uv_python::downloads::{self, DownloadResult, ManagedPythonDownload, PythonDownloadRequest};
 use uv_python::managed::{
@@ -26,6 +27,7 @@ use uv_shell::Shell;
 use uv_trampoline_builder::{Launcher, LauncherKind};
 use uv_warnings::warn_user;
 
+use crate::commands::python::shim;
 use crate::commands::python::{ChangeEvent, ChangeEventKind};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{elapsed, ExitStatus};
@@ -40,6 +42,8 @@ struct InstallRequest {
     download_request: PythonDownloadRequest,
     /// A download that satisfies the request
     download: &'static ManagedPythonDownload,
+    /// A local archive to install the download from, rather than downloading it
+    archive: Option<PathBuf>,
 }
 
 impl InstallRequest {
@@ -74,6 +78,35 @@ impl InstallRequest {
             request,
             download_request,
             download,
+            archive: None,
+        })
+    }
+
+    /// Create a request to install a local `python-build-standalone` archive.
+    fn from_archive(archive: &Path, python_downloads_json_url: Option<&str>) -> Result<Self> {
+        if !archive.is_file() {
+            anyhow::bail!("Python archive not found: `{}`", archive.user_display());
+        }
+
+        // Identify the distribution by its file name, then ensure it can run on this platform
+        let download = ManagedPythonDownload::from_archive(archive, python_downloads_json_url)?;
+        if !PythonDownloadRequest::from_env()?
+            .with_prereleases(true)
+            .satisfied_by_download(download)
+        {
+            anyhow::bail!(
+                "The Python archive `{}` contains `{}`, which is not compatible with the current platform",
+                archive.user_display(),
+                download.key()
+            );
+        }
+
+        let download_request = PythonDownloadRequest::from(download.key());
+        Ok(Self {
+            request: PythonRequest::Key(download_request.clone()),
+            download_request,
+            download,
+            archive: Some(archive.to_path_buf()),
         })
     }
 
@@ -134,6 +167,7 @@ pub(crate) async fn install(
     python_install_mirror: Option<String>,
     pypy_install_mirror: Option<String>,
     python_downloads_json_url: Option<String>,
+    archive_dir: Option<PathBuf>,
     network_settings: NetworkSettings,
     default: bool,
     python_downloads: PythonDownloads,
@@ -179,8 +213,19 @@ pub(crate) async fn install(
     } else {
         targets
             .iter()
-            .map(|target| PythonRequest::parse(target.as_str()))
-            .map(|a| InstallRequest::new(a, python_downloads_json_url.as_deref()))
+            .map(|target| {
+                if SourceDistExtension::from_path(target).is_ok() {
+                    InstallRequest::from_archive(
+                        Path::new(target),
+                        python_downloads_json_url.as_deref(),
+                    )
+                } else {
+                    InstallRequest::new(
+                        PythonRequest::parse(target.as_str()),
+                        python_downloads_json_url.as_deref(),
+                    )
+                }
+            })
             .collect::<Result<Vec<_>>>()?
     };
 
@@ -276,8 +321,11 @@ pub(crate) async fn install(
         })
     };
 
-    // Check if Python downloads are banned
-    if matches!(python_downloads, PythonDownloads::Never) && !unsatisfied.is_empty() {
+    // Check if Python downloads are banned; installing from local archives is always allowed
+    if matches!(python_downloads, PythonDownloads::Never)
+        && archive_dir.is_none()
+        && unsatisfied.iter().any(|request| request.archive.is_none())
+    {
         writeln!(
             printer.stderr(),
             "Python downloads are not allowed (`python-downloads = \"never\"`). Change to `python-downloads = \"manual\"` to allow explicit installs.",
@@ -295,10 +343,17 @@ pub(crate) async fn install(
                 request.cyan(),
             );
         })
-        .map(|request| request.download)
         // Ensure we only download each version once
-        .unique_by(|download| download.key())
-        .collect::<Vec<_>>();
+        .unique_by(|request| request.download.key())
+        .map(|request| {
+            let archive = match (&request.archive, &archive_dir) {
+                (Some(archive), _) => Some(archive.clone()),
+                (None, Some(dir)) => Some(request.download.find_archive(dir)?),
+                (None, None) => None,
+            };
+            Ok((request.download, archive))
+        })
+        .collect::<Result<Vec<_>>>()?;
 
     // Download and unpack the Python versions concurrently
     let client = uv_client::BaseClientBuilder::new()
@@ -309,10 +364,19 @@ pub(crate) async fn install(
     let reporter = PythonDownloadReporter::new(printer, downloads.len() as u64);
     let mut tasks = FuturesUnordered::new();
 
-    for download in &downloads {
+    for (download, archive) in &downloads {
         tasks.push(async {
-            (
-                *download,
+            let result = if let Some(archive) = archive {
+                download
+                    .install_from_archive(
+                        archive,
+                        installations_dir,
+                        &scratch_dir,
+                        reinstall,
+                        Some(&reporter),
+                    )
+                    .await
+            } else {
                 download
                     .fetch_with_retry(
                         &client,
@@ -323,8 +387,9 @@ pub(crate) async fn install(
                         pypy_install_mirror.as_deref(),
                         Some(&reporter),
                     )
-                    .await,
-            )
+                    .await
+            };
+            (*download, result)
         });
     }
 
@@ -402,6 +467,13 @@ pub(crate) async fn install(
         }
     }
 
+    // Add shims for any newly installed Python versions.
+    if !changelog.installed.is_empty() {
+        if let Err(err) = shim::refresh() {
+            warn_user!("Failed to update Python shims: {err}");
+        }
+    }
+
     if changelog.installed.is_empty() && errors.is_empty() {
         if is_default_install {
             writeln!(
@@ -11,7 +11,7 @@ use uv_dirs::user_uv_config_dir;
 use uv_fs::Simplified;
 use uv_python::{
     EnvironmentPreference, PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
-    VersionFileDiscoveryOptions, PYTHON_VERSION_FILENAME,
+    VersionFileDiscoveryOptions, VersionRequest, PYTHON_VERSION_FILENAME,
 };
 use uv_warnings::warn_user_once;
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
@@ -132,6 +132,7 @@ pub(crate) async fn pin(
         // TODO(zanieb): Maybe avoid reparsing here?
         PythonRequest::parse(
             &python
+                .as_ref()
                 .unwrap()
                 .interpreter()
                 .sys_executable()
@@ -144,7 +145,43 @@ pub(crate) async fn pin(
 
     let existing = version_file.ok().flatten();
     // TODO(zanieb): Allow updating the discovered version file with an `--update` flag.
-    let new = if global {
+    let new = if let Some(existing) = existing.as_ref().filter(|existing| {
+        // Version files owned by other tools (e.g., `.tool-versions`) are updated in place, rather
+        // than shadowed by a `.python-version` file
+        !global && existing.kind().is_foreign() && existing.path().parent() == Some(project_dir)
+    }) {
+        if !matches!(request, PythonRequest::Version(_)) {
+            bail!(
+                "Only Python versions can be pinned in `{}`, but `{}` was requested",
+                existing.file_name(),
+                request.to_canonical_string()
+            );
+        }
+        // Some tools don't resolve partial versions, so pin the full version of the interpreter
+        let request = if existing.kind().requires_patch_version()
+            && !matches!(
+                request,
+                PythonRequest::Version(VersionRequest::MajorMinorPatch(..))
+            ) {
+            let Some(python) = &python else {
+                bail!(
+                    "A full Python version is required in `{}`, but no interpreter was found for `{}`",
+                    existing.file_name(),
+                    request.to_canonical_string()
+                );
+            };
+            let interpreter = python.interpreter();
+            PythonRequest::Version(VersionRequest::MajorMinorPatch(
+                interpreter.python_major(),
+                interpreter.python_minor(),
+                interpreter.python_patch(),
+                interpreter.variant(),
+            ))
+        } else {
+            request
+        };
+        PythonVersionFile::new(existing.path().to_path_buf()).with_versions(vec![request])
+    } else if global {
         let Some(config_dir) = user_uv_config_dir() else {
             return Err(anyhow::anyhow!("No user-level config directory found."));
         };
@@ -0,0 +1,380 @@
+use std::collections::BTreeSet;
+use std::convert::Infallible;
+use std::ffi::{OsStr, OsString};
+use std::fmt::Write;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::BaseClientBuilder;
+use uv_configuration::PreviewMode;
+use uv_fs::Simplified;
+use uv_python::managed::{python_shim_dir, ManagedPythonInstallations};
+use uv_python::{
+    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    PythonVersionFile, VersionFileDiscoveryOptions, VersionRequest,
+};
+use uv_settings::PythonInstallMirrors;
+use uv_shell::Shell;
+use uv_warnings::{warn_user, warn_user_once};
+
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::NetworkSettings;
+
+/// A marker included in every shim, used to distinguish shims from other files.
+const SHIM_MARKER: &str = "uv-python-shim";
+
+/// List the Python shims.
+pub(crate) fn list(preview: PreviewMode, printer: Printer) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!(
+            "`uv python shim` is experimental and may change without warning. Pass `--preview` to disable this warning."
+        );
+    }
+
+    let shim_dir = python_shim_dir()?;
+    let shims = find_shims(&shim_dir)?;
+
+    if shims.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "No Python shims found in `{}`. Use `uv python shim rebuild` to create them.",
+            shim_dir.simplified_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for shim in shims {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            shim_dir.join(shim).simplified_display().cyan()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Create or rebuild the Python shims.
+pub(crate) fn rebuild(preview: PreviewMode, printer: Printer) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!(
+            "`uv python shim` is experimental and may change without warning. Pass `--preview` to disable this warning."
+        );
+    }
+
+    let shim_dir = python_shim_dir()?;
+    let shims = write_shims(&shim_dir)?;
+
+    writeln!(
+        printer.stderr(),
+        "Created {} in `{}`",
+        format!(
+            "{} shim{}",
+            shims.len(),
+            if shims.len() == 1 { "" } else { "s" }
+        )
+        .bold(),
+        shim_dir.simplified_display().cyan()
+    )?;
+    for shim in &shims {
+        writeln!(printer.stderr(), " {} {}", "+".green(), shim.bold())?;
+    }
+
+    if !Shell::contains_path(&shim_dir) {
+        if let Some(command) = Shell::from_env().and_then(|shell| shell.prepend_path(&shim_dir)) {
+            warn_user!(
+                "`{}` is not on your PATH. To use the Python shims, run `{}`.",
+                shim_dir.simplified_display().cyan(),
+                command.green(),
+            );
+        } else {
+            warn_user!(
+                "`{}` is not on your PATH. To use the Python shims, add the directory to your PATH.",
+                shim_dir.simplified_display().cyan(),
+            );
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Rebuild the Python shims if they've previously been created, e.g., after installing or
+/// uninstalling a Python version.
+pub(crate) fn refresh() -> Result<()> {
+    let shim_dir = python_shim_dir()?;
+    if find_shims(&shim_dir)?.is_empty() {
+        return Ok(());
+    }
+    debug!("Rebuilding Python shims in: {}", shim_dir.user_display());
+    write_shims(&shim_dir)?;
+    Ok(())
+}
+
+/// Execute the Python interpreter selected by the shim with the given name.
+pub(crate) async fn exec(
+    project_dir: &Path,
+    name: &str,
+    args: Vec<OsString>,
+    install_mirrors: PythonInstallMirrors,
+    network_settings: &NetworkSettings,
+    python_downloads: PythonDownloads,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let Some(shim_request) = parse_shim_name(name) else {
+        bail!("Invalid Python shim name: `{name}`");
+    };
+
+    // Shims for a specific minor version always use that version; otherwise, respect the nearest
+    // pinned version, if any.
+    let pinned = if matches!(
+        shim_request,
+        VersionRequest::Default | VersionRequest::Major(..)
+    ) {
+        PythonVersionFile::discover(
+            project_dir,
+            &VersionFileDiscoveryOptions::default().with_no_config(no_config),
+        )
+        .await?
+        .and_then(PythonVersionFile::into_version)
+    } else {
+        None
+    };
+    let request = pinned.unwrap_or(PythonRequest::Version(shim_request));
+    debug!("Resolved shim `{name}` to request: {request}");
+
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+    let reporter = PythonDownloadReporter::single(printer);
+
+    // Only consider managed Python installations, which avoids discovering the shims themselves
+    // on the `PATH`.
+    let installation = PythonInstallation::find_or_download(
+        Some(&request),
+        EnvironmentPreference::OnlySystem,
+        PythonPreference::OnlyManaged,
+        python_downloads,
+        &client_builder,
+        cache,
+        Some(&reporter),
+        install_mirrors.python_install_mirror.as_deref(),
+        install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_downloads_json_url.as_deref(),
+    )
+    .await?;
+
+    let executable = installation.interpreter().sys_executable();
+    debug!(
+        "Executing shim `{name}` with: {}",
+        executable.user_display()
+    );
+
+    let mut command = Command::new(executable);
+    command.args(args);
+    match exec_spawn(&mut command) {
+        Ok(never) => match never {},
+        Err(err) => Err(anyhow::Error::from(err)
+            .context(format!("Failed to spawn: `{}`", executable.user_display()))),
+    }
+}
+
+/// Spawns a command exec style.
+fn exec_spawn(command: &mut Command) -> std::io::Result<Infallible> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        let err = command.exec();
+        Err(err)
+    }
+    #[cfg(windows)]
+    {
+        command.stdin(std::process::Stdio::inherit());
+        let status = command.status()?;
+
+        // A missing exit code means the process was terminated, which we treat as a failure.
+        #[allow(clippy::exit)]
+        std::process::exit(status.code().unwrap_or(1))
+    }
+}
+
+/// Parse the [`VersionRequest`] from a shim name, e.g., `python3.12` or `python3.13t`.
+fn parse_shim_name(name: &str) -> Option<VersionRequest> {
+    let name = name
+        .strip_suffix(std::env::consts::EXE_SUFFIX)
+        .unwrap_or(name);
+    let version = name.strip_prefix("python")?;
+    if version.is_empty() {
+        return Some(VersionRequest::Default);
+    }
+    match VersionRequest::from_str(version).ok()? {
+        VersionRequest::Default | VersionRequest::Any | VersionRequest::Range(..) => None,
+        request => Some(request),
+    }
+}
+
+/// Return the names of the shims that should exist, based on the installed managed Python
+/// versions.
+fn shim_names() -> Result<BTreeSet<String>> {
+    let mut names = BTreeSet::from(["python".to_string(), "python3".to_string()]);
+    let installations = ManagedPythonInstallations::from_settings(None)?;
+    for installation in installations.find_matching_current_platform()? {
+        let name = installation.key().executable_name_minor();
+        let name = name
+            .strip_suffix(std::env::consts::EXE_SUFFIX)
+            .unwrap_or(&name);
+        names.insert(name.to_string());
+    }
+    Ok(names)
+}
+
+/// Return the file name of the shim with the given name.
+fn shim_filename(name: &str) -> String {
+    format!("{name}{}", std::env::consts::EXE_SUFFIX)
+}
+
+/// Return the contents of the shim with the given name, which invokes the given `uv` executable.
+///
+/// On Windows, the shim is a trampoline that invokes `uv` with the path to the shim as the first
+/// argument, which is then resolved by [`shim_from_trampoline`].
+fn shim_contents(name: &str, uv: &Path) -> Result<Vec<u8>> {
+    if cfg!(windows) {
+        Ok(uv_trampoline_builder::windows_script_launcher(
+            &format!("# {SHIM_MARKER}\n"),
+            false,
+            uv,
+        )?)
+    } else {
+        let uv = uv.to_string_lossy().replace('\'', r"'\''");
+        Ok(
+            format!("#!/bin/sh\n# {SHIM_MARKER}\nexec '{uv}' python shim exec {name} -- \"$@\"\n")
+                .into_bytes(),
+        )
+    }
+}
+
+/// If the given argument is the path to a shim trampoline, return the name of the shim.
+///
+/// On Windows, shim trampolines invoke `uv` with their own path as the first argument.
+pub(crate) fn shim_from_trampoline(arg: &OsStr) -> Option<String> {
+    if !cfg!(windows) {
+        return None;
+    }
+    let path = Path::new(arg);
+    if !path.is_absolute() || !is_shim(path) {
+        return None;
+    }
+    let name = path.file_stem()?.to_str()?;
+    Some(name.to_string())
+}
+
+/// Find the shims in the given directory, returning their file names.
+fn find_shims(shim_dir: &Path) -> Result<Vec<String>> {
+    let entries = match fs_err::read_dir(shim_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut shims = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if !is_shim(&path) {
+            continue;
+        }
+        if let Some(name) = path.file_name().and_then(|name| name.to_str()) {
+            shims.push(name.to_string());
+        }
+    }
+    shims.sort_unstable();
+    Ok(shims)
+}
+
+/// Returns `true` if the file at the given path is a shim created by uv.
+fn is_shim(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    if parse_shim_name(name).is_none() {
+        return false;
+    }
+    fs_err::read(path).is_ok_and(|contents| {
+        contents
+            .windows(SHIM_MARKER.len())
+            .any(|window| window == SHIM_MARKER.as_bytes())
+    })
+}
+
+/// Write the shims to the given directory, removing any stale shims, and return their names.
+fn write_shims(shim_dir: &Path) -> Result<Vec<String>> {
+    let uv = std::env::current_exe().context("Failed to determine the path to `uv`")?;
+    let names = shim_names()?;
+
+    fs_err::create_dir_all(shim_dir)?;
+
+    // Remove shims for Python versions that are no longer installed.
+    let filenames = names
+        .iter()
+        .map(|name| shim_filename(name))
+        .collect::<BTreeSet<_>>();
+    for stale in find_shims(shim_dir)? {
+        if !filenames.contains(&stale) {
+            debug!("Removing stale shim: {stale}");
+            fs_err::remove_file(shim_dir.join(stale))?;
+        }
+    }
+
+    for name in &names {
+        let path = shim_dir.join(shim_filename(name));
+        uv_fs::write_atomic_sync(&path, shim_contents(name, &uv)?)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs_err::set_permissions(&path, std::fs::Permissions::from_mode(0o755))?;
+        }
+    }
+
+    Ok(names.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use uv_python::PythonVariant;
+
+    use super::*;
+
+    #[test]
+    fn shim_name() {
+        assert_eq!(parse_shim_name("python"), Some(VersionRequest::Default));
+        assert_eq!(
+            parse_shim_name("python3"),
+            Some(VersionRequest::Major(3, PythonVariant::Default))
+        );
+        assert_eq!(
+            parse_shim_name("python3.12"),
+            Some(VersionRequest::MajorMinor(3, 12, PythonVariant::Default))
+        );
+        assert_eq!(
+            parse_shim_name("python3.13t"),
+            Some(VersionRequest::MajorMinor(
+                3,
+                13,
+                PythonVariant::Freethreaded
+            ))
+        );
+        assert_eq!(parse_shim_name("pythonw"), None);
+        assert_eq!(parse_shim_name("pip"), None);
+    }
+}
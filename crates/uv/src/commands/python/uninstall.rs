@@ -15,8 +15,10 @@ use uv_fs::Simplified;
 use uv_python::downloads::PythonDownloadRequest;
 use uv_python::managed::{python_executable_dir, ManagedPythonInstallations};
 use uv_python::{PythonInstallationKey, PythonRequest};
+use uv_warnings::warn_user;
 
 use crate::commands::python::install::format_executables;
+use crate::commands::python::shim;
 use crate::commands::python::{ChangeEvent, ChangeEventKind};
 use crate::commands::{elapsed, ExitStatus};
 use crate::printer::Printer;
@@ -36,6 +38,11 @@ pub(crate) async fn uninstall(
     // Perform the uninstallation.
     do_uninstall(&installations, targets, all, printer, preview).await?;
 
+    // Remove shims for any uninstalled Python versions.
+    if let Err(err) = shim::refresh() {
+        warn_user!("Failed to update Python shims: {err}");
+    }
+
     // Clean up any empty directories.
     if uv_fs::directories(installations.root())?.all(|path| uv_fs::is_temporary(&path)) {
         fs_err::tokio::remove_dir_all(&installations.root()).await?;
@@ -0,0 +1,524 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_configuration::PreviewMode;
+use uv_fs::Simplified;
+use uv_python::downloads::{DownloadResult, PythonDownloadRequest};
+use uv_python::managed::{
+    python_executable_dir, ManagedPythonInstallation, ManagedPythonInstallations,
+};
+use uv_python::{PyVenvConfiguration, PythonDownloads, PythonRequest};
+use uv_tool::InstalledTools;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::commands::python::{ChangeEvent, ChangeEventKind};
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::{elapsed, ExitStatus};
+use crate::printer::Printer;
+use crate::settings::NetworkSettings;
+
+/// Upgrade managed Python versions to the latest patch release.
+pub(crate) async fn upgrade(
+    project_dir: &Path,
+    install_dir: Option<PathBuf>,
+    targets: Vec<String>,
+    remove_superseded: bool,
+    venvs: Vec<PathBuf>,
+    python_install_mirror: Option<String>,
+    pypy_install_mirror: Option<String>,
+    python_downloads_json_url: Option<String>,
+    network_settings: NetworkSettings,
+    python_downloads: PythonDownloads,
+    preview: PreviewMode,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    let requests = targets
+        .iter()
+        .map(|target| {
+            let request = PythonRequest::parse(target.as_str());
+            PythonDownloadRequest::from_request(&request)
+                .map(|request| request.with_prereleases(true))
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Cannot upgrade managed Python for request: {request}")
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Read the existing installations, lock the directory for the duration.
+    let installations = ManagedPythonInstallations::from_settings(install_dir)?.init()?;
+    let installations_dir = installations.root();
+    let scratch_dir = installations.scratch();
+    let _lock = installations.lock().await?;
+    let existing = installations
+        .find_all()?
+        .filter(|installation| {
+            requests.is_empty()
+                || requests
+                    .iter()
+                    .any(|request| request.satisfied_by_key(installation.key()))
+        })
+        .collect::<Vec<_>>();
+
+    if existing.is_empty() {
+        if requests.is_empty() {
+            writeln!(printer.stderr(), "No Python installations found")?;
+        } else {
+            writeln!(
+                printer.stderr(),
+                "No Python installations found matching the requests"
+            )?;
+        }
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Find the newest installed patch release for each minor version. The installations are
+    // sorted from newest to oldest, so the first installation for each minor version is the
+    // newest.
+    let mut latest: Vec<&ManagedPythonInstallation> = Vec::new();
+    for installation in &existing {
+        if !latest.iter().any(|newest| supersedes(newest, installation)) {
+            latest.push(installation);
+        }
+    }
+
+    // Find a newer patch release for each minor version, if any.
+    let mut downloads = Vec::new();
+    for installation in &latest {
+        if let Some(download) = installation.find_upgrade(python_downloads_json_url.as_deref())? {
+            debug!(
+                "Found upgrade `{}` for `{}`",
+                download.key().green(),
+                installation.key().green()
+            );
+            downloads.push(download);
+        } else {
+            debug!("`{}` is up-to-date", installation.key().green());
+        }
+    }
+
+    // Check if Python downloads are banned.
+    if matches!(python_downloads, PythonDownloads::Never) && !downloads.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "Python downloads are not allowed (`python-downloads = \"never\"`). Change to `python-downloads = \"manual\"` to allow explicit upgrades.",
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Download and unpack the Python versions concurrently.
+    let client = uv_client::BaseClientBuilder::new()
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .build();
+    let reporter = PythonDownloadReporter::new(printer, downloads.len() as u64);
+    let mut tasks = FuturesUnordered::new();
+    for download in &downloads {
+        tasks.push(async {
+            (
+                *download,
+                download
+                    .fetch_with_retry(
+                        &client,
+                        installations_dir,
+                        &scratch_dir,
+                        false,
+                        python_install_mirror.as_deref(),
+                        pypy_install_mirror.as_deref(),
+                        Some(&reporter),
+                    )
+                    .await,
+            )
+        });
+    }
+
+    let mut errors = vec![];
+    let mut installed = Vec::with_capacity(downloads.len());
+    while let Some((download, result)) = tasks.next().await {
+        match result {
+            Ok(DownloadResult::AlreadyAvailable(path) | DownloadResult::Fetched(path)) => {
+                let installation = ManagedPythonInstallation::new(path, download);
+                installation.ensure_externally_managed()?;
+                installation.ensure_sysconfig_patched()?;
+                installation.ensure_canonical_executables()?;
+                if let Err(e) = installation.ensure_dylib_patched() {
+                    e.warn_user(&installation);
+                }
+                installed.push(installation);
+            }
+            Err(err) => {
+                errors.push((download.key().clone(), anyhow::Error::new(err)));
+            }
+        }
+    }
+
+    // Map each superseded installation to the newest installation for its minor version.
+    let newest = installed
+        .iter()
+        .chain(latest.iter().copied())
+        .sorted_unstable_by(|a, b| b.key().cmp(a.key()))
+        .collect::<Vec<_>>();
+    let superseded = existing
+        .iter()
+        .filter_map(|installation| {
+            newest
+                .iter()
+                .find(|newest| supersedes(newest, installation))
+                .map(|newest| (installation, *newest))
+        })
+        .collect::<Vec<_>>();
+
+    // Update any environments that reference a superseded installation.
+    let mut environments = Vec::new();
+    let installed_tools = InstalledTools::from_settings()?;
+    let _tools_lock = if installed_tools.root().is_dir() {
+        Some(installed_tools.lock().await?)
+    } else {
+        None
+    };
+    for (name, _) in installed_tools.tools()? {
+        environments.push(installed_tools.tool_dir(&name));
+    }
+    let project_venv = project_environment(project_dir).await;
+    if project_venv.join("pyvenv.cfg").is_file() {
+        environments.push(project_venv);
+    }
+    environments.extend(
+        venvs
+            .into_iter()
+            .map(|venv| std::path::absolute(&venv).unwrap_or(venv)),
+    );
+
+    let mut updated = Vec::new();
+    // The superseded installations that are still used by an environment that couldn't be updated.
+    let mut in_use = Vec::new();
+    for environment in environments.into_iter().unique() {
+        let base = match SupersededBase::from_environment(&environment, &superseded) {
+            Ok(Some(base)) => base,
+            Ok(None) => continue,
+            Err(err) => {
+                warn_user!(
+                    "Failed to read the environment at `{}`: {err}",
+                    environment.user_display()
+                );
+                continue;
+            }
+        };
+        match base.retarget(&environment) {
+            Ok(()) => {
+                debug!(
+                    "Updated `{}` to use `{}`",
+                    environment.user_display(),
+                    base.target.key()
+                );
+                updated.push(environment);
+            }
+            Err(err) => {
+                warn_user!(
+                    "Failed to update the environment at `{}`: {err}",
+                    environment.user_display()
+                );
+                in_use.push(base.installation.key());
+            }
+        }
+    }
+
+    // Update any executable links that reference a superseded installation.
+    if preview.is_enabled() && !superseded.is_empty() {
+        for path in python_executable_dir()?
+            .read_dir()
+            .into_iter()
+            .flatten()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_ok_and(|file_type| !file_type.is_dir()))
+            .map(|entry| entry.path())
+        {
+            let Some((_, target)) = superseded
+                .iter()
+                .find(|(installation, _)| installation.is_bin_link(&path))
+            else {
+                continue;
+            };
+            fs_err::remove_file(&path)?;
+            target.create_bin_link(&path)?;
+            debug!(
+                "Updated `{}` to use `{}`",
+                path.simplified_display(),
+                target.key()
+            );
+        }
+    }
+
+    // If requested, remove the superseded installations.
+    let mut removed = Vec::new();
+    if remove_superseded {
+        for (installation, _) in &superseded {
+            if in_use.contains(&installation.key()) {
+                warn_user!(
+                    "Keeping `{}`, since an environment that uses it could not be updated",
+                    installation.key().green()
+                );
+                continue;
+            }
+            match fs_err::tokio::remove_dir_all(installation.path()).await {
+                Ok(()) => removed.push(*installation),
+                Err(err) => errors.push((installation.key().clone(), anyhow::Error::new(err))),
+            }
+        }
+    }
+
+    if installed.is_empty() && removed.is_empty() && updated.is_empty() && errors.is_empty() {
+        writeln!(printer.stderr(), "All Python versions are up-to-date")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    if !installed.is_empty() {
+        let versions = if installed.len() == 1 {
+            format!("Python {}", installed[0].key().version())
+        } else {
+            format!("{} versions", installed.len())
+        };
+        // Ex) "Upgraded Python 3.12.9 in 1.68s"
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Upgraded {} {}",
+                versions.bold(),
+                format!("in {}", elapsed(start.elapsed())).dimmed()
+            )
+            .dimmed()
+        )?;
+    }
+
+    let events = installed
+        .iter()
+        .map(|installation| ChangeEvent {
+            key: installation.key().clone(),
+            kind: ChangeEventKind::Added,
+        })
+        .chain(removed.iter().map(|installation| ChangeEvent {
+            key: installation.key().clone(),
+            kind: ChangeEventKind::Removed,
+        }))
+        .sorted_unstable_by(|a, b| a.key.cmp(&b.key).then_with(|| a.kind.cmp(&b.kind)));
+    for event in events {
+        match event.kind {
+            ChangeEventKind::Added => {
+                writeln!(printer.stderr(), " {} {}", "+".green(), event.key.bold())?;
+            }
+            ChangeEventKind::Removed => {
+                writeln!(printer.stderr(), " {} {}", "-".red(), event.key.bold())?;
+            }
+            ChangeEventKind::Reinstalled => {
+                writeln!(printer.stderr(), " {} {}", "~".yellow(), event.key.bold())?;
+            }
+        }
+    }
+
+    if !updated.is_empty() {
+        let s = if updated.len() == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "Updated {} to use the latest patch release",
+            format!("{} environment{s}", updated.len()).bold()
+        )?;
+    }
+
+    if !errors.is_empty() {
+        for (key, err) in errors
+            .into_iter()
+            .sorted_unstable_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b))
+        {
+            writeln!(
+                printer.stderr(),
+                "{}: Failed to upgrade {}",
+                "error".red().bold(),
+                key.green()
+            )?;
+            for err in err.chain() {
+                writeln!(
+                    printer.stderr(),
+                    "  {}: {}",
+                    "Caused by".red().bold(),
+                    err.to_string().trim()
+                )?;
+            }
+        }
+        return Ok(ExitStatus::Failure);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Returns `true` if `newer` supersedes `older`, i.e., it's a newer patch release of the same
+/// minor version for the same platform.
+fn supersedes(newer: &ManagedPythonInstallation, older: &ManagedPythonInstallation) -> bool {
+    newer.key().os() == older.key().os()
+        && newer.key().arch() == older.key().arch()
+        && newer.key().libc() == older.key().libc()
+        && newer.is_upgrade_of(older)
+}
+
+/// Returns the virtual environment of the project in the given directory, respecting
+/// `UV_PROJECT_ENVIRONMENT`, or the `.venv` in the directory if it isn't part of a project.
+async fn project_environment(project_dir: &Path) -> PathBuf {
+    match VirtualProject::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await
+    {
+        Ok(project) => project.workspace().venv(Some(false)),
+        Err(err) => {
+            debug!("Failed to discover a project: {err}");
+            project_dir.join(".venv")
+        }
+    }
+}
+
+/// A superseded installation that is the base interpreter of a virtual environment.
+struct SupersededBase<'a> {
+    /// The superseded installation.
+    installation: &'a ManagedPythonInstallation,
+    /// The newest installation for the same minor version.
+    target: &'a ManagedPythonInstallation,
+    /// The `home` of the environment, relative to the installation.
+    relative: PathBuf,
+}
+
+impl<'a> SupersededBase<'a> {
+    /// Find the superseded installation used by the virtual environment at the given path, if
+    /// any.
+    fn from_environment(
+        root: &Path,
+        superseded: &[(&'a ManagedPythonInstallation, &'a ManagedPythonInstallation)],
+    ) -> Result<Option<Self>> {
+        let cfg = root.join("pyvenv.cfg");
+        if !cfg.is_file() {
+            return Err(anyhow::anyhow!(
+                "`{}` is not a virtual environment",
+                root.user_display()
+            ));
+        }
+
+        let Some(home) = PyVenvConfiguration::parse(&cfg)?
+            .home()
+            .map(Path::to_path_buf)
+        else {
+            return Ok(None);
+        };
+
+        // Compare canonical paths, since the `home` may be written differently than the
+        // installation directory (e.g., with a different case on Windows).
+        let home = fs_err::canonicalize(&home).unwrap_or(home);
+        Ok(superseded.iter().find_map(|&(installation, target)| {
+            let path = fs_err::canonicalize(installation.path())
+                .unwrap_or_else(|_| installation.path().to_path_buf());
+            home.strip_prefix(&path).ok().map(|relative| Self {
+                installation,
+                target,
+                relative: relative.to_path_buf(),
+            })
+        }))
+    }
+
+    /// Update the virtual environment at the given path to use the newest installation.
+    fn retarget(&self, root: &Path) -> Result<()> {
+        let Self {
+            installation,
+            target,
+            relative,
+        } = self;
+
+        // Update the `home` (and version) in the `pyvenv.cfg`.
+        let cfg = root.join("pyvenv.cfg");
+        let home = if relative.as_os_str().is_empty() {
+            target.path().to_path_buf()
+        } else {
+            target.path().join(relative)
+        };
+        let content = fs_err::read_to_string(&cfg)?;
+        let content =
+            PyVenvConfiguration::set(&content, "home", &home.simplified_display().to_string());
+        let content = PyVenvConfiguration::set(
+            &content,
+            "version_info",
+            &target.key().version().to_string(),
+        );
+        fs_err::write(&cfg, content)?;
+
+        if cfg!(unix) {
+            // On Unix, the environment's `python` executable is a link to the base interpreter.
+            let executable = root.join("bin").join("python");
+            if let Ok(link) = executable.read_link() {
+                if let Ok(relative) = link.strip_prefix(installation.path()) {
+                    uv_fs::replace_symlink(target.path().join(relative), &executable)?;
+                }
+            }
+        } else if cfg!(windows) {
+            // On Windows, the environment's executables are copies of the base interpreter's
+            // launcher, which finds the base interpreter via the `home` in the `pyvenv.cfg`.
+            // Replace them with the launcher of the newest installation, in case it changed.
+            let launchers = target
+                .path()
+                .join("Lib")
+                .join("venv")
+                .join("scripts")
+                .join("nt");
+            let suffix = if target.key().variant().is_freethreaded() {
+                "t"
+            } else {
+                ""
+            };
+            let version = target.key().version();
+            let (major, minor) = (version.major(), version.minor());
+            for (executable, shim, launcher) in [
+                (
+                    "python.exe".to_string(),
+                    "python.exe",
+                    format!("venvlauncher{suffix}.exe"),
+                ),
+                (
+                    format!("python{major}.exe"),
+                    "python.exe",
+                    format!("venvlauncher{suffix}.exe"),
+                ),
+                (
+                    format!("python{major}.{minor}.exe"),
+                    "python.exe",
+                    format!("venvlauncher{suffix}.exe"),
+                ),
+                (
+                    "pythonw.exe".to_string(),
+                    "pythonw.exe",
+                    format!("venvwlauncher{suffix}.exe"),
+                ),
+            ] {
+                let path = root.join("Scripts").join(executable);
+                if !path.is_file() {
+                    continue;
+                }
+                if let Some(source) = [launchers.join(shim), launchers.join(launcher)]
+                    .into_iter()
+                    .find(|source| source.is_file())
+                {
+                    fs_err::copy(source, &path)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
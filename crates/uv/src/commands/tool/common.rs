@@ -1,12 +1,14 @@
 use anyhow::{bail, Context};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
-use std::collections::Bound;
+use std::collections::{BTreeMap, Bound};
 use std::fmt::Write;
+use std::sync::Mutex;
 use std::{collections::BTreeSet, ffi::OsString};
 use tracing::{debug, warn};
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
+use uv_configuration::PreviewMode;
 use uv_distribution_types::Requirement;
 use uv_distribution_types::{InstalledDist, Name};
 #[cfg(unix)]
@@ -19,6 +21,7 @@ use uv_python::{
     EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVariant, VersionRequest,
 };
+use uv_resolver::PylockToml;
 use uv_settings::{PythonInstallMirrors, ToolOptions};
 use uv_shell::Shell;
 use uv_tool::{entrypoint_paths, tool_executable_dir, InstalledTools, Tool, ToolEntrypoint};
@@ -51,6 +54,63 @@ pub(super) fn matching_packages(name: &str, site_packages: &SitePackages) -> Vec
         .collect()
 }
 
+/// The executables claimed by a set of tools that are installed together, e.g., from a manifest.
+///
+/// Used to detect tools that provide the same executable, which would otherwise overwrite one
+/// another.
+#[derive(Debug, Default)]
+pub(crate) struct ClaimedExecutables(Mutex<BTreeMap<String, PackageName>>);
+
+impl ClaimedExecutables {
+    /// Claim the given executables for a tool, failing if another tool already claimed any of them.
+    fn claim<'a>(
+        &self,
+        tool: &PackageName,
+        executables: impl IntoIterator<Item = &'a str>,
+    ) -> anyhow::Result<()> {
+        let mut claimed = self.0.lock().unwrap();
+        let executables = executables.into_iter().collect::<Vec<_>>();
+        for executable in &executables {
+            if let Some(existing) = claimed
+                .get(*executable)
+                .filter(|existing| *existing != tool)
+            {
+                bail!(
+                    "The tools `{}` and `{}` both provide the executable `{}`",
+                    existing.cyan(),
+                    tool.cyan(),
+                    executable.bold()
+                );
+            }
+        }
+        for executable in executables {
+            claimed.insert(executable.to_string(), tool.clone());
+        }
+        Ok(())
+    }
+}
+
+/// Render the lock to record in a tool receipt, if any.
+///
+/// Recording the resolution in the receipt is experimental, so the lock is omitted unless preview
+/// mode is enabled.
+pub(crate) fn receipt_lock(
+    lock: Option<&PylockToml>,
+    preview: PreviewMode,
+) -> anyhow::Result<Option<toml::Table>> {
+    if preview.is_disabled() {
+        return Ok(None);
+    }
+    let Some(lock) = lock else {
+        return Ok(None);
+    };
+    let lock = lock
+        .to_toml()
+        .context("Failed to serialize the lock for the tool receipt")?;
+    let lock = toml::from_str(&lock).context("Failed to parse the lock for the tool receipt")?;
+    Ok(Some(lock))
+}
+
 /// Remove any entrypoints attached to the [`Tool`].
 pub(crate) fn remove_entrypoints(tool: &Tool) {
     for executable in tool
@@ -170,6 +230,8 @@ pub(crate) fn install_executables(
     constraints: Vec<Requirement>,
     overrides: Vec<Requirement>,
     build_constraints: Vec<Requirement>,
+    lock: Option<toml::Table>,
+    claimed: Option<&ClaimedExecutables>,
     printer: Printer,
 ) -> anyhow::Result<ExitStatus> {
     let site_packages = SitePackages::from_environment(environment)?;
@@ -223,6 +285,21 @@ pub(crate) fn install_executables(
         return Ok(ExitStatus::Failure);
     }
 
+    // Error if another tool installed alongside this one provides the same executable, regardless
+    // of `--force`.
+    if let Some(claimed) = claimed {
+        if let Err(err) = claimed.claim(
+            name,
+            target_entry_points
+                .iter()
+                .map(|(entry_point, _, _)| entry_point.as_str()),
+        ) {
+            // Clean up the environment we just created
+            installed_tools.remove_environment(name)?;
+            return Err(err);
+        }
+    }
+
     // Error if we're overwriting an existing entrypoint, unless the user passed `--force`.
     if !force {
         let mut existing_entry_points = target_entry_points
@@ -297,7 +374,8 @@ pub(crate) fn install_executables(
             .into_iter()
             .map(|(name, _, target_path)| ToolEntrypoint::new(name, target_path)),
         options,
-    );
+    )
+    .with_lock(lock);
     installed_tools.add_tool_receipt(name, tool)?;
 
     // If the executable directory isn't on the user's PATH, warn.
@@ -0,0 +1,173 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_configuration::{Concurrency, PreviewMode};
+use uv_normalize::PackageName;
+use uv_python::{PythonDownloads, PythonPreference};
+use uv_settings::{PythonInstallMirrors, ResolverInstallerOptions};
+use uv_tool::{InstalledTools, ToolIssue};
+use uv_warnings::warn_user;
+
+use crate::commands::tool::install::install_tools;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::NetworkSettings;
+
+/// Check installed tools for problems, optionally repairing them by reinstalling the affected
+/// tools from their receipts.
+pub(crate) async fn doctor(
+    names: Vec<PackageName>,
+    fix: bool,
+    options: ResolverInstallerOptions,
+    install_mirrors: PythonInstallMirrors,
+    network_settings: NetworkSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    cache: Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    let installed_tools = InstalledTools::from_settings()?;
+    let _lock = match installed_tools.lock().await {
+        Ok(lock) => lock,
+        Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+            writeln!(printer.stderr(), "No tools installed")?;
+            return Ok(ExitStatus::Success);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let tools = installed_tools
+        .tools()?
+        .into_iter()
+        .filter(|(name, _)| names.is_empty() || names.contains(name))
+        .sorted_unstable_by(|(name_a, _), (name_b, _)| name_a.cmp(name_b))
+        .collect::<Vec<_>>();
+
+    let mut status = ExitStatus::Success;
+
+    for name in &names {
+        if !tools.iter().any(|(installed, _)| installed == name) {
+            writeln!(printer.stderr(), "`{}` is not installed", name.cyan())?;
+            status = ExitStatus::Failure;
+        }
+    }
+
+    if tools.is_empty() {
+        if names.is_empty() {
+            writeln!(printer.stderr(), "No tools installed")?;
+        }
+        return Ok(status);
+    }
+
+    let mut num_issues = 0;
+    let mut num_tools = 0;
+    let mut fixable = Vec::new();
+
+    for (name, tool) in tools {
+        // Skip invalid tools
+        let Ok(tool) = tool else {
+            warn_user!(
+                "Ignoring malformed tool `{name}` (run `{}` to remove)",
+                format!("uv tool uninstall {name}").green()
+            );
+            continue;
+        };
+
+        let issues = installed_tools.check(&name, &tool, &cache)?;
+        if issues.is_empty() {
+            continue;
+        }
+
+        writeln!(printer.stdout(), "{}", name.bold())?;
+        for issue in &issues {
+            writeln!(printer.stdout(), "- {issue}")?;
+        }
+
+        num_issues += issues.len();
+        num_tools += 1;
+
+        // Reinstalling a tool overwrites its executables, so avoid repairing tools whose
+        // executables are owned by another tool.
+        if !issues.iter().any(ToolIssue::is_fixable) {
+            continue;
+        }
+        if !issues.iter().any(ToolIssue::blocks_repair) {
+            fixable.push((name, tool));
+        } else {
+            warn_user!(
+                "Unable to repair `{name}` automatically, since its executables conflict with another tool (run `{}` to reinstall)",
+                format!("uv tool install {name} --force").green()
+            );
+        }
+    }
+
+    if num_issues == 0 {
+        writeln!(printer.stderr(), "No issues found")?;
+        return Ok(status);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Found {} in {}",
+        format!(
+            "{num_issues} issue{}",
+            if num_issues == 1 { "" } else { "s" }
+        )
+        .bold(),
+        format!("{num_tools} tool{}", if num_tools == 1 { "" } else { "s" }).bold(),
+    )?;
+
+    if !fix {
+        if !fixable.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "Run `{}` to reinstall {}",
+                "uv tool doctor --fix".green(),
+                fixable.iter().map(|(name, _)| name.cyan()).join(", ")
+            )?;
+        }
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Any tools that can't be repaired remain broken.
+    if fixable.len() < num_tools {
+        status = ExitStatus::Failure;
+    }
+
+    if fixable.is_empty() {
+        return Ok(status);
+    }
+
+    let installed_tools = installed_tools.init()?;
+    let result = Box::pin(install_tools(
+        fixable,
+        &installed_tools,
+        &install_mirrors,
+        true,
+        false,
+        false,
+        &options,
+        &network_settings,
+        python_preference,
+        python_downloads,
+        installer_metadata,
+        concurrency,
+        &cache,
+        printer,
+        preview,
+    ))
+    .await?;
+
+    if matches!(result, ExitStatus::Success) {
+        Ok(status)
+    } else {
+        Ok(result)
+    }
+}
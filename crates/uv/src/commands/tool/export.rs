@@ -0,0 +1,48 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_tool::{InstalledTools, ToolManifest};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Export the installed tools to a manifest.
+pub(crate) async fn export(no_lock: bool, printer: Printer) -> Result<ExitStatus> {
+    let installed_tools = InstalledTools::from_settings()?;
+    let _lock = match installed_tools.lock().await {
+        Ok(lock) => lock,
+        Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+            writeln!(printer.stderr(), "No tools installed")?;
+            return Ok(ExitStatus::Success);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut tools = Vec::new();
+    for (name, tool) in installed_tools.tools()? {
+        // Skip invalid tools
+        let Ok(tool) = tool else {
+            warn_user!(
+                "Ignoring malformed tool `{name}` (run `{}` to remove)",
+                format!("uv tool uninstall {name}").green()
+            );
+            continue;
+        };
+
+        let tool = if no_lock { tool.with_lock(None) } else { tool };
+        tools.push((name, tool));
+    }
+
+    if tools.is_empty() {
+        writeln!(printer.stderr(), "No tools installed")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let manifest = ToolManifest::from_tools(tools);
+    write!(printer.stdout(), "{}", manifest.to_toml()?)?;
+
+    Ok(ExitStatus::Success)
+}
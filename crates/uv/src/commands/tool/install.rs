@@ -1,8 +1,11 @@
 use std::collections::BTreeMap;
 use std::fmt::Write;
+use std::path::Path;
 use std::str::FromStr;
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
+use futures::StreamExt;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use tracing::{debug, trace};
 
@@ -18,11 +21,13 @@ use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
 use uv_pep508::MarkerTree;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
+    PythonRequest,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
-use uv_settings::{PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
-use uv_tool::InstalledTools;
+use uv_resolver::PylockToml;
+use uv_settings::{Combine, PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
+use uv_tool::{InstalledTools, Tool, ToolManifest};
 use uv_warnings::warn_user;
 use uv_workspace::WorkspaceCache;
 
@@ -32,7 +37,9 @@ use crate::commands::project::{
     resolve_environment, resolve_names, sync_environment, update_environment,
     EnvironmentSpecification, PlatformState, ProjectError,
 };
-use crate::commands::tool::common::{install_executables, refine_interpreter, remove_entrypoints};
+use crate::commands::tool::common::{
+    install_executables, receipt_lock, refine_interpreter, remove_entrypoints, ClaimedExecutables,
+};
 use crate::commands::tool::{Target, ToolRequest};
 use crate::commands::ExitStatus;
 use crate::commands::{diagnostics, reporters::PythonDownloadReporter};
@@ -52,6 +59,8 @@ pub(crate) async fn install(
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     force: bool,
+    locked: bool,
+    frozen: bool,
     options: ResolverInstallerOptions,
     settings: ResolverInstallerSettings,
     network_settings: NetworkSettings,
@@ -63,6 +72,15 @@ pub(crate) async fn install(
     printer: Printer,
     preview: PreviewMode,
 ) -> Result<ExitStatus> {
+    if (locked || frozen) && preview.is_disabled() {
+        let flag = if locked { "--locked" } else { "--frozen" };
+        writeln!(
+            printer.stderr(),
+            "The `{flag}` option is only available in preview mode; add the `--preview` flag to use `{flag}`"
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
     let client_builder = BaseClientBuilder::new()
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
@@ -273,13 +291,13 @@ pub(crate) async fn install(
     // Resolve the constraints.
     let constraints = spec
         .constraints
-        .into_iter()
-        .map(|constraint| constraint.requirement)
+        .iter()
+        .map(|constraint| constraint.requirement.clone())
         .collect::<Vec<_>>();
 
     // Resolve the overrides.
     let overrides = resolve_names(
-        spec.overrides,
+        spec.overrides.clone(),
         &interpreter,
         &settings,
         &network_settings,
@@ -306,6 +324,80 @@ pub(crate) async fn install(
     let installed_tools = InstalledTools::from_settings()?.init()?;
     let _lock = installed_tools.lock().await?;
 
+    Box::pin(install_tool(
+        from,
+        requirements,
+        constraints,
+        overrides,
+        build_constraints,
+        spec,
+        python,
+        python_request.as_ref(),
+        interpreter,
+        request.is_latest(),
+        &installed_tools,
+        options,
+        settings,
+        force,
+        locked,
+        frozen,
+        None,
+        None,
+        &install_mirrors,
+        &network_settings,
+        &state,
+        workspace_cache,
+        python_preference,
+        python_downloads,
+        installer_metadata,
+        concurrency,
+        &cache,
+        printer,
+        preview,
+    ))
+    .await
+}
+
+/// Install a tool from the given requirements, the first of which is the tool itself.
+#[allow(clippy::fn_params_excessive_bools)]
+async fn install_tool(
+    from: Requirement,
+    requirements: Vec<Requirement>,
+    constraints: Vec<Requirement>,
+    overrides: Vec<Requirement>,
+    build_constraints: Vec<Requirement>,
+    spec: RequirementsSpecification,
+    python: Option<String>,
+    python_request: Option<&PythonRequest>,
+    interpreter: Interpreter,
+    latest: bool,
+    installed_tools: &InstalledTools,
+    options: ToolOptions,
+    settings: ResolverInstallerSettings,
+    force: bool,
+    locked: bool,
+    frozen: bool,
+    locked_tool: Option<Tool>,
+    claimed: Option<&ClaimedExecutables>,
+    install_mirrors: &PythonInstallMirrors,
+    network_settings: &NetworkSettings,
+    state: &PlatformState,
+    workspace_cache: WorkspaceCache,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+
+    let reporter = PythonDownloadReporter::single(printer);
+
     // Find the existing receipt, if it exists. If the receipt is present but malformed, we'll
     // remove the environment and continue with the install.
     //
@@ -338,7 +430,7 @@ pub(crate) async fn install(
 
     let existing_environment =
         installed_tools
-            .get_environment(&from.name, &cache)?
+            .get_environment(&from.name, cache)?
             .filter(|environment| {
                 if environment.uses(&interpreter) {
                     trace!(
@@ -357,14 +449,177 @@ pub(crate) async fn install(
                 }
             });
 
+    // Create a `RequirementsSpecification` from the resolved requirements, to avoid re-resolving.
+    let spec = RequirementsSpecification {
+        requirements: requirements
+            .iter()
+            .cloned()
+            .map(UnresolvedRequirementSpecification::from)
+            .collect(),
+        constraints: constraints
+            .iter()
+            .cloned()
+            .map(NameRequirementSpecification::from)
+            .collect(),
+        overrides: overrides
+            .iter()
+            .cloned()
+            .map(UnresolvedRequirementSpecification::from)
+            .collect(),
+        ..spec
+    };
+
+    // If the user passed `--locked` or `--frozen`, install the tool from the lock in the provided
+    // tool entry (e.g., from a manifest) or its existing receipt, without re-resolving.
+    if locked || frozen {
+        let flag = if locked { "--locked" } else { "--frozen" };
+        let Some(locked_tool) = locked_tool.or_else(|| existing_tool_receipt.clone()) else {
+            bail!(
+                "`{}` is not installed, but `{}` requires a lock in an existing tool receipt",
+                from.name.cyan(),
+                flag.green()
+            );
+        };
+        let Some(lock) = locked_tool.lock().cloned() else {
+            bail!(
+                "The receipt for `{}` does not include a lock, but `{}` was provided. To record a lock, run `{}`.",
+                from.name.cyan(),
+                flag.green(),
+                format!("uv tool upgrade --preview {}", from.name).green()
+            );
+        };
+
+        // With `--locked`, the lock is only up-to-date if it was created from the same requirements.
+        if locked
+            && (requirements != locked_tool.requirements()
+                || constraints != locked_tool.constraints()
+                || overrides != locked_tool.overrides()
+                || build_constraints != locked_tool.build_constraints())
+        {
+            bail!(
+                "The lock for `{}` needs to be updated, but `{}` was provided. To update the lock, run `{}`.",
+                from.name.cyan(),
+                flag.green(),
+                format!("uv tool install --preview {}", from.name).green()
+            );
+        }
+
+        // With `--locked`, the lock must also match a fresh resolution of the requirements, such
+        // that a lock with stale pins is rejected.
+        if locked {
+            let resolution = match resolve_environment(
+                EnvironmentSpecification::from(spec),
+                &interpreter,
+                &settings.resolver,
+                network_settings,
+                state,
+                Box::new(DefaultResolveLogger),
+                concurrency,
+                cache,
+                printer,
+                preview,
+            )
+            .await
+            {
+                Ok(resolution) => resolution,
+                Err(ProjectError::Operation(err)) => {
+                    return diagnostics::OperationDiagnostic::native_tls(
+                        network_settings.native_tls,
+                    )
+                    .report(err)
+                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                }
+                Err(err) => return Err(err.into()),
+            };
+            let resolved = PylockToml::from_resolution(
+                &resolution,
+                &[],
+                &installed_tools.tool_dir(&from.name),
+            )
+            .with_context(|| format!("Failed to convert the resolution for `{}`", from.name))?;
+            if receipt_lock(Some(&resolved), preview)?.as_ref() != Some(&lock) {
+                bail!(
+                    "The lock for `{}` needs to be updated, but `{}` was provided. To update the lock, run `{}`.",
+                    from.name.cyan(),
+                    flag.green(),
+                    format!("uv tool upgrade --preview {}", from.name).green()
+                );
+            }
+        }
+
+        let environment = if let Some(environment) = existing_environment {
+            environment
+        } else {
+            installed_tools.create_environment(&from.name, interpreter)?
+        };
+
+        // Convert the lock to a resolution for the tool environment.
+        let resolution = toml::Value::Table(lock.clone())
+            .try_into::<PylockToml>()
+            .with_context(|| format!("Failed to parse the lock for `{}`", from.name))?
+            .to_resolution(
+                environment.root(),
+                environment.interpreter().markers(),
+                environment.interpreter().tags()?,
+                &settings.resolver.build_options,
+            )?;
+
+        // At this point, we're going to modify the environment, so we should remove any of its
+        // existing executables.
+        if let Some(existing_receipt) = existing_tool_receipt {
+            remove_entrypoints(&existing_receipt);
+        }
+
+        let environment = match sync_environment(
+            environment,
+            &resolution,
+            Modifications::Exact,
+            Constraints::from_requirements(locked_tool.build_constraints().iter().cloned()),
+            (&settings).into(),
+            network_settings,
+            state,
+            Box::new(DefaultInstallLogger),
+            installer_metadata,
+            concurrency,
+            cache,
+            printer,
+            preview,
+        )
+        .await
+        {
+            Ok(environment) => environment,
+            Err(ProjectError::Operation(err)) => {
+                return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                    .report(err)
+                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        // Retain the requirements and lock from the locked tool entry.
+        return install_executables(
+            &environment,
+            &from.name,
+            installed_tools,
+            options,
+            force,
+            locked_tool.python().clone(),
+            locked_tool.requirements().to_vec(),
+            locked_tool.constraints().to_vec(),
+            locked_tool.overrides().to_vec(),
+            locked_tool.build_constraints().to_vec(),
+            Some(lock),
+            claimed,
+            printer,
+        );
+    }
+
     // If the requested and receipt requirements are the same...
     if existing_environment
         .as_ref()
         .filter(|_| {
             // And the user didn't request a reinstall or upgrade...
-            !request.is_latest()
-                && settings.reinstall.is_none()
-                && settings.resolver.upgrade.is_none()
+            !latest && settings.reinstall.is_none() && settings.resolver.upgrade.is_none()
         })
         .is_some()
     {
@@ -392,44 +647,24 @@ pub(crate) async fn install(
         }
     }
 
-    // Create a `RequirementsSpecification` from the resolved requirements, to avoid re-resolving.
-    let spec = RequirementsSpecification {
-        requirements: requirements
-            .iter()
-            .cloned()
-            .map(UnresolvedRequirementSpecification::from)
-            .collect(),
-        constraints: constraints
-            .iter()
-            .cloned()
-            .map(NameRequirementSpecification::from)
-            .collect(),
-        overrides: overrides
-            .iter()
-            .cloned()
-            .map(UnresolvedRequirementSpecification::from)
-            .collect(),
-        ..spec
-    };
-
     // TODO(zanieb): Build the environment in the cache directory then copy into the tool directory.
     // This lets us confirm the environment is valid before removing an existing install. However,
     // entrypoints always contain an absolute path to the relevant Python interpreter, which would
     // be invalidated by moving the environment.
-    let environment = if let Some(environment) = existing_environment {
-        let environment = match update_environment(
+    let (environment, lock) = if let Some(environment) = existing_environment {
+        let update = match update_environment(
             environment,
             spec,
             Modifications::Exact,
             Constraints::from_requirements(build_constraints.iter().cloned()),
             &settings,
-            &network_settings,
-            &state,
+            network_settings,
+            state,
             Box::new(DefaultResolveLogger),
             Box::new(DefaultInstallLogger),
             installer_metadata,
             concurrency,
-            &cache,
+            cache,
             workspace_cache,
             DryRun::Disabled,
             printer,
@@ -437,7 +672,7 @@ pub(crate) async fn install(
         )
         .await
         {
-            Ok(update) => update.into_environment(),
+            Ok(update) => update,
             Err(ProjectError::Operation(err)) => {
                 return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                     .report(err)
@@ -452,7 +687,7 @@ pub(crate) async fn install(
             remove_entrypoints(&existing_receipt);
         }
 
-        environment
+        (update.environment, update.lock)
     } else {
         let spec = EnvironmentSpecification::from(spec);
 
@@ -462,11 +697,11 @@ pub(crate) async fn install(
             spec.clone(),
             &interpreter,
             &settings.resolver,
-            &network_settings,
-            &state,
+            network_settings,
+            state,
             Box::new(DefaultResolveLogger),
             concurrency,
-            &cache,
+            cache,
             printer,
             preview,
         )
@@ -485,14 +720,14 @@ pub(crate) async fn install(
                     // Python 3.10 interpreter and re-resolve.
                     let Some(interpreter) = refine_interpreter(
                         &interpreter,
-                        python_request.as_ref(),
+                        python_request,
                         &err,
                         &client_builder,
                         &reporter,
-                        &install_mirrors,
+                        install_mirrors,
                         python_preference,
                         python_downloads,
-                        &cache,
+                        cache,
                     )
                     .await
                     .ok()
@@ -514,11 +749,11 @@ pub(crate) async fn install(
                         spec,
                         &interpreter,
                         &settings.resolver,
-                        &network_settings,
-                        &state,
+                        network_settings,
+                        state,
                         Box::new(DefaultResolveLogger),
                         concurrency,
-                        &cache,
+                        cache,
                         printer,
                         preview,
                     )
@@ -541,6 +776,11 @@ pub(crate) async fn install(
 
         let environment = installed_tools.create_environment(&from.name, interpreter)?;
 
+        // Capture the resolution in `pylock.toml` format, to record it in the tool receipt.
+        let lock = PylockToml::from_resolution(&resolution, &[], environment.root())
+            .inspect_err(|err| debug!("Failed to convert resolution to `pylock.toml`: {err}"))
+            .ok();
+
         // At this point, we removed any existing environment, so we should remove any of its
         // executables.
         if let Some(existing_receipt) = existing_tool_receipt {
@@ -548,18 +788,18 @@ pub(crate) async fn install(
         }
 
         // Sync the environment with the resolved requirements.
-        match sync_environment(
+        let environment = match sync_environment(
             environment,
             &resolution.into(),
             Modifications::Exact,
             Constraints::from_requirements(build_constraints.iter().cloned()),
             (&settings).into(),
-            &network_settings,
-            &state,
+            network_settings,
+            state,
             Box::new(DefaultInstallLogger),
             installer_metadata,
             concurrency,
-            &cache,
+            cache,
             printer,
             preview,
         )
@@ -576,13 +816,15 @@ pub(crate) async fn install(
                     .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
             }
             Err(err) => return Err(err.into()),
-        }
+        };
+
+        (environment, lock)
     };
 
     install_executables(
         &environment,
         &from.name,
-        &installed_tools,
+        installed_tools,
         options,
         force || invalid_tool_receipt,
         python,
@@ -590,6 +832,294 @@ pub(crate) async fn install(
         constraints,
         overrides,
         build_constraints,
+        receipt_lock(lock.as_ref(), preview)?,
+        claimed,
         printer,
     )
 }
+
+/// Install the tools listed in a manifest, e.g., as produced by `uv tool export`.
+///
+/// Tools are installed concurrently. Tools that are already installed with the same requirements
+/// are left unchanged.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn install_manifest(
+    manifest: &Path,
+    install_mirrors: PythonInstallMirrors,
+    force: bool,
+    locked: bool,
+    frozen: bool,
+    options: ResolverInstallerOptions,
+    network_settings: NetworkSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    cache: Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    if (locked || frozen) && preview.is_disabled() {
+        let flag = if locked { "--locked" } else { "--frozen" };
+        writeln!(
+            printer.stderr(),
+            "The `{flag}` option is only available in preview mode; add the `--preview` flag to use `{flag}`"
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    let manifest = ToolManifest::from_path(manifest)?;
+    if manifest.is_empty() {
+        writeln!(printer.stderr(), "No tools to install")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let installed_tools = InstalledTools::from_settings()?.init()?;
+    let _lock = installed_tools.lock().await?;
+
+    install_tools(
+        manifest.into_tools(),
+        &installed_tools,
+        &install_mirrors,
+        force,
+        locked,
+        frozen,
+        &options,
+        &network_settings,
+        python_preference,
+        python_downloads,
+        installer_metadata,
+        concurrency,
+        &cache,
+        printer,
+        preview,
+    )
+    .await
+}
+
+/// Install the given tools in parallel, as described by their [`Tool`] entries (e.g., from a
+/// manifest or from existing receipts), reporting any failures.
+///
+/// Note it is generally incorrect to use this without acquiring the lock on the tools directory.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(super) async fn install_tools(
+    tools: impl IntoIterator<Item = (PackageName, Tool)>,
+    installed_tools: &InstalledTools,
+    install_mirrors: &PythonInstallMirrors,
+    force: bool,
+    locked: bool,
+    frozen: bool,
+    options: &ResolverInstallerOptions,
+    network_settings: &NetworkSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    // Tools are installed concurrently, so track the executables provided by each tool to reject
+    // tools that would overwrite one another's executables.
+    let claimed = &ClaimedExecutables::default();
+
+    let results = futures::stream::iter(tools)
+        .map(|(name, tool)| async move {
+            let result = Box::pin(install_manifest_tool(
+                &name,
+                tool,
+                installed_tools,
+                claimed,
+                install_mirrors,
+                force,
+                locked,
+                frozen,
+                options,
+                network_settings,
+                python_preference,
+                python_downloads,
+                installer_metadata,
+                concurrency,
+                cache,
+                printer,
+                preview,
+            ))
+            .await;
+            (name, result)
+        })
+        .buffer_unordered(concurrency.installs)
+        .collect::<Vec<_>>()
+        .await;
+
+    let mut status = ExitStatus::Success;
+    for (name, result) in results
+        .into_iter()
+        .sorted_unstable_by(|(name_a, _), (name_b, _)| name_a.cmp(name_b))
+    {
+        match result {
+            Ok(ExitStatus::Success) => {}
+            Ok(_) => status = ExitStatus::Failure,
+            Err(err) => {
+                status = ExitStatus::Failure;
+                writeln!(
+                    printer.stderr(),
+                    "{}: Failed to install {}",
+                    "error".red().bold(),
+                    name.green()
+                )?;
+                for err in err.chain() {
+                    writeln!(
+                        printer.stderr(),
+                        "  {}: {}",
+                        "Caused by".red().bold(),
+                        err.to_string().trim()
+                    )?;
+                }
+            }
+        }
+    }
+
+    Ok(status)
+}
+
+/// Install a single tool from a manifest.
+#[allow(clippy::fn_params_excessive_bools)]
+async fn install_manifest_tool(
+    name: &PackageName,
+    tool: Tool,
+    installed_tools: &InstalledTools,
+    claimed: &ClaimedExecutables,
+    install_mirrors: &PythonInstallMirrors,
+    force: bool,
+    locked: bool,
+    frozen: bool,
+    options: &ResolverInstallerOptions,
+    network_settings: &NetworkSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    let Some(from) = tool
+        .requirements()
+        .iter()
+        .find(|requirement| requirement.name == *name)
+        .cloned()
+    else {
+        bail!(
+            "The manifest entry for `{}` does not include a requirement on `{}`",
+            name.cyan(),
+            name.cyan()
+        );
+    };
+
+    if (locked || frozen) && tool.lock().is_none() {
+        bail!(
+            "The manifest entry for `{}` does not include a lock, but `{}` was provided",
+            name.cyan(),
+            if locked { "--locked" } else { "--frozen" }.green()
+        );
+    }
+
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+
+    let reporter = PythonDownloadReporter::single(printer);
+
+    // Find the Python interpreter requested for the tool, if any.
+    let python_request = tool.python().as_deref().map(PythonRequest::parse);
+    let interpreter = PythonInstallation::find_or_download(
+        python_request.as_ref(),
+        EnvironmentPreference::OnlySystem,
+        python_preference,
+        python_downloads,
+        &client_builder,
+        cache,
+        Some(&reporter),
+        install_mirrors.python_install_mirror.as_deref(),
+        install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_downloads_json_url.as_deref(),
+    )
+    .await?
+    .into_interpreter();
+
+    // Resolve the settings, preferring: CLI and user configuration > manifest.
+    let options = options
+        .clone()
+        .combine(ResolverInstallerOptions::from(tool.options().clone()));
+    let settings = ResolverInstallerSettings::from(options.clone());
+
+    // If the user passed `--force`, it implies `--reinstall-package <from>`
+    let settings = if force {
+        ResolverInstallerSettings {
+            reinstall: settings
+                .reinstall
+                .combine(Reinstall::package(from.name.clone())),
+            ..settings
+        }
+    } else {
+        settings
+    };
+
+    let spec = RequirementsSpecification {
+        requirements: tool
+            .requirements()
+            .iter()
+            .cloned()
+            .map(UnresolvedRequirementSpecification::from)
+            .collect(),
+        constraints: tool
+            .constraints()
+            .iter()
+            .cloned()
+            .map(NameRequirementSpecification::from)
+            .collect(),
+        overrides: tool
+            .overrides()
+            .iter()
+            .cloned()
+            .map(UnresolvedRequirementSpecification::from)
+            .collect(),
+        ..RequirementsSpecification::default()
+    };
+
+    let state = PlatformState::default();
+
+    install_tool(
+        from,
+        tool.requirements().to_vec(),
+        tool.constraints().to_vec(),
+        tool.overrides().to_vec(),
+        tool.build_constraints().to_vec(),
+        spec,
+        tool.python().clone(),
+        python_request.as_ref(),
+        interpreter,
+        false,
+        installed_tools,
+        ToolOptions::from(options),
+        settings,
+        force,
+        locked,
+        frozen,
+        (locked || frozen).then_some(tool),
+        Some(claimed),
+        install_mirrors,
+        network_settings,
+        &state,
+        WorkspaceCache::default(),
+        python_preference,
+        python_downloads,
+        installer_metadata,
+        concurrency,
+        cache,
+        printer,
+        preview,
+    )
+    .await
+}
@@ -16,6 +16,7 @@ use uv_python::{
     PythonRequest,
 };
 use uv_requirements::RequirementsSpecification;
+use uv_resolver::PylockToml;
 use uv_settings::{Combine, PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
 use uv_tool::InstalledTools;
 use uv_workspace::WorkspaceCache;
@@ -28,7 +29,7 @@ use crate::commands::project::{
     resolve_environment, sync_environment, update_environment, EnvironmentUpdate, PlatformState,
 };
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::commands::tool::common::remove_entrypoints;
+use crate::commands::tool::common::{receipt_lock, remove_entrypoints};
 use crate::commands::{conjunction, tool::common::install_executables, ExitStatus};
 use crate::printer::Printer;
 use crate::settings::{NetworkSettings, ResolverInstallerSettings};
@@ -290,7 +291,7 @@ async fn upgrade_tool(
 
     // Check if we need to create a new environment — if so, resolve it first, then
     // install the requested tool
-    let (environment, outcome) = if let Some(interpreter) =
+    let (environment, outcome, lock) = if let Some(interpreter) =
         interpreter.filter(|interpreter| !environment.uses(interpreter))
     {
         // If we're using a new interpreter, re-create the environment for each tool.
@@ -310,6 +311,11 @@ async fn upgrade_tool(
 
         let environment = installed_tools.create_environment(name, interpreter.clone())?;
 
+        // Capture the resolution in `pylock.toml` format, to record it in the tool receipt.
+        let lock = PylockToml::from_resolution(&resolution, &[], environment.root())
+            .inspect_err(|err| debug!("Failed to convert resolution to `pylock.toml`: {err}"))
+            .ok();
+
         let environment = sync_environment(
             environment,
             &resolution.into(),
@@ -327,7 +333,7 @@ async fn upgrade_tool(
         )
         .await?;
 
-        (environment, UpgradeOutcome::UpgradeEnvironment)
+        (environment, UpgradeOutcome::UpgradeEnvironment, lock)
     } else {
         // Otherwise, upgrade the existing environment.
         // TODO(zanieb): Build the environment in the cache directory then copy into the tool
@@ -335,6 +341,7 @@ async fn upgrade_tool(
         let EnvironmentUpdate {
             environment,
             changelog,
+            lock,
         } = update_environment(
             environment,
             spec,
@@ -363,9 +370,11 @@ async fn upgrade_tool(
             UpgradeOutcome::UpgradeDependencies
         };
 
-        (environment, outcome)
+        (environment, outcome, lock)
     };
 
+    let lock = receipt_lock(lock.as_ref(), preview)?;
+
     if matches!(
         outcome,
         UpgradeOutcome::UpgradeEnvironment | UpgradeOutcome::UpgradeTool
@@ -386,8 +395,22 @@ async fn upgrade_tool(
             existing_tool_receipt.constraints().to_vec(),
             existing_tool_receipt.overrides().to_vec(),
             existing_tool_receipt.build_constraints().to_vec(),
+            lock,
+            None,
             printer,
         )?;
+    } else {
+        // Otherwise, update the lock in the receipt. If we aren't recording a lock, drop any
+        // existing lock, unless the environment is unchanged.
+        let lock = if lock.is_none() && matches!(outcome, UpgradeOutcome::NoOp) {
+            existing_tool_receipt.lock().cloned()
+        } else {
+            lock
+        };
+        if lock.as_ref() != existing_tool_receipt.lock() {
+            debug!("Updating lock in receipt for tool `{name}`");
+            installed_tools.add_tool_receipt(name, existing_tool_receipt.with_lock(lock))?;
+        }
     }
 
     Ok(outcome)
@@ -298,6 +298,7 @@ async fn venv_impl(
             .cache(cache.clone())
             .index_locations(index_locations)
             .index_strategy(index_strategy)
+            .preview(preview)
             .keyring(keyring_provider)
             .allow_insecure_host(network_settings.allow_insecure_host.clone())
             .markers(interpreter.markers())
@@ -26,10 +26,13 @@ use uv_cache_info::Timestamp;
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
     compat::CompatArgs, BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands,
-    PipCommand, PipNamespace, ProjectCommand, PythonCommand, PythonNamespace, SelfCommand,
-    SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs, VersionArgs,
+    IndexCommand, IndexNamespace, PipCommand, PipNamespace, ProjectCommand, PythonCommand,
+    PythonNamespace, PythonShimCommand, PythonShimNamespace, SelfCommand, SelfNamespace,
+    ToolCommand, ToolNamespace, TopLevelArgs, VersionArgs,
 };
+use uv_client::HostTls;
 use uv_configuration::min_stack_size;
+use uv_distribution_types::IndexUrl;
 use uv_fs::{Simplified, CWD};
 #[cfg(feature = "self-update")]
 use uv_pep440::release_specifiers_to_ranges;
@@ -413,7 +416,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     show_settings!(cache_settings, false);
 
     // Configure the cache.
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_fallbacks(cache_settings.fallback_cache_dirs);
 
     let result = match *cli.command {
         Commands::Help(args) => commands::help(
@@ -920,7 +924,53 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             command: CacheCommand::Prune(args),
         }) => {
             show_settings!(args);
-            commands::cache_prune(args.ci, &cache, printer)
+            commands::cache_prune(args.ci, args.max_size, args.unused_for, &cache, printer)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Stats(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_stats(args.top, args.output_format, &cache, printer)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Verify(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_verify(args.dry_run, &cache, printer)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Export(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::CacheExportSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::cache_export(
+                &args.lock,
+                &args.output_file,
+                args.python.as_deref(),
+                &args.settings,
+                &globals.network_settings,
+                globals.python_preference,
+                globals.concurrency,
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Import(args),
+        }) => {
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::cache_import(&args.bundle, &cache, printer).await
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
@@ -1103,6 +1153,27 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             args.shell.generate(&mut Cli::command(), &mut stdout());
             Ok(ExitStatus::Success)
         }
+        Commands::BundleRun(args) => {
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            let install_mirrors = filesystem
+                .map(|fs| fs.install_mirrors.clone())
+                .unwrap_or_default();
+
+            commands::bundle_run(
+                &args.bundle,
+                args.args,
+                install_mirrors,
+                &globals.network_settings,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.installer_metadata,
+                &cache,
+                printer,
+            )
+            .await
+        }
         Commands::Tool(ToolNamespace {
             command: run_variant @ (ToolCommand::Uvx(_) | ToolCommand::Run(_)),
         }) => {
@@ -1224,6 +1295,31 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                     .combine(Refresh::from(args.settings.resolver.upgrade.clone())),
             );
 
+            // Install the tools listed in the manifest, if provided.
+            if let Some(manifest) = args.from_manifest {
+                return Box::pin(commands::tool_install_manifest(
+                    &manifest,
+                    args.install_mirrors,
+                    args.force,
+                    args.locked,
+                    args.frozen,
+                    args.options,
+                    globals.network_settings,
+                    globals.python_preference,
+                    globals.python_downloads,
+                    globals.installer_metadata,
+                    globals.concurrency,
+                    cache,
+                    printer,
+                    globals.preview,
+                ))
+                .await;
+            }
+
+            let Some(package) = args.package else {
+                unreachable!("`uv tool install` requires a package or `--from-manifest`");
+            };
+
             let mut requirements = Vec::with_capacity(
                 args.with.len() + args.with_editable.len() + args.with_requirements.len(),
             );
@@ -1257,7 +1353,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 .collect::<Result<Vec<_>, _>>()?;
 
             Box::pin(commands::tool_install(
-                args.package,
+                package,
                 args.editable,
                 args.from,
                 &requirements,
@@ -1267,6 +1363,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.python,
                 args.install_mirrors,
                 args.force,
+                args.locked,
+                args.frozen,
                 args.options,
                 args.settings,
                 globals.network_settings,
@@ -1299,6 +1397,41 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             )
             .await
         }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Export(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolExportSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::tool_export(args.no_lock, printer).await
+        }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Doctor(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolDoctorSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            Box::pin(commands::tool_doctor(
+                args.names,
+                args.fix,
+                args.options,
+                args.install_mirrors,
+                globals.network_settings,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.installer_metadata,
+                globals.concurrency,
+                cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
         Commands::Tool(ToolNamespace {
             command: ToolCommand::Upgrade(args),
         }) => {
@@ -1393,6 +1526,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.python_install_mirror,
                 args.pypy_install_mirror,
                 args.python_downloads_json_url,
+                args.archive_dir,
                 globals.network_settings,
                 args.default,
                 globals.python_downloads,
@@ -1402,6 +1536,29 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             )
             .await
         }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Upgrade(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonUpgradeSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::python_upgrade(
+                &project_dir,
+                args.install_dir,
+                args.targets,
+                args.remove_superseded,
+                args.venvs,
+                args.python_install_mirror,
+                args.pypy_install_mirror,
+                args.python_downloads_json_url,
+                globals.network_settings,
+                globals.python_downloads,
+                globals.preview,
+                printer,
+            )
+            .await
+        }
         Commands::Python(PythonNamespace {
             command: PythonCommand::Uninstall(args),
         }) => {
@@ -1485,6 +1642,44 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::python_dir(args.bin)?;
             Ok(ExitStatus::Success)
         }
+        Commands::Python(PythonNamespace {
+            command:
+                PythonCommand::Shim(PythonShimNamespace {
+                    command: PythonShimCommand::List,
+                }),
+        }) => commands::python_shim_list(globals.preview, printer),
+        Commands::Python(PythonNamespace {
+            command:
+                PythonCommand::Shim(PythonShimNamespace {
+                    command: PythonShimCommand::Rebuild,
+                }),
+        }) => commands::python_shim_rebuild(globals.preview, printer),
+        Commands::Python(PythonNamespace {
+            command:
+                PythonCommand::Shim(PythonShimNamespace {
+                    command: PythonShimCommand::Exec(args),
+                }),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonShimExecSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::python_shim_exec(
+                &project_dir,
+                &args.name,
+                args.args,
+                args.install_mirrors,
+                &globals.network_settings,
+                globals.python_downloads,
+                cli.top_level.no_config,
+                &cache,
+                printer,
+            )
+            .await
+        }
         Commands::Publish(args) => {
             show_settings!(args);
 
@@ -1508,10 +1703,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 keyring_provider,
                 check_url,
                 index,
+                client_cert,
+                client_key,
+                ca_bundle,
                 index_locations,
             } = PublishSettings::resolve(args, filesystem);
 
-            let (publish_url, check_url) = if let Some(index_name) = index {
+            let (publish_url, check_url, host_tls) = if let Some(index_name) = index {
                 debug!("Publishing with index {index_name}");
                 let index = index_locations
                     .simple_indexes()
@@ -1542,9 +1740,35 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                     .clone()
                     .with_context(|| format!("Index is missing a publish URL: `{index_name}`"))?;
                 let check_url = index.url.clone();
-                (publish_url, Some(check_url))
+                // Any TLS settings on the command-line take precedence over those of the index.
+                let client_cert = client_cert.or_else(|| index.client_cert.clone());
+                let client_key = client_key.or_else(|| index.client_key.clone());
+                let ca_bundle = ca_bundle.or_else(|| index.ca_bundle.clone());
+                let host_tls = [&publish_url, check_url.url()]
+                    .into_iter()
+                    .filter_map(|url| {
+                        HostTls::new(
+                            url.clone(),
+                            client_cert.clone(),
+                            client_key.clone(),
+                            ca_bundle.clone(),
+                        )
+                    })
+                    .collect();
+                (publish_url, Some(check_url), host_tls)
             } else {
-                (publish_url, check_url)
+                let host_tls = std::iter::once(&publish_url)
+                    .chain(check_url.as_ref().map(IndexUrl::url))
+                    .filter_map(|url| {
+                        HostTls::new(
+                            url.clone(),
+                            client_cert.clone(),
+                            client_key.clone(),
+                            ca_bundle.clone(),
+                        )
+                    })
+                    .collect();
+                (publish_url, check_url, host_tls)
             };
 
             commands::publish(
@@ -1556,6 +1780,25 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 username,
                 password,
                 check_url,
+                host_tls,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::Serve(args),
+        }) => {
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::index_serve(
+                args.dir,
+                &args.host,
+                args.port,
+                args.upload,
                 &cache,
                 printer,
             )
@@ -1749,6 +1992,7 @@ async fn run_project(
                 args.frozen,
                 args.dry_run,
                 args.active,
+                args.target,
                 args.all_packages,
                 args.package,
                 args.extras,
@@ -1801,6 +2045,9 @@ async fn run_project(
                 args.locked,
                 args.frozen,
                 args.dry_run,
+                args.from_pylock,
+                args.wheel_coverage,
+                args.require_wheel_coverage,
                 args.python,
                 args.install_mirrors,
                 args.settings,
@@ -1963,6 +2210,7 @@ async fn run_project(
                 args.no_dedupe,
                 args.invert,
                 args.outdated,
+                args.status,
                 args.python_version,
                 args.python_platform,
                 args.python,
@@ -2028,6 +2276,39 @@ async fn run_project(
             .boxed_local()
             .await
         }
+        ProjectCommand::Bundle(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::BundleSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(args.refresh);
+
+            commands::bundle(
+                project_dir,
+                args.output_file,
+                args.entry_point,
+                args.package,
+                args.python_platforms,
+                args.python_download,
+                args.launcher,
+                args.locked,
+                args.frozen,
+                args.python,
+                args.install_mirrors,
+                args.settings,
+                globals.network_settings,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.concurrency,
+                no_config,
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .boxed_local()
+            .await
+        }
     }
 }
 
@@ -2051,9 +2332,48 @@ where
     I: IntoIterator<Item = T>,
     T: Into<OsString> + Clone,
 {
-    // Set the `UV` variable to the current executable so it is implicitly propagated to all child
-    // processes, e.g., in `uv run`.
+    let mut args = args.into_iter().map(Into::into).collect::<Vec<OsString>>();
+
     if let Ok(current_exe) = std::env::current_exe() {
+        if commands::is_bundle(&current_exe) {
+            // If the executable was created by `uv bundle`, run the bundled application, passing
+            // through all arguments.
+            let argv0 = args
+                .first()
+                .cloned()
+                .unwrap_or_else(|| OsString::from("uv"));
+            let rest = args.into_iter().skip(1);
+            args = [
+                argv0,
+                "bundle-run".into(),
+                current_exe.clone().into(),
+                "--".into(),
+            ]
+            .into_iter()
+            .chain(rest)
+            .collect();
+        } else if let Some(name) = args
+            .get(1)
+            .and_then(|arg| commands::python_shim_from_trampoline(arg))
+        {
+            // On Windows, Python shims are trampolines that invoke uv with the path to the shim.
+            let argv0 = args[0].clone();
+            let rest = args.into_iter().skip(2);
+            args = [
+                argv0,
+                "python".into(),
+                "shim".into(),
+                "exec".into(),
+                name.into(),
+                "--".into(),
+            ]
+            .into_iter()
+            .chain(rest)
+            .collect();
+        }
+
+        // Set the `UV` variable to the current executable so it is implicitly propagated to all
+        // child processes, e.g., in `uv run`. A bundle invoked through `UV` runs as uv.
         // SAFETY: The proof obligation must be satisfied by the caller.
         unsafe {
             // This will become unsafe in Rust 2024
@@ -1,4 +1,5 @@
 use std::env::VarError;
+use std::ffi::OsString;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::process;
@@ -10,15 +11,16 @@ use uv_cache::{CacheArgs, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
     options::{flag, resolver_installer_options, resolver_options},
-    AuthorFrom, BuildArgs, ExportArgs, PublishArgs, PythonDirArgs, ResolverInstallerArgs,
-    ToolUpgradeArgs,
+    AuthorFrom, BuildArgs, BundleArgs, CacheExportArgs, ExportArgs, PublishArgs, PythonDirArgs,
+    ResolverInstallerArgs, ToolUpgradeArgs,
 };
 use uv_cli::{
     AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
     PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
     PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonListFormat, PythonPinArgs, PythonUninstallArgs, RemoveArgs, RunArgs, SyncArgs,
-    ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs,
+    PythonListFormat, PythonPinArgs, PythonShimExecArgs, PythonUninstallArgs, PythonUpgradeArgs,
+    RemoveArgs, RunArgs, SyncArgs, ToolDirArgs, ToolDoctorArgs, ToolExportArgs, ToolInstallArgs,
+    ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs,
 };
 use uv_client::Connectivity;
 use uv_configuration::{
@@ -203,11 +205,18 @@ impl NetworkSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) fallback_cache_dirs: Vec<PathBuf>,
 }
 
 impl CacheSettings {
     /// Resolve the [`CacheSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(args: CacheArgs, workspace: Option<&FilesystemOptions>) -> Self {
+        let fallback_cache_dirs = args
+            .fallback_cache_dirs()
+            .or_else(|| {
+                workspace.and_then(|workspace| workspace.globals.fallback_cache_dir.clone())
+            })
+            .unwrap_or_default();
         Self {
             no_cache: args.no_cache
                 || workspace
@@ -216,6 +225,7 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            fallback_cache_dirs,
         }
     }
 }
@@ -580,8 +590,9 @@ impl ToolRunSettings {
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub(crate) struct ToolInstallSettings {
-    pub(crate) package: String,
+    pub(crate) package: Option<String>,
     pub(crate) from: Option<String>,
+    pub(crate) from_manifest: Option<PathBuf>,
     pub(crate) with: Vec<String>,
     pub(crate) with_requirements: Vec<PathBuf>,
     pub(crate) with_editable: Vec<String>,
@@ -594,6 +605,8 @@ pub(crate) struct ToolInstallSettings {
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) force: bool,
     pub(crate) editable: bool,
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
     pub(crate) install_mirrors: PythonInstallMirrors,
 }
 
@@ -605,6 +618,7 @@ impl ToolInstallSettings {
             package,
             editable,
             from,
+            from_manifest,
             with,
             with_editable,
             with_requirements,
@@ -613,6 +627,8 @@ impl ToolInstallSettings {
             build_constraints,
             installer,
             force,
+            locked,
+            frozen,
             build,
             refresh,
             python,
@@ -636,6 +652,7 @@ impl ToolInstallSettings {
         Self {
             package,
             from,
+            from_manifest,
             with: with
                 .into_iter()
                 .flat_map(CommaSeparatedRequirements::into_iter)
@@ -663,6 +680,8 @@ impl ToolInstallSettings {
             python: python.and_then(Maybe::into_option),
             force,
             editable,
+            locked,
+            frozen,
             refresh: Refresh::from(refresh),
             options,
             settings,
@@ -795,6 +814,57 @@ impl ToolListSettings {
     }
 }
 
+/// The resolved settings to use for a `tool export` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct ToolExportSettings {
+    pub(crate) no_lock: bool,
+}
+
+impl ToolExportSettings {
+    /// Resolve the [`ToolExportSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolExportArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolExportArgs { no_lock } = args;
+
+        Self { no_lock }
+    }
+}
+
+/// The resolved settings to use for a `tool doctor` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct ToolDoctorSettings {
+    pub(crate) names: Vec<PackageName>,
+    pub(crate) fix: bool,
+    pub(crate) options: ResolverInstallerOptions,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+}
+
+impl ToolDoctorSettings {
+    /// Resolve the [`ToolDoctorSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolDoctorArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolDoctorArgs { name, fix } = args;
+
+        let options = filesystem
+            .clone()
+            .map(FilesystemOptions::into_options)
+            .map(|options| options.top_level)
+            .unwrap_or_default();
+
+        let install_mirrors = filesystem
+            .map(FilesystemOptions::into_options)
+            .map(|options| options.install_mirrors)
+            .unwrap_or_default();
+
+        Self {
+            names: name,
+            fix,
+            options,
+            install_mirrors,
+        }
+    }
+}
+
 /// The resolved settings to use for a `tool uninstall` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -929,6 +999,7 @@ pub(crate) struct PythonInstallSettings {
     pub(crate) python_install_mirror: Option<String>,
     pub(crate) pypy_install_mirror: Option<String>,
     pub(crate) python_downloads_json_url: Option<String>,
+    pub(crate) archive_dir: Option<PathBuf>,
     pub(crate) default: bool,
 }
 
@@ -958,6 +1029,7 @@ impl PythonInstallSettings {
             mirror: _,
             pypy_mirror: _,
             python_downloads_json_url: _,
+            archive_dir,
             default,
         } = args;
 
@@ -969,11 +1041,65 @@ impl PythonInstallSettings {
             python_install_mirror: python_mirror,
             pypy_install_mirror: pypy_mirror,
             python_downloads_json_url,
+            archive_dir,
             default,
         }
     }
 }
 
+/// The resolved settings to use for a `python upgrade` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct PythonUpgradeSettings {
+    pub(crate) install_dir: Option<PathBuf>,
+    pub(crate) targets: Vec<String>,
+    pub(crate) remove_superseded: bool,
+    pub(crate) venvs: Vec<PathBuf>,
+    pub(crate) python_install_mirror: Option<String>,
+    pub(crate) pypy_install_mirror: Option<String>,
+    pub(crate) python_downloads_json_url: Option<String>,
+}
+
+impl PythonUpgradeSettings {
+    /// Resolve the [`PythonUpgradeSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: PythonUpgradeArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let options = filesystem.map(FilesystemOptions::into_options);
+        let (python_mirror, pypy_mirror, python_downloads_json_url) = match options {
+            Some(options) => (
+                options.install_mirrors.python_install_mirror,
+                options.install_mirrors.pypy_install_mirror,
+                options.install_mirrors.python_downloads_json_url,
+            ),
+            None => (None, None, None),
+        };
+        let python_mirror = args.mirror.or(python_mirror);
+        let pypy_mirror = args.pypy_mirror.or(pypy_mirror);
+        let python_downloads_json_url =
+            args.python_downloads_json_url.or(python_downloads_json_url);
+
+        let PythonUpgradeArgs {
+            install_dir,
+            targets,
+            remove_superseded,
+            venv,
+            mirror: _,
+            pypy_mirror: _,
+            python_downloads_json_url: _,
+        } = args;
+
+        Self {
+            install_dir,
+            targets,
+            remove_superseded,
+            venvs: venv,
+            python_install_mirror: python_mirror,
+            pypy_install_mirror: pypy_mirror,
+            python_downloads_json_url,
+        }
+    }
+}
+
 /// The resolved settings to use for a `python uninstall` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -1067,6 +1193,31 @@ impl PythonPinSettings {
     }
 }
 
+/// The resolved settings to use for a `python shim exec` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonShimExecSettings {
+    pub(crate) name: String,
+    pub(crate) args: Vec<OsString>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+}
+
+impl PythonShimExecSettings {
+    /// Resolve the [`PythonShimExecSettings`] from the CLI and workspace configuration.
+    pub(crate) fn resolve(args: PythonShimExecArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PythonShimExecArgs { name, args } = args;
+
+        let install_mirrors = filesystem
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            name,
+            args,
+            install_mirrors,
+        }
+    }
+}
+
 /// The resolved settings to use for a `sync` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
 #[derive(Debug, Clone)]
@@ -1076,6 +1227,7 @@ pub(crate) struct SyncSettings {
     pub(crate) dry_run: DryRun,
     pub(crate) script: Option<PathBuf>,
     pub(crate) active: Option<bool>,
+    pub(crate) target: Option<Target>,
     pub(crate) extras: ExtrasSpecification,
     pub(crate) dev: DependencyGroups,
     pub(crate) editable: EditableMode,
@@ -1116,6 +1268,7 @@ impl SyncSettings {
             frozen,
             active,
             no_active,
+            target,
             dry_run,
             installer,
             build,
@@ -1150,6 +1303,7 @@ impl SyncSettings {
             dry_run,
             script,
             active: flag(active, no_active),
+            target: target.map(Target::from),
             extras: ExtrasSpecification::from_args(
                 extra.unwrap_or_default(),
                 no_extra,
@@ -1198,6 +1352,9 @@ pub(crate) struct LockSettings {
     pub(crate) frozen: bool,
     pub(crate) dry_run: DryRun,
     pub(crate) script: Option<PathBuf>,
+    pub(crate) from_pylock: Option<PathBuf>,
+    pub(crate) wheel_coverage: bool,
+    pub(crate) require_wheel_coverage: bool,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
@@ -1213,6 +1370,9 @@ impl LockSettings {
             check_exists,
             dry_run,
             script,
+            from_pylock,
+            wheel_coverage,
+            require_wheel_coverage,
             resolver,
             build,
             refresh,
@@ -1229,6 +1389,9 @@ impl LockSettings {
             frozen: check_exists,
             dry_run: DryRun::from_args(dry_run),
             script,
+            from_pylock,
+            wheel_coverage,
+            require_wheel_coverage,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
@@ -1485,6 +1648,7 @@ pub(crate) struct TreeSettings {
     pub(crate) no_dedupe: bool,
     pub(crate) invert: bool,
     pub(crate) outdated: bool,
+    pub(crate) status: bool,
     #[allow(dead_code)]
     pub(crate) script: Option<PathBuf>,
     pub(crate) python_version: Option<PythonVersion>,
@@ -1499,6 +1663,7 @@ impl TreeSettings {
     pub(crate) fn resolve(args: TreeArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let TreeArgs {
             tree,
+            status,
             universal,
             dev,
             only_dev,
@@ -1542,6 +1707,7 @@ impl TreeSettings {
             no_dedupe: tree.no_dedupe,
             invert: tree.invert,
             outdated: tree.outdated,
+            status,
             script,
             python_version,
             python_platform,
@@ -1667,6 +1833,92 @@ impl ExportSettings {
     }
 }
 
+/// The resolved settings to use for a `bundle` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct BundleSettings {
+    pub(crate) output_file: Option<PathBuf>,
+    pub(crate) entry_point: Option<String>,
+    pub(crate) package: Option<PackageName>,
+    pub(crate) python_platforms: Vec<TargetTriple>,
+    pub(crate) python_download: bool,
+    pub(crate) launcher: Option<PathBuf>,
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl BundleSettings {
+    /// Resolve the [`BundleSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: BundleArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let BundleArgs {
+            output_file,
+            entry_point,
+            package,
+            python_platform,
+            python_download,
+            launcher,
+            locked,
+            frozen,
+            resolver,
+            build,
+            refresh,
+            python,
+        } = args;
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            output_file,
+            entry_point,
+            package,
+            python_platforms: python_platform,
+            python_download,
+            launcher,
+            locked,
+            frozen,
+            python: python.and_then(Maybe::into_option),
+            install_mirrors,
+            refresh: Refresh::from(refresh),
+            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+        }
+    }
+}
+
+/// The resolved settings to use for a `cache export` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct CacheExportSettings {
+    pub(crate) lock: PathBuf,
+    pub(crate) output_file: PathBuf,
+    pub(crate) python: Option<String>,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl CacheExportSettings {
+    /// Resolve the [`CacheExportSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: CacheExportArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let CacheExportArgs {
+            lock,
+            output_file,
+            python,
+        } = args;
+
+        Self {
+            lock,
+            output_file,
+            python: python.and_then(Maybe::into_option),
+            settings: ResolverSettings::combine(ResolverOptions::default(), filesystem),
+        }
+    }
+}
+
 /// The resolved settings to use for a `pip compile` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -3074,6 +3326,9 @@ pub(crate) struct PublishSettings {
     pub(crate) username: Option<String>,
     pub(crate) password: Option<String>,
     pub(crate) index: Option<String>,
+    pub(crate) client_cert: Option<PathBuf>,
+    pub(crate) client_key: Option<PathBuf>,
+    pub(crate) ca_bundle: Option<PathBuf>,
 
     // Both CLI and configuration.
     pub(crate) publish_url: Url,
@@ -3131,6 +3386,9 @@ impl PublishSettings {
                 .unwrap_or_default(),
             check_url: args.check_url.combine(check_url),
             index: args.index,
+            client_cert: args.client_cert,
+            client_key: args.client_key,
+            ca_bundle: args.ca_bundle,
             index_locations: IndexLocations::new(
                 index
                     .into_iter()
@@ -0,0 +1,179 @@
+use std::process::Command;
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Bundle a project, then run the bundle.
+#[test]
+fn bundle() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [project.scripts]
+        greet = "project:main"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str(indoc! {r#"
+            import sys
+
+            import iniconfig
+
+            def main():
+                print(f"Hello from {__name__}: {sys.argv[1:]}")
+                sys.exit(3)
+        "#})?;
+
+    uv_snapshot!(context.filters(), context.bundle().arg("--preview"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Successfully bundled project==0.1.0 into dist/project (2 wheels for 1 platform)
+    ");
+
+    let bundle = context
+        .temp_dir
+        .child("dist")
+        .child(format!("project{}", std::env::consts::EXE_SUFFIX));
+
+    // The first run unpacks the bundle into the cache; arguments and the exit code are passed
+    // through.
+    let mut command = Command::new(bundle.path());
+    command.arg("--name").arg("value");
+    context.add_shared_env(&mut command, false);
+    command.env(EnvVars::UV_CACHE_DIR, context.cache_dir.path());
+
+    uv_snapshot!(context.filters(), command, @r"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+    Hello from project: ['--name', 'value']
+
+    ----- stderr -----
+    Unpacking project==0.1.0 into the cache
+    ");
+
+    // Subsequent runs reuse the cached environment.
+    let mut command = Command::new(bundle.path());
+    context.add_shared_env(&mut command, false);
+    command.env(EnvVars::UV_CACHE_DIR, context.cache_dir.path());
+
+    uv_snapshot!(context.filters(), command, @r"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+    Hello from project: []
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// Bundling a project without any scripts requires an entry point.
+#[test]
+fn bundle_no_scripts() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+
+    uv_snapshot!(context.filters(), context.bundle().arg("--preview"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: The project `project` does not declare any scripts in `[project.scripts]`
+    ");
+
+    Ok(())
+}
+
+/// Bundles cannot use the names of uv's own executables, which are never run as bundles.
+#[test]
+fn bundle_reserved_name() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        greet = "project:main"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str(indoc! {r"
+            def main():
+                pass
+        "})?;
+
+    uv_snapshot!(context.filters(), context.bundle().arg("--preview").arg("--output-file").arg("dist/uv"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: The bundle cannot be written to `dist/uv`, as the name is reserved for uv's own executables
+    ");
+
+    context
+        .temp_dir
+        .child("dist")
+        .child("uv")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
@@ -51,7 +51,7 @@ fn clean_package_pypi() -> Result<()> {
     // Assert that the `.rkyv` file is created for `iniconfig`.
     let rkyv = context
         .cache_dir
-        .child("simple-v15")
+        .child("simple-v16")
         .child("pypi")
         .child("iniconfig.rkyv");
     assert!(
@@ -125,7 +125,7 @@ fn clean_package_index() -> Result<()> {
     // Assert that the `.rkyv` file is created for `iniconfig`.
     let rkyv = context
         .cache_dir
-        .child("simple-v15")
+        .child("simple-v16")
         .child("index")
         .child("e8208120cae3ba69")
         .child("iniconfig.rkyv");
@@ -0,0 +1,112 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+
+/// Return the filters for `cache export` output, which includes the size of the bundle.
+fn filters(context: &TestContext) -> Vec<(&str, &str)> {
+    context
+        .filters()
+        .into_iter()
+        .chain([(r"\(\d+(\.\d+)?[KMG]?i?B\)", "([SIZE])")])
+        .collect()
+}
+
+/// Export the distributions referenced by a lockfile (both the wheel and the source distribution),
+/// import them into an empty cache, and sync the project offline.
+#[test]
+fn export_import() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    let filters = filters(&context);
+
+    uv_snapshot!(&filters, context.cache_export().arg("--output-file").arg("bundle.tar.zst"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Exported 2 distributions to [TEMP_DIR]/bundle.tar.zst ([SIZE])
+    "###);
+
+    // Clear the cache.
+    context.clean().assert().success();
+
+    // The number of entries for the source distribution depends on how its metadata was read.
+    let import_filters = filters
+        .iter()
+        .copied()
+        .chain([(r"Imported \d+ cache entries", "Imported [N] cache entries")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(&import_filters, context.cache_import().arg("bundle.tar.zst"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Imported [N] cache entries into [CACHE_DIR]/
+    "###);
+
+    // Importing the bundle again should be a no-op.
+    uv_snapshot!(&filters, context.cache_import().arg("bundle.tar.zst"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Imported 0 cache entries into [CACHE_DIR]/
+    "###);
+
+    // The project should be installable without network access.
+    uv_snapshot!(context.filters(), context.sync().arg("--offline"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
+/// Importing a file that isn't a cache bundle should fail.
+#[test]
+fn import_invalid() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("bundle.tar.zst")
+        .write_str("bundle")?;
+
+    uv_snapshot!(context.filters(), context.cache_import().arg("bundle.tar.zst"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to unpack bundle: bundle.tar.zst
+      Caused by: Unknown frame descriptor
+    "###);
+
+    Ok(())
+}
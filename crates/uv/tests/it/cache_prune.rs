@@ -382,3 +382,183 @@ fn prune_stale_revision() -> Result<()> {
 
     Ok(())
 }
+
+/// Set the modification time of every unzipped wheel in the cache, along with its usage record,
+/// to the given number of days in the past.
+fn age_archives(context: &TestContext, days: u64) -> Result<()> {
+    let time = filetime::FileTime::from_system_time(
+        std::time::SystemTime::now() - std::time::Duration::from_secs(days * 24 * 60 * 60),
+    );
+    for bucket in ["archive-v0", "usage-v0"] {
+        for entry in fs_err::read_dir(context.cache_dir.join(bucket))? {
+            filetime::set_file_mtime(entry?.path(), time)?;
+        }
+    }
+    Ok(())
+}
+
+/// `cache prune --unused-for` should evict wheels that haven't been used recently, along with the
+/// entries that point to them.
+#[test]
+fn prune_unused_for() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain(std::iter::once((r"Removed \d+ files", "Removed [N] files")))
+        .collect();
+
+    // The wheel was used just now, so it should be retained.
+    uv_snapshot!(&filters, context.prune().arg("--unused-for").arg("30d"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    "###);
+
+    age_archives(&context, 60)?;
+
+    uv_snapshot!(&filters, context.prune().arg("--unused-for").arg("30d"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    "###);
+
+    assert_eq!(
+        fs_err::read_dir(context.cache_dir.join("archive-v0"))?.count(),
+        0
+    );
+
+    // Reinstalling the requirement should repopulate the cache.
+    uv_snapshot!(&filters, context.pip_sync().arg("requirements.txt").arg("--reinstall"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
+/// `cache prune --max-size` should evict the least recently used wheels until the cache fits.
+#[test]
+fn prune_max_size() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain(std::iter::once((r"Removed \d+ files", "Removed [N] files")))
+        .collect();
+
+    // A generous limit should retain everything.
+    uv_snapshot!(&filters, context.prune().arg("--max-size").arg("1GiB"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    "###);
+
+    // Recently used wheels are retained, even if the cache exceeds the limit.
+    uv_snapshot!(&filters, context.prune().arg("--max-size").arg("1KB"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    "###);
+
+    age_archives(&context, 1)?;
+
+    // Only the wheels count towards the limit, so a limit that fits the wheels retains them, even
+    // though the cache as a whole is larger.
+    let mut archive_bytes = 0;
+    for entry in walkdir::WalkDir::new(context.cache_dir.join("archive-v0")) {
+        let entry = entry?;
+        if entry.file_type().is_file() {
+            archive_bytes += entry.metadata()?.len();
+        }
+    }
+    uv_snapshot!(&filters, context.prune().arg("--max-size").arg(archive_bytes.to_string()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    "###);
+
+    uv_snapshot!(&filters, context.prune().arg("--max-size").arg("1KB"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    "###);
+
+    assert_eq!(
+        fs_err::read_dir(context.cache_dir.join("archive-v0"))?.count(),
+        0
+    );
+
+    Ok(())
+}
+
+/// `cache prune --max-size` should reject invalid sizes.
+#[test]
+fn prune_max_size_invalid() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.prune().arg("--max-size").arg("20GB/s"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value '20GB/s' for '--max-size <MAX_SIZE>': invalid size unit `GB/s`; expected one of `B`, `KB`, `MB`, `GB`, `TB`, `KiB`, `MiB`, `GiB`, or `TiB`
+
+    For more information, try '--help'.
+    "###);
+}
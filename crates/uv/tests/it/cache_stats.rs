@@ -0,0 +1,98 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::TestContext;
+
+/// Run `uv cache stats` and parse the JSON output.
+fn stats(context: &TestContext) -> Result<serde_json::Value> {
+    let output = context
+        .cache_stats()
+        .arg("--output-format")
+        .arg("json")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    Ok(serde_json::from_slice(&output)?)
+}
+
+/// Return the statistics for the bucket with the given name prefix (e.g., `wheels`).
+fn bucket<'a>(stats: &'a serde_json::Value, prefix: &str) -> &'a serde_json::Value {
+    stats["buckets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|bucket| {
+            bucket["name"]
+                .as_str()
+                .is_some_and(|name| name.starts_with(&format!("{prefix}-v")))
+        })
+        .unwrap()
+}
+
+/// `cache stats` should attribute the cached wheels and archives to their packages.
+#[test]
+fn stats_packages() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let stats = stats(&context)?;
+    assert!(stats["total_bytes"].as_u64().unwrap() > 0);
+    assert_eq!(stats["unreferenced_archives"], 0);
+
+    for prefix in ["wheels", "archive"] {
+        let bucket = bucket(&stats, prefix);
+        assert!(bucket["num_files"].as_u64().unwrap() > 0);
+        assert_eq!(bucket["packages"][0]["name"], "iniconfig");
+    }
+
+    // The archive bucket holds the unzipped wheel, so it should account for the majority of the
+    // cache.
+    let archive = bucket(&stats, "archive");
+    assert!(archive["total_bytes"].as_u64().unwrap() * 2 > stats["total_bytes"].as_u64().unwrap());
+
+    Ok(())
+}
+
+/// `cache stats` should report archives that are not referenced by any wheel entry.
+#[test]
+fn stats_unreferenced_archives() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Remove the wheel entries, leaving the archive dangling.
+    let wheels = bucket(&stats(&context)?, "wheels")["name"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    fs_err::remove_dir_all(context.cache_dir.child(wheels))?;
+
+    let stats = stats(&context)?;
+    assert_eq!(stats["unreferenced_archives"], 1);
+    assert_eq!(
+        stats["unreferenced_bytes"],
+        bucket(&stats, "archive")["total_bytes"]
+    );
+
+    Ok(())
+}
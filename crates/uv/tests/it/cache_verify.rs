@@ -0,0 +1,157 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+
+/// Return the filters for `cache verify` output, which includes archive IDs and removed sizes.
+fn filters(context: &TestContext) -> Vec<(&str, &str)> {
+    context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"archive-v0/[A-Za-z0-9_-]+", "archive-v0/[ARCHIVE]"),
+            (r"\(\d+(\.\d+)?[KMG]?i?B\)", "([SIZE])"),
+        ])
+        .collect()
+}
+
+/// `cache verify` should be a no-op if the cache is intact.
+#[test]
+fn verify_no_op() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.cache_verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    No corrupt entries found
+    "###);
+
+    Ok(())
+}
+
+/// `cache verify` should remove unzipped wheels that don't match their `RECORD`, along with the
+/// entries that point to them, and leave the remaining entries intact.
+#[test]
+fn verify_corrupt_archive() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Remove a file from the unzipped wheel.
+    for entry in fs_err::read_dir(context.cache_dir.join("archive-v0"))? {
+        fs_err::remove_file(entry?.path().join("iniconfig").join("__init__.py"))?;
+    }
+
+    let filters = filters(&context);
+
+    // In `--dry-run` mode, the corrupt entry should be reported, but retained.
+    uv_snapshot!(&filters, context.cache_verify().arg("--dry-run"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Corrupt cache entry: [CACHE_DIR]/archive-v0/[ARCHIVE] (RECORD file doesn't match wheel contents: `iniconfig/__init__.py` is missing)
+    Found 1 corrupt entry
+    "###);
+
+    uv_snapshot!(&filters, context.cache_verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Corrupt cache entry: [CACHE_DIR]/archive-v0/[ARCHIVE] (RECORD file doesn't match wheel contents: `iniconfig/__init__.py` is missing)
+    Removed 1 corrupt entry ([SIZE])
+    "###);
+
+    // The cache should now be intact.
+    uv_snapshot!(&filters, context.cache_verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    No corrupt entries found
+    "###);
+
+    // Re-installing should re-download the wheel.
+    context.venv().assert().success();
+    uv_snapshot!(&filters, context.pip_sync().arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
+/// `cache verify` should remove cached index responses that can't be deserialized.
+#[test]
+fn verify_corrupt_entry() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Truncate the cached index response.
+    let simple = context.cache_dir.child("simple-v16").child("pypi");
+    simple.child("iniconfig.rkyv").write_str("corrupt")?;
+
+    uv_snapshot!(filters(&context), context.cache_verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Corrupt cache entry: [CACHE_DIR]/simple-v16/pypi/iniconfig.rkyv (Reading from cache archive failed: data-with-cache-policy buffer should be at least 8 bytes in length, but is 7 bytes)
+    Removed 1 corrupt entry ([SIZE])
+    "###);
+
+    simple
+        .child("iniconfig.rkyv")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
@@ -323,8 +323,8 @@ impl TestContext {
       )
     )?                      # (we allow the patch version to be missing entirely, e.g., in a request)
     (?:(?:a|b|rc)[0-9]+)?   # Pre-release version component, e.g., `a6` or `rc2`
-    (?:[td])?               # A short variant, such as `t` (for freethreaded) or `d` (for debug)
-    (?:\+[a-z]+)?           # A long variant, such as `+free-threaded`
+    (?:td|t|d)?             # A short variant, such as `t` (for freethreaded) or `d` (for debug)
+    (?:\+[a-z]+){0,2}       # A long variant, such as `+freethreaded` or `+freethreaded+debug`
   )
   -
   [a-z0-9]+                 # Operating system (e.g., 'macos')
@@ -369,6 +369,10 @@ impl TestContext {
         ));
         self.extra_env
             .push((EnvVars::UV_PYTHON_INSTALL_DIR.into(), managed.into()));
+        self.extra_env.push((
+            EnvVars::UV_PYTHON_SHIM_DIR.into(),
+            self.temp_dir.join("shims").into(),
+        ));
         self.extra_env
             .push((EnvVars::UV_PYTHON_DOWNLOADS.into(), "automatic".into()));
 
@@ -720,6 +724,21 @@ impl TestContext {
             .env_remove(EnvVars::UV_CACHE_DIR)
             .env_remove(EnvVars::UV_TOOL_BIN_DIR)
             .env_remove(EnvVars::XDG_CONFIG_HOME)
+            .env_remove(EnvVars::XDG_DATA_HOME)
+            // Ignore Python installations managed by other tools, i.e., that the developer is
+            // using, including those found in their default locations.
+            .env(
+                EnvVars::PYENV_ROOT,
+                self.home_dir.child(".pyenv").as_os_str(),
+            )
+            .env(
+                EnvVars::ASDF_DATA_DIR,
+                self.home_dir.child(".asdf").as_os_str(),
+            )
+            .env(
+                EnvVars::MISE_DATA_DIR,
+                self.home_dir.child("mise").as_os_str(),
+            )
             .current_dir(self.temp_dir.path());
 
         for (key, value) in &self.extra_env {
@@ -856,6 +875,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv bundle` command with options shared across scenarios.
+    pub fn bundle(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("bundle");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv build` command with options shared across scenarios.
     pub fn build(&self) -> Command {
         let mut command = self.new_command();
@@ -921,6 +948,17 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv python upgrade` command with options shared across scenarios.
+    pub fn python_upgrade(&self) -> Command {
+        let mut command = self.new_command();
+        self.add_shared_options(&mut command, true);
+        command
+            .arg("python")
+            .arg("upgrade")
+            .current_dir(&self.temp_dir);
+        command
+    }
+
     /// Create a `uv python uninstall` command with options shared across scenarios.
     pub fn python_uninstall(&self) -> Command {
         let mut command = self.new_command();
@@ -948,6 +986,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv python shim` command with options shared across scenarios.
+    pub fn python_shim(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("python").arg("shim");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
     /// Create a `uv run` command with options shared across scenarios.
     pub fn run(&self) -> Command {
         let mut command = self.new_command();
@@ -991,6 +1037,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv tool doctor` command with options shared across scenarios.
+    pub fn tool_doctor(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("tool").arg("doctor");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv tool export` command with options shared across scenarios.
+    pub fn tool_export(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("tool").arg("export");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv tool dir` command with options shared across scenarios.
     pub fn tool_dir(&self) -> Command {
         let mut command = self.new_command();
@@ -1047,6 +1109,46 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache stats` command.
+    pub fn cache_stats(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("stats");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache verify` command.
+    pub fn cache_verify(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("verify");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache export` command.
+    pub fn cache_export(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("export");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache import` command.
+    pub fn cache_import(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("import");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv index serve` command.
+    pub fn index_serve(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("index").arg("serve");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
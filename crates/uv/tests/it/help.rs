@@ -23,6 +23,7 @@ fn help() {
       sync                       Update the project's environment
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
+      bundle                     Bundle the project into a single-file executable
       tree                       Display the project's dependency tree
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
@@ -30,6 +31,7 @@ fn help() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      index                      Manage package indexes
       cache                      Manage uv's cache
       self                       Manage the uv executable
       version                    Read or update the project's version
@@ -103,6 +105,7 @@ fn help_flag() {
       sync     Update the project's environment
       lock     Update the project's lockfile
       export   Export the project's lockfile to an alternate format
+      bundle   Bundle the project into a single-file executable
       tree     Display the project's dependency tree
       tool     Run and install commands provided by Python packages
       python   Manage Python versions and installations
@@ -110,6 +113,7 @@ fn help_flag() {
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
+      index    Manage package indexes
       cache    Manage uv's cache
       self     Manage the uv executable
       version  Read or update the project's version
@@ -181,6 +185,7 @@ fn help_short_flag() {
       sync     Update the project's environment
       lock     Update the project's lockfile
       export   Export the project's lockfile to an alternate format
+      bundle   Bundle the project into a single-file executable
       tree     Display the project's dependency tree
       tool     Run and install commands provided by Python packages
       python   Manage Python versions and installations
@@ -188,6 +193,7 @@ fn help_short_flag() {
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
+      index    Manage package indexes
       cache    Manage uv's cache
       self     Manage the uv executable
       version  Read or update the project's version
@@ -292,9 +298,11 @@ fn help_subcommand() {
     Commands:
       list       List the available Python installations
       install    Download and install Python versions
+      upgrade    Upgrade installed Python versions to the latest patch release
       find       Search for a Python installation
       pin        Pin to a specific Python version
       dir        Show the uv Python installation directory
+      shim       Manage `python` shims for managed Python versions
       uninstall  Uninstall Python versions
 
     Cache options:
@@ -718,9 +726,11 @@ fn help_flag_subcommand() {
     Commands:
       list       List the available Python installations
       install    Download and install Python versions
+      upgrade    Upgrade installed Python versions to the latest patch release
       find       Search for a Python installation
       pin        Pin to a specific Python version
       dir        Show the uv Python installation directory
+      shim       Manage `python` shims for managed Python versions
       uninstall  Uninstall Python versions
 
     Cache options:
@@ -941,6 +951,7 @@ fn help_with_global_option() {
       sync                       Update the project's environment
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
+      bundle                     Bundle the project into a single-file executable
       tree                       Display the project's dependency tree
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
@@ -948,6 +959,7 @@ fn help_with_global_option() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      index                      Manage package indexes
       cache                      Manage uv's cache
       self                       Manage the uv executable
       version                    Read or update the project's version
@@ -1062,6 +1074,7 @@ fn help_with_no_pager() {
       sync                       Update the project's environment
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
+      bundle                     Bundle the project into a single-file executable
       tree                       Display the project's dependency tree
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
@@ -1069,6 +1082,7 @@ fn help_with_no_pager() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      index                      Manage package indexes
       cache                      Manage uv's cache
       self                       Manage the uv executable
       version                    Read or update the project's version
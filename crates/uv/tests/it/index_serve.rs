@@ -0,0 +1,263 @@
+use std::io::{BufRead, BufReader};
+use std::process::{Child, ChildStderr, Command, Stdio};
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// A running `uv index serve` process, which is killed on drop.
+struct Server {
+    child: Child,
+    /// The stderr of the server, which must remain open for the server to log requests.
+    _stderr: BufReader<ChildStderr>,
+    /// The URL of the server, e.g., `http://127.0.0.1:8000/`.
+    url: String,
+}
+
+impl Server {
+    /// Spawn the server on an available port, and wait for it to start listening.
+    fn spawn(mut command: Command) -> Result<Self> {
+        let mut child = command
+            .arg("--port")
+            .arg("0")
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let mut stderr = BufReader::new(child.stderr.take().unwrap());
+
+        // The server displays the index URL once it's listening.
+        let mut line = String::new();
+        stderr.read_line(&mut line)?;
+        let url = line
+            .trim()
+            .rsplit(' ')
+            .next()
+            .and_then(|url| url.strip_suffix("simple/"))
+            .unwrap_or_else(|| panic!("Unexpected server output: {line}"))
+            .to_string();
+
+        Ok(Self {
+            child,
+            _stderr: stderr,
+            url,
+        })
+    }
+
+    /// The URL of the simple index.
+    fn index_url(&self) -> String {
+        format!("{}simple/", self.url)
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Serve a directory of wheels, and install from it.
+#[test]
+fn serve_directory() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheels = context.temp_dir.child("wheels");
+    wheels.create_dir_all()?;
+    wheels.child("ok-1.0.0-py3-none-any.whl").write_file(
+        &context
+            .workspace_root
+            .join("scripts/links/ok-1.0.0-py3-none-any.whl"),
+    )?;
+    wheels.child("ok-2.0.0-py3-none-any.whl").write_file(
+        &context
+            .workspace_root
+            .join("scripts/links/ok-2.0.0-py3-none-any.whl"),
+    )?;
+    wheels.child("README.md").touch()?;
+
+    let mut command = context.index_serve();
+    command.arg("--dir").arg(wheels.path());
+    let server = Server::spawn(command)?;
+
+    // The project list and project page should be available as JSON.
+    let client = reqwest::blocking::Client::new();
+    let projects: serde_json::Value = client
+        .get(server.index_url())
+        .header("Accept", "application/vnd.pypi.simple.v1+json")
+        .send()?
+        .json()?;
+    assert_eq!(
+        projects,
+        serde_json::json!({"meta": {"api-version": "1.1"}, "projects": [{"name": "ok"}]})
+    );
+
+    let response = client
+        .get(format!("{}ok/", server.index_url()))
+        .header("Accept", "application/vnd.pypi.simple.v1+json")
+        .send()?;
+    assert_eq!(
+        response.headers()["content-type"],
+        "application/vnd.pypi.simple.v1+json"
+    );
+    let project: serde_json::Value = response.json()?;
+    assert_eq!(project["name"], "ok");
+    assert_eq!(project["versions"], serde_json::json!(["1.0.0", "2.0.0"]));
+    let file = &project["files"][0];
+    assert_eq!(file["filename"], "ok-1.0.0-py3-none-any.whl");
+    assert_eq!(file["url"], "../../files/ok-1.0.0-py3-none-any.whl");
+    assert!(file["hashes"]["sha256"].is_string());
+    assert!(file["core-metadata"]["sha256"].is_string());
+
+    // The project page should fall back to HTML.
+    let response = client.get(format!("{}ok/", server.index_url())).send()?;
+    assert_eq!(response.headers()["content-type"], "text/html");
+    assert!(response.text()?.contains(">ok-2.0.0-py3-none-any.whl</a>"));
+
+    // Non-normalized names should redirect to the normalized name.
+    let response = client.get(format!("{}OK/", server.index_url())).send()?;
+    assert_eq!(response.url().path(), "/simple/ok/");
+
+    // The core metadata should be served alongside each wheel.
+    let metadata = client
+        .get(format!(
+            "{}files/ok-1.0.0-py3-none-any.whl.metadata",
+            server.url
+        ))
+        .send()?
+        .error_for_status()?
+        .text()?;
+    assert!(metadata.contains("Name: ok"));
+
+    let response = client
+        .get(format!("{}simple/missing/", server.url))
+        .send()?;
+    assert_eq!(response.status(), 404);
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok")
+        .arg("--index-url")
+        .arg(server.index_url())
+        // The upload time of each file is its modification time.
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==2.0.0
+    "###);
+
+    Ok(())
+}
+
+/// Publish to the index with `--upload`, and install from it.
+#[test]
+fn serve_upload() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheels = context.temp_dir.child("wheels");
+    wheels.create_dir_all()?;
+
+    let mut command = context.index_serve();
+    command.arg("--dir").arg(wheels.path()).arg("--upload");
+    let server = Server::spawn(command)?;
+
+    let server_filter = regex::escape(&server.url);
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(server_filter.as_str(), "[SERVER]/")])
+        .collect::<Vec<_>>();
+    let wheel = context
+        .workspace_root
+        .join("scripts/links/ok-1.0.0-py3-none-any.whl");
+
+    uv_snapshot!(filters, context.publish()
+        .arg("--username")
+        .arg("local")
+        .arg("--password")
+        .arg("local")
+        .arg("--publish-url")
+        .arg(format!("{}legacy/", server.url))
+        .arg(&wheel), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Publishing 1 file to [SERVER]/legacy/
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    "###);
+
+    wheels
+        .child("ok-1.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+
+    // Uploading an identical file should succeed.
+    uv_snapshot!(filters, context.publish()
+        .arg("--username")
+        .arg("local")
+        .arg("--password")
+        .arg("local")
+        .arg("--publish-url")
+        .arg(format!("{}legacy/", server.url))
+        .arg(&wheel), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Publishing 1 file to [SERVER]/legacy/
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    "###);
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok")
+        .arg("--index-url")
+        .arg(server.index_url())
+        // The upload time of each file is its modification time.
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    "###);
+
+    // Uploading a file that differs from the existing file with the same name should fail.
+    wheels.child("ok-1.0.0-py3-none-any.whl").write_file(
+        &context
+            .workspace_root
+            .join("scripts/links/ok-2.0.0-py3-none-any.whl"),
+    )?;
+
+    uv_snapshot!(filters, context.publish()
+        .arg("--username")
+        .arg("local")
+        .arg("--password")
+        .arg("local")
+        .arg("--publish-url")
+        .arg(format!("{}legacy/", server.url))
+        .arg(&wheel), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Publishing 1 file to [SERVER]/legacy/
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    error: Failed to publish `[WORKSPACE]/scripts/links/ok-1.0.0-py3-none-any.whl` to [SERVER]/legacy/
+      Caused by: Upload failed with status code 409 Conflict. Server says: File already exists: ok-1.0.0-py3-none-any.whl
+    "###);
+
+    Ok(())
+}
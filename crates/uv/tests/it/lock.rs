@@ -5,6 +5,8 @@ use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
 use std::io::BufReader;
 use url::Url;
+use wiremock::matchers::{any, basic_auth, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use crate::common::{
     self, build_vendor_links_url, decode_token, download_to_disk, packse_index_url, uv_snapshot,
@@ -8707,6 +8709,132 @@ fn lock_relative_index() -> Result<()> {
     Ok(())
 }
 
+/// Resolve against an index that's unavailable, failing over to a mirror.
+#[tokio::test]
+async fn lock_index_mirror_failover() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // The index is unavailable.
+    let canonical = MockServer::start().await;
+    Mock::given(any())
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&canonical)
+        .await;
+
+    // The mirror requires its own credentials, and uses relative links.
+    let mirror = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/simple/ok/"))
+        .and(basic_auth("mirror", "password"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"<a href="../../files/ok-1.0.0-py3-none-any.whl#sha256=79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f">ok-1.0.0-py3-none-any.whl</a>"#,
+            "text/html",
+        ))
+        .mount(&mirror)
+        .await;
+    Mock::given(path("/files/ok-1.0.0-py3-none-any.whl"))
+        .and(basic_auth("mirror", "password"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_bytes(fs_err::read(
+                context
+                    .workspace_root
+                    .join("scripts/links/ok-1.0.0-py3-none-any.whl"),
+            )?),
+        )
+        .mount(&mirror)
+        .await;
+    Mock::given(any())
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&mirror)
+        .await;
+
+    let mirror_url = Url::parse(&mirror.uri())?;
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (regex::escape(&canonical.uri()), "[CANONICAL]".to_string()),
+            (regex::escape(&mirror.uri()), "[MIRROR]".to_string()),
+        ])
+        .collect::<Vec<_>>();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [[tool.uv.index]]
+        name = "internal"
+        url = "{}/simple"
+        mirrors = ["http://mirror:password@{}:{}/simple"]
+        default = true
+        "#,
+        canonical.uri(),
+        mirror_url.host_str().unwrap(),
+        mirror_url.port().unwrap(),
+    })?;
+
+    uv_snapshot!(filters, context.lock().env_remove(EnvVars::UV_EXCLUDE_NEWER), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // The lockfile refers to the canonical index, without the mirror's credentials.
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => filters.clone(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 2
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "foo"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "ok" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "ok" }]
+
+        [[package]]
+        name = "ok"
+        version = "1.0.0"
+        source = { registry = "[CANONICAL]/simple" }
+        wheels = [
+            { url = "[CANONICAL]/files/ok-1.0.0-py3-none-any.whl", hash = "sha256:79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f" },
+        ]
+        "#
+        );
+    });
+
+    // Install from the lockfile, which again requires failing over to the mirror.
+    uv_snapshot!(filters, context.sync().arg("--frozen").env_remove(EnvVars::UV_EXCLUDE_NEWER), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    "###);
+
+    Ok(())
+}
+
 /// Lock a package that's excluded from the parent workspace, but depends on that parent.
 #[test]
 fn lock_no_sources() -> Result<()> {
@@ -17812,6 +17940,94 @@ fn lock_simplified_environments() -> Result<()> {
     Ok(())
 }
 
+/// Lock for a Pyodide (`wasm32-emscripten`) environment.
+#[test]
+fn lock_emscripten_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "colorama ; sys_platform == 'win32'"]
+
+        [tool.uv]
+        environments = ["sys_platform == 'emscripten'"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    // Because we're only locking for Emscripten, `colorama` should not be included.
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 2
+        requires-python = ">=3.12"
+        resolution-markers = [
+            "sys_platform == 'emscripten'",
+        ]
+        supported-markers = [
+            "sys_platform == 'emscripten'",
+        ]
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:11.254Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892, upload-time = "2023-01-07T11:08:09.864Z" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig", marker = "sys_platform == 'emscripten'" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "colorama", marker = "sys_platform == 'win32'" },
+            { name = "iniconfig" },
+        ]
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn lock_dependency_metadata() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -20351,6 +20567,84 @@ fn lock_dry_run() -> Result<()> {
     Ok(())
 }
 
+/// Import the pinned versions from a `pylock.toml` file.
+#[test]
+fn lock_from_pylock() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    context
+        .export()
+        .arg("-o")
+        .arg("pylock.toml")
+        .assert()
+        .success();
+
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+
+    // Relax the `anyio` requirement, and add a requirement that conflicts with the pinned `idna`.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio", "idna==3.4"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--preview").arg("--from-pylock").arg("pylock.toml").arg("--dry-run"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Keep anyio v3.7.0
+    Re-resolve idna v3.6 -> v3.4
+    Keep sniffio v1.3.1
+    Would import 3 pinned packages from `pylock.toml` (2 kept, 1 re-resolved)
+    ");
+
+    // The lockfile should not be written in `--dry-run` mode.
+    assert!(!context.temp_dir.join("uv.lock").exists());
+
+    uv_snapshot!(context.filters(), context.lock().arg("--preview").arg("--from-pylock").arg("pylock.toml"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Kept anyio v3.7.0
+    Re-resolved idna v3.6 -> v3.4
+    Kept sniffio v1.3.1
+    Imported 3 pinned packages from `pylock.toml` (2 kept, 1 re-resolved)
+    ");
+
+    // Subsequent locks should respect the imported versions.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn lock_dry_run_noop() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -27332,3 +27626,148 @@ fn lock_omit_wheels_exclude_newer() -> Result<()> {
 
     Ok(())
 }
+
+/// Report the wheel coverage of each package across the declared environments, and fail with
+/// `--require-wheel-coverage` if any environment falls back to a source distribution.
+#[test]
+fn lock_wheel_coverage() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = "==3.12.*"
+        dependencies = ["iniconfig==2.0.0", "markupsafe==2.1.5"]
+
+        [tool.uv]
+        environments = [
+            "sys_platform == 'linux' and platform_machine == 'x86_64'",
+            "sys_platform == 'emscripten'",
+        ]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--wheel-coverage"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==2.0.0
+      wheel: platform_machine == 'x86_64' and sys_platform == 'linux'
+      wheel: sys_platform == 'emscripten'
+    markupsafe==2.1.5
+      wheel: platform_machine == 'x86_64' and sys_platform == 'linux'
+      sdist: sys_platform == 'emscripten'
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    1 of 2 packages has wheels for all 2 declared environments
+    ");
+
+    // Requiring wheel coverage should fail, without writing the lockfile.
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--require-wheel-coverage"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    error: `--require-wheel-coverage` was provided, but the following packages lack a wheel for one or more of the declared environments: `markupsafe==2.1.5` (`sys_platform == 'emscripten'`)
+    ");
+
+    assert!(!context.temp_dir.join("uv.lock").exists());
+
+    // Dropping the uncovered environment should succeed.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = "==3.12.*"
+        dependencies = ["iniconfig==2.0.0", "markupsafe==2.1.5"]
+
+        [tool.uv]
+        environments = [
+            "sys_platform == 'linux' and platform_machine == 'x86_64'",
+        ]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--require-wheel-coverage"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // With an open-ended `requires-python`, the platform is covered but the Python version isn't,
+    // since `markupsafe==2.1.5` only has wheels for CPython 3.12 and earlier.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0", "markupsafe==2.1.5"]
+
+        [tool.uv]
+        environments = [
+            "sys_platform == 'linux' and platform_machine == 'x86_64'",
+        ]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--wheel-coverage"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==2.0.0
+      wheel: platform_machine == 'x86_64' and sys_platform == 'linux'
+    markupsafe==2.1.5
+      sdist: platform_machine == 'x86_64' and sys_platform == 'linux'
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    1 of 2 packages has wheels for all 1 declared environment
+    ");
+
+    Ok(())
+}
+
+/// `--require-wheel-coverage` should fail if no environments are declared, rather than checking
+/// nothing.
+#[test]
+fn lock_require_wheel_coverage_without_environments() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--require-wheel-coverage"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: `--require-wheel-coverage` was provided, but no environments are declared in `tool.uv.environments` or `tool.uv.required-environments`
+    ");
+
+    assert!(!context.temp_dir.join("uv.lock").exists());
+
+    Ok(())
+}
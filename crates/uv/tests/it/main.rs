@@ -11,12 +11,24 @@ mod build;
 #[cfg(feature = "python")]
 mod build_backend;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod bundle;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_clean;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_export;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_stats;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_verify;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "test-ecosystem"))]
 mod ecosystem;
 
@@ -28,6 +40,9 @@ mod export;
 
 mod help;
 
+#[cfg(feature = "python")]
+mod index_serve;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "git"))]
 mod init;
 
@@ -98,6 +113,12 @@ mod sync;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_dir;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod tool_doctor;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod tool_export;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_install;
 
@@ -12475,6 +12475,189 @@ fn compile_index_url_first_match_all_versions() -> Result<()> {
     Ok(())
 }
 
+/// With `unsafe-best-match` in preview, versions from multiple indexes are only merged if the indexes
+/// declare each other via `tracks` or `alternate-locations` (PEP 708).
+///
+/// In this case, `ok` 1.0.0 is hosted on the "extra" index, and `ok` 2.0.0 is hosted on the
+/// "primary" index.
+#[test]
+fn compile_index_url_unsafe_alternate_locations() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("ok")?;
+
+    let links = Url::from_directory_path(context.workspace_root.join("scripts/links/")).unwrap();
+    let primary = context.temp_dir.child("primary");
+    let extra = context.temp_dir.child("extra");
+    let primary_url = Url::from_directory_path(primary.path()).unwrap();
+    let extra_url = Url::from_directory_path(extra.path()).unwrap();
+    let primary_project = primary_url.join("ok/")?;
+    let extra_project = extra_url.join("ok/")?;
+
+    // Write the project page for `ok` to the given index, with the given `<meta>` tags.
+    let write_index = |index: &assert_fs::fixture::ChildPath, version: &str, meta: &str| {
+        index
+            .child("ok")
+            .child("index.html")
+            .write_str(&indoc::formatdoc! {r#"
+            <!DOCTYPE html>
+            <html>
+              <head>
+                <meta name="pypi:repository-version" content="1.1" />
+                {meta}
+              </head>
+              <body>
+                <h1>Links for ok</h1>
+                <a href="{links}ok-{version}-py3-none-any.whl">ok-{version}-py3-none-any.whl</a>
+              </body>
+            </html>
+        "#})
+    };
+
+    // Neither index declares the other.
+    write_index(&primary, "2.0.0", "")?;
+    write_index(&extra, "1.0.0", "")?;
+
+    // Outside of preview, the declarations aren't enforced, so the versions are merged.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--index-strategy")
+        .arg("unsafe-best-match")
+        .arg("--index-url")
+        .arg(primary_url.as_str())
+        .arg("--extra-index-url")
+        .arg(extra_url.as_str())
+        .arg("requirements.in")
+        .arg("--no-deps"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --index-strategy unsafe-best-match requirements.in --no-deps
+    ok==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // In preview, the versions can't be merged.
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--index-strategy")
+        .arg("unsafe-best-match")
+        .arg("--index-url")
+        .arg(primary_url.as_str())
+        .arg("--extra-index-url")
+        .arg(extra_url.as_str())
+        .arg("requirements.in")
+        .arg("--no-deps")
+        .arg("--preview"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `ok` was found on multiple indexes (`file://[TEMP_DIR]/extra/`, `file://[TEMP_DIR]/primary/`), but the indexes don't declare each other via `tracks` or `alternate-locations`
+    "###
+    );
+
+    // A one-sided declaration is insufficient.
+    write_index(
+        &extra,
+        "1.0.0",
+        &format!(r#"<meta name="pypi:alternate-locations" content="{primary_project}" />"#),
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--index-strategy")
+        .arg("unsafe-best-match")
+        .arg("--index-url")
+        .arg(primary_url.as_str())
+        .arg("--extra-index-url")
+        .arg(extra_url.as_str())
+        .arg("requirements.in")
+        .arg("--no-deps")
+        .arg("--preview"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `ok` was found on multiple indexes (`file://[TEMP_DIR]/extra/`, `file://[TEMP_DIR]/primary/`), but the indexes don't declare each other via `tracks` or `alternate-locations`
+    "###
+    );
+
+    // If both indexes list each other as alternate locations, the highest version is selected.
+    write_index(
+        &primary,
+        "2.0.0",
+        &format!(r#"<meta name="pypi:alternate-locations" content="{extra_project}" />"#),
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--index-strategy")
+        .arg("unsafe-best-match")
+        .arg("--index-url")
+        .arg(primary_url.as_str())
+        .arg("--extra-index-url")
+        .arg(extra_url.as_str())
+        .arg("requirements.in")
+        .arg("--no-deps")
+        .arg("--preview"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --index-strategy unsafe-best-match requirements.in --no-deps --preview
+    ok==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // If the "extra" index tracks the "primary" index (e.g., as a mirror), the versions are merged.
+    write_index(&primary, "2.0.0", "")?;
+    write_index(
+        &extra,
+        "1.0.0",
+        &format!(r#"<meta name="pypi:tracks" content="{primary_project}" />"#),
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--index-strategy")
+        .arg("unsafe-best-match")
+        .arg("--index-url")
+        .arg(primary_url.as_str())
+        .arg("--extra-index-url")
+        .arg(extra_url.as_str())
+        .arg("requirements.in")
+        .arg("--no-deps")
+        .arg("--preview"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --index-strategy unsafe-best-match requirements.in --no-deps --preview
+    ok==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Install a package via `--extra-index-url`.
 ///
 /// If the package exists exist on the "extra" index, but at an incompatible version, the
@@ -12925,6 +13108,55 @@ fn python_platform() -> Result<()> {
     Ok(())
 }
 
+/// Compile for Pyodide, i.e., the `wasm32-emscripten` platform, which is tied to Python 3.12.
+#[test]
+fn python_platform_emscripten() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        iniconfig ; sys_platform == 'emscripten'
+        colorama ; sys_platform == 'win32'
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--python-platform")
+        .arg("wasm32-emscripten")
+        .arg("--python-version")
+        .arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-platform wasm32-emscripten --python-version 3.12
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // Pyodide 2024.0 is only compatible with Python 3.12.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--python-platform")
+        .arg("wasm32-emscripten")
+        .arg("--python-version")
+        .arg("3.11"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Pyodide 2024.0 requires Python 3.12, but Python 3.11 was requested
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a specific source distribution via a Git HTTPS dependency.
 #[test]
 #[cfg(feature = "git")]
@@ -2714,6 +2714,144 @@ fn install_no_binary_cache() {
     );
 }
 
+/// Entries that are missing from the cache should be imported from a fallback cache.
+#[test]
+fn install_fallback_cache() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Populate the cache.
+    uv_snapshot!(
+        context.pip_install().arg("idna"),
+        @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + idna==3.6
+    "###
+    );
+
+    // Move the populated cache elsewhere, leaving the cache directory empty.
+    let fallback = context.temp_dir.child("fallback-cache");
+    fs::rename(&context.cache_dir, &fallback)?;
+    fs::create_dir_all(&context.cache_dir)?;
+
+    // Re-create the virtual environment.
+    context.venv().assert().success();
+
+    // Re-install offline. The distribution should be installed from the fallback cache.
+    uv_snapshot!(
+        context.pip_install()
+            .arg("idna")
+            .arg("--offline")
+            .arg("--fallback-cache-dir")
+            .arg(fallback.path()),
+        @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + idna==3.6
+    "###
+    );
+
+    // The distribution should have been imported into the cache.
+    assert!(context.cache_dir.child("archive-v0").exists());
+
+    Ok(())
+}
+
+/// Entries should be imported from a fallback cache that's read-only, and (if available) on a
+/// different filesystem than the cache directory, in which case archives are copied rather than
+/// linked.
+#[test]
+#[cfg(target_os = "linux")]
+fn install_fallback_cache_read_only() -> Result<()> {
+    use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+    let context = TestContext::new("3.12");
+
+    // Populate the cache.
+    context.pip_install().arg("idna").assert().success();
+
+    // Prefer a fallback cache on another filesystem, if one is available.
+    let device = |path: &std::path::Path| fs::metadata(path).map(|metadata| metadata.dev()).ok();
+    let shm = tempfile::tempdir_in("/dev/shm")
+        .ok()
+        .filter(|dir| device(dir.path()) != device(context.temp_dir.path()));
+    let fallback = match &shm {
+        Some(dir) => dir.path().join("fallback-cache"),
+        None => context.temp_dir.child("fallback-cache").to_path_buf(),
+    };
+
+    // Move the populated cache into the fallback cache, retaining any links as-is, and make it
+    // read-only.
+    for entry in walkdir::WalkDir::new(&context.cache_dir) {
+        let entry = entry?;
+        let target = fallback.join(entry.path().strip_prefix(&context.cache_dir)?);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target)?;
+        } else if entry.file_type().is_symlink() {
+            fs_err::os::unix::fs::symlink(fs::read_link(entry.path())?, &target)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    fs::remove_dir_all(&context.cache_dir)?;
+    fs::create_dir_all(&context.cache_dir)?;
+
+    let set_mode = |file_mode: u32, dir_mode: u32| -> Result<()> {
+        for entry in walkdir::WalkDir::new(&fallback).contents_first(true) {
+            let entry = entry?;
+            if entry.file_type().is_dir() {
+                fs::set_permissions(entry.path(), std::fs::Permissions::from_mode(dir_mode))?;
+            } else if entry.file_type().is_file() {
+                fs::set_permissions(entry.path(), std::fs::Permissions::from_mode(file_mode))?;
+            }
+        }
+        Ok(())
+    };
+    set_mode(0o444, 0o555)?;
+
+    // Re-create the virtual environment.
+    context.venv().assert().success();
+
+    // Re-install offline, providing the fallback cache via the environment as a list of paths.
+    let fallbacks =
+        std::env::join_paths([context.temp_dir.child("missing").path(), fallback.as_path()])?;
+    uv_snapshot!(
+        context.pip_install()
+            .arg("idna")
+            .arg("--offline")
+            .env(EnvVars::UV_FALLBACK_CACHE_DIR, &fallbacks),
+        @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + idna==3.6
+    "###
+    );
+
+    // The distribution should have been imported into the cache.
+    assert!(context.cache_dir.child("archive-v0").exists());
+
+    // Restore the permissions, such that the fallback cache can be removed.
+    set_mode(0o644, 0o755)?;
+
+    Ok(())
+}
+
 /// Respect `--only-binary` flags in `requirements.txt`
 #[test]
 fn only_binary_requirements_txt() {
@@ -7987,6 +8125,106 @@ fn local_index_fallback() -> Result<()> {
     Ok(())
 }
 
+/// Resolve against a local index on which the project is quarantined (PEP 792).
+#[test]
+fn local_index_quarantined() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let root = context.temp_dir.child("simple-html");
+    fs_err::create_dir_all(&root)?;
+
+    let tqdm = root.child("tqdm");
+    fs_err::create_dir_all(&tqdm)?;
+
+    let index = tqdm.child("index.html");
+    index.write_str(
+        r#"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <meta name="pypi:repository-version" content="1.4" />
+            <meta name="pypi:project-status" content="quarantined" />
+            <meta name="pypi:project-status-reason" content="Suspected malware" />
+          </head>
+          <body>
+            <h1>Links for tqdm</h1>
+          </body>
+        </html>
+    "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm")
+        .arg("--index-url")
+        .arg(Url::from_directory_path(root).unwrap().as_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because tqdm was quarantined by the package registry and you require tqdm, we can conclude that your requirements are unsatisfiable.
+
+          hint: `tqdm` was quarantined by the package registry:
+            Suspected malware
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve against a local index on which the project is archived (PEP 792).
+#[test]
+fn local_index_archived() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let root = context.temp_dir.child("simple-html");
+    fs_err::create_dir_all(&root)?;
+
+    let tqdm = root.child("tqdm");
+    fs_err::create_dir_all(&tqdm)?;
+
+    let index = tqdm.child("index.html");
+    index.write_str(&indoc::formatdoc! {r#"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <meta name="pypi:repository-version" content="1.4" />
+            <meta name="pypi:project-status" content="archived" />
+          </head>
+          <body>
+            <h1>Links for tqdm</h1>
+            <a
+              href="{}/tqdm-1000.0.0-py3-none-any.whl"
+              data-requires-python=">=3.8"
+            >
+              tqdm-1000.0.0-py3-none-any.whl
+            </a>
+          </body>
+        </html>
+    "#, Url::from_directory_path(context.workspace_root.join("scripts/links/")).unwrap().as_str()})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("tqdm")
+        .arg("--index-url")
+        .arg(Url::from_directory_path(root).unwrap().as_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    warning: `tqdm` is archived
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 fn accept_existing_prerelease() -> Result<()> {
     let context = TestContext::new("3.12").with_filtered_counts();
@@ -3,7 +3,7 @@ use std::{env, path::Path, process::Command};
 use crate::common::{uv_snapshot, TestContext};
 use assert_fs::{
     assert::PathAssert,
-    prelude::{FileTouch, PathChild, PathCreateDir},
+    prelude::{FileTouch, FileWriteStr, PathChild, PathCreateDir},
 };
 use predicates::prelude::predicate;
 use tracing::debug;
@@ -179,6 +179,113 @@ fn python_reinstall_patch() {
     ");
 }
 
+#[test]
+fn python_upgrade() {
+    use assert_cmd::assert::OutputAssertExt;
+
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // Install an old patch version
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12.6"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.6 in [TIME]
+     + cpython-3.12.6-[PLATFORM]
+    ");
+
+    // Create a virtual environment that uses it
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.12.6")
+        .assert()
+        .success();
+
+    // Upgrade to the latest patch version, removing the superseded version
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("--remove-superseded"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Upgraded Python 3.12.10 in [TIME]
+     - cpython-3.12.6-[PLATFORM]
+     + cpython-3.12.10-[PLATFORM]
+    Updated 1 environment to use the latest patch release
+    ");
+
+    // The virtual environment should use the latest patch version
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.join("pyvenv.cfg")).unwrap();
+    assert!(pyvenv_cfg.contains("version_info = 3.12.10"));
+    assert!(!pyvenv_cfg.contains("3.12.6"));
+
+    // Upgrading again should be a no-op
+    uv_snapshot!(context.filters(), context.python_upgrade(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    All Python versions are up-to-date
+    ");
+}
+
+/// The project environment should be found via `UV_PROJECT_ENVIRONMENT`, rather than assuming
+/// `.venv`.
+#[test]
+fn python_upgrade_project_environment() -> anyhow::Result<()> {
+    use assert_cmd::assert::OutputAssertExt;
+
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    context.python_install().arg("3.12.6").assert().success();
+
+    context.temp_dir.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    )?;
+
+    // Create the project environment at a custom location
+    context
+        .venv()
+        .arg("custom-venv")
+        .arg("--python")
+        .arg("3.12.6")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.python_upgrade().env(EnvVars::UV_PROJECT_ENVIRONMENT, "custom-venv"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Upgraded Python 3.12.10 in [TIME]
+     + cpython-3.12.10-[PLATFORM]
+    Updated 1 environment to use the latest patch release
+    ");
+
+    let pyvenv_cfg =
+        fs_err::read_to_string(context.temp_dir.join("custom-venv").join("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("version_info = 3.12.10"));
+
+    Ok(())
+}
+
 #[test]
 fn python_install_automatic() {
     let context: TestContext = TestContext::new_with_versions(&[])
@@ -672,6 +779,53 @@ fn python_install_freethreaded() {
     ");
 }
 
+/// Install a free-threaded debug build, which is only published for Linux.
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn python_install_freethreaded_debug() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.13+freethreaded+debug"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.3 in [TIME]
+     + cpython-3.13.3+freethreaded+debug-[PLATFORM]
+    ");
+
+    // The interpreter should be both free-threaded and a debug build
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--python")
+        .arg("3.13+freethreaded+debug")
+        .arg("python")
+        .arg("-c")
+        .arg("import sys, sysconfig; print(sysconfig.get_config_var('Py_GIL_DISABLED'), hasattr(sys, 'gettotalrefcount'))"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    1 True
+
+    ----- stderr -----
+    ");
+
+    // Should be distinct from the free-threaded release build
+    uv_snapshot!(context.filters(), context.python_install().arg("3.13t"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.3 in [TIME]
+     + cpython-3.13.3+freethreaded-[PLATFORM]
+    ");
+}
+
 #[test]
 fn python_install_invalid_request() {
     let context: TestContext = TestContext::new_with_versions(&[])
@@ -1380,3 +1534,179 @@ fn python_install_cached() {
       Caused by: An offline Python installation was requested, but cpython-3.12.10[DATE]-[PLATFORM].tar.gz) is missing in python-cache
     ");
 }
+
+/// Test installing Python from local archives.
+#[test]
+fn python_install_from_archive() {
+    use assert_cmd::assert::OutputAssertExt;
+
+    // It does not make sense to run this test when the developer selected faster test runs
+    // by setting the env var.
+    if env::var_os("UV_PYTHON_CACHE_DIR").is_some() {
+        debug!("Skipping test because UV_PYTHON_CACHE_DIR is set");
+        return;
+    }
+
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // Populate the Python cache, to retrieve an archive to install from
+    let python_cache = context.temp_dir.child("python-cache");
+    context
+        .python_install()
+        .arg("3.13")
+        .env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.as_ref())
+        .assert()
+        .success();
+    context.python_uninstall().arg("3.13").assert().success();
+
+    // Restore the published file name of the cached archive, e.g.,
+    // `cpython-3.13.3+20250409-x86_64-unknown-linux-gnu-install_only_stripped.tar.gz`
+    let cached = fs_err::read_dir(&python_cache)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .file_name()
+        .into_string()
+        .unwrap();
+    let (_, filename) = cached.split_once('-').unwrap();
+    let filename = filename.replacen("cpython-3.13.3-", "cpython-3.13.3+", 1);
+    let archives = context.temp_dir.child("archives");
+    archives.create_dir_all().unwrap();
+    let archive = archives.child(&filename);
+    fs_err::copy(python_cache.child(&cached), &archive).unwrap();
+
+    // Install from the archive, without network access
+    uv_snapshot!(context.filters(), context
+        .python_install()
+        .arg(archive.as_os_str())
+        .arg("--offline"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.3 in [TIME]
+     + cpython-3.13.3-[PLATFORM]
+    ");
+
+    context.python_uninstall().arg("3.13").assert().success();
+
+    // Install from a directory of archives, even if downloads are disabled
+    uv_snapshot!(context.filters(), context
+        .python_install()
+        .arg("3.13")
+        .arg("--archive-dir")
+        .arg(archives.as_os_str())
+        .arg("--offline")
+        .arg("--no-python-downloads"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.3 in [TIME]
+     + cpython-3.13.3-[PLATFORM]
+    ");
+
+    // An archive must match a known Python download
+    let unknown = context.temp_dir.child("python.tar.gz");
+    unknown.touch().unwrap();
+    uv_snapshot!(context.filters(), context
+        .python_install()
+        .arg(unknown.as_os_str())
+        .arg("--offline"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `python.tar.gz` is not a known Python distribution archive; the file name must match an entry in the Python downloads list
+    ");
+}
+
+#[cfg(unix)]
+#[test]
+fn python_shim() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    let shim_dir = context.temp_dir.child("shims");
+
+    context.python_install().arg("3.12").assert().success();
+
+    // Shims are only created on request
+    uv_snapshot!(context.filters(), context.python_shim().arg("list").arg("--preview"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No Python shims found in `[TEMP_DIR]/shims`. Use `uv python shim rebuild` to create them.
+    ");
+
+    uv_snapshot!(context.filters(), context
+        .python_shim()
+        .arg("rebuild")
+        .arg("--preview")
+        .env(EnvVars::PATH, shim_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Created 3 shims in `[TEMP_DIR]/shims`
+     + python
+     + python3
+     + python3.12
+    ");
+
+    uv_snapshot!(context.filters(), context.python_shim().arg("list").arg("--preview"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/shims/python
+    [TEMP_DIR]/shims/python3
+    [TEMP_DIR]/shims/python3.12
+
+    ----- stderr -----
+    ");
+
+    // The shim should respect the pinned Python version
+    context
+        .temp_dir
+        .child(".python-version")
+        .write_str("3.12")
+        .unwrap();
+    uv_snapshot!(context.filters(), Command::new(shim_dir.child("python"))
+        .arg("-c")
+        .arg("import sys; print(sys.version_info[:2])")
+        .env(EnvVars::UV_PYTHON_INSTALL_DIR, context.temp_dir.child("managed").as_os_str())
+        .env(EnvVars::UV_CACHE_DIR, context.cache_dir.as_os_str())
+        .env(EnvVars::UV_PYTHON_DOWNLOADS, "never")
+        .current_dir(&context.temp_dir), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    (3, 12)
+
+    ----- stderr -----
+    ");
+
+    // Installing a new minor version should add a shim
+    context.python_install().arg("3.13").assert().success();
+    shim_dir
+        .child("python3.13")
+        .assert(predicate::path::exists());
+
+    // Uninstalling it should remove the shim
+    context.python_uninstall().arg("3.13").assert().success();
+    shim_dir
+        .child("python3.13")
+        .assert(predicate::path::missing());
+}
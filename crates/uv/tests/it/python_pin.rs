@@ -1,12 +1,13 @@
 use std::path::PathBuf;
 
-use crate::common::{uv_snapshot, TestContext};
+use crate::common::{apply_filters, uv_snapshot, TestContext};
 use anyhow::Result;
 use assert_fs::fixture::{FileWriteStr, PathChild, PathCreateDir};
 use insta::assert_snapshot;
 use uv_python::{
     platform::{Arch, Os},
-    PYTHON_VERSIONS_FILENAME, PYTHON_VERSION_FILENAME,
+    MISE_TOML_FILENAME, PYTHON_VERSIONS_FILENAME, PYTHON_VERSION_FILENAME, RUNTIME_TXT_FILENAME,
+    TOOL_VERSIONS_FILENAME,
 };
 
 #[test]
@@ -814,3 +815,119 @@ fn python_pin_with_comments() -> Result<()> {
 
     Ok(())
 }
+
+/// Read and update Python versions declared in files used by other tools.
+#[test]
+fn python_pin_tool_version_files() -> Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    // A `.tool-versions` file, as used by asdf and mise
+    let tool_versions = context.temp_dir.child(TOOL_VERSIONS_FILENAME);
+    tool_versions.write_str("nodejs 20.1.0\npython 3.11 # the default\n")?;
+
+    uv_snapshot!(context.filters(), context.python_pin(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.11
+
+    ----- stderr -----
+    ");
+
+    // The Python version is updated in place, retaining the other tools and comments. asdf
+    // requires a full version, so the request is resolved to an interpreter
+    uv_snapshot!(context.filters(), context.python_pin().arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Updated `.tool-versions` from `3.11` -> `3.12.[X]`
+
+    ----- stderr -----
+    ");
+
+    let contents = apply_filters(context.read(TOOL_VERSIONS_FILENAME), context.filters());
+    assert_snapshot!(contents, @r"
+    nodejs 20.1.0
+    python 3.12.[X] # the default
+    ");
+    assert!(!context.temp_dir.child(PYTHON_VERSION_FILENAME).exists());
+
+    // Only versions can be written to other tools' files
+    uv_snapshot!(context.filters(), context.python_pin().arg("cpython@3.12"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Only Python versions can be pinned in `.tool-versions`, but `cpython@3.12` was requested
+    ");
+
+    // A `.tool-versions` file without a Python version is ignored
+    tool_versions.write_str("nodejs 20.1.0\n")?;
+
+    // A `mise.toml` file
+    let mise_toml = context.temp_dir.child(MISE_TOML_FILENAME);
+    mise_toml.write_str(indoc::indoc! {r#"
+        [tools]
+        node = "20"
+        python = "3.11"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Updated `mise.toml` from `3.11` -> `3.12`
+
+    ----- stderr -----
+    ");
+
+    assert_snapshot!(context.read(MISE_TOML_FILENAME), @r#"
+    [tools]
+    node = "20"
+    python = "3.12"
+    "#);
+    fs_err::remove_file(&mise_toml)?;
+
+    // A Heroku-style `runtime.txt` file
+    let runtime_txt = context.temp_dir.child(RUNTIME_TXT_FILENAME);
+    runtime_txt.write_str("python-3.11.9\n")?;
+
+    uv_snapshot!(context.filters(), context.python_pin(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.11.[X]
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Updated `runtime.txt` from `3.11.[X]` -> `3.12.[X]`
+
+    ----- stderr -----
+    ");
+
+    let contents = apply_filters(context.read(RUNTIME_TXT_FILENAME), context.filters());
+    assert_snapshot!(contents, @"python-3.12.[X]");
+
+    // A `.python-version` file takes precedence
+    context
+        .temp_dir
+        .child(PYTHON_VERSION_FILENAME)
+        .write_str("3.11\n")?;
+
+    uv_snapshot!(context.filters(), context.python_pin(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.11
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
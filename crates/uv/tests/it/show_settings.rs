@@ -82,6 +82,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -139,6 +140,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -263,6 +268,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -320,6 +326,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -445,6 +455,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -502,6 +513,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -659,6 +674,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -716,6 +732,10 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -842,6 +862,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -1002,6 +1023,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -1059,6 +1081,10 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -1210,6 +1236,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -1267,6 +1294,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                     Index {
                         name: None,
@@ -1299,6 +1330,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -1425,6 +1460,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -1484,6 +1520,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                     Index {
                         name: None,
@@ -1516,6 +1556,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                     Index {
                         name: None,
@@ -1548,6 +1592,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -1697,6 +1745,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -1755,6 +1804,10 @@ fn resolve_find_links() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 no_index: true,
@@ -1902,6 +1955,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -2067,6 +2121,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -2124,6 +2179,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                     Index {
                         name: None,
@@ -2156,6 +2215,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -2280,6 +2343,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -2337,6 +2401,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                     Index {
                         name: None,
@@ -2369,6 +2437,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -2516,6 +2588,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -2671,6 +2744,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -2826,6 +2900,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -2983,6 +3058,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -3159,10 +3235,14 @@ fn resolve_tool() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     ToolInstallSettings {
-        package: "requirements.in",
+        package: Some(
+            "requirements.in",
+        ),
         from: None,
+        from_manifest: None,
         with: [],
         with_requirements: [],
         with_editable: [],
@@ -3244,6 +3324,8 @@ fn resolve_tool() -> anyhow::Result<()> {
         },
         force: false,
         editable: false,
+        locked: false,
+        frozen: false,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
@@ -3324,6 +3406,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -3507,6 +3590,7 @@ fn resolve_both() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -3564,6 +3648,10 @@ fn resolve_both() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -3813,6 +3901,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -3870,6 +3959,10 @@ fn resolve_config_file() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -4095,6 +4188,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -4253,6 +4347,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -4430,6 +4525,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -4599,6 +4695,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -4658,6 +4755,10 @@ fn index_priority() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                     Index {
                         name: None,
@@ -4690,6 +4791,10 @@ fn index_priority() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -4814,6 +4919,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -4873,6 +4979,10 @@ fn index_priority() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                     Index {
                         name: None,
@@ -4905,6 +5015,10 @@ fn index_priority() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -5035,6 +5149,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -5094,6 +5209,10 @@ fn index_priority() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                     Index {
                         name: None,
@@ -5126,6 +5245,10 @@ fn index_priority() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -5251,6 +5374,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -5310,6 +5434,10 @@ fn index_priority() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                     Index {
                         name: None,
@@ -5342,6 +5470,10 @@ fn index_priority() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -5474,6 +5606,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -5533,6 +5666,10 @@ fn index_priority() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                     Index {
                         name: None,
@@ -5565,6 +5702,10 @@ fn index_priority() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -5690,6 +5831,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipCompileSettings {
         format: None,
@@ -5749,6 +5891,10 @@ fn index_priority() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                     Index {
                         name: None,
@@ -5781,6 +5927,10 @@ fn index_priority() -> anyhow::Result<()> {
                         publish_url: None,
                         authenticate: Auto,
                         ignore_error_codes: None,
+                        mirrors: [],
+                        client_cert: None,
+                        client_key: None,
+                        ca_bundle: None,
                     },
                 ],
                 flat_index: [],
@@ -5919,6 +6069,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipInstallSettings {
         package: [],
@@ -6067,6 +6218,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipInstallSettings {
         package: [],
@@ -6213,6 +6365,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipInstallSettings {
         package: [],
@@ -6361,6 +6514,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipInstallSettings {
         package: [],
@@ -6507,6 +6661,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipInstallSettings {
         package: [],
@@ -6654,6 +6809,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        fallback_cache_dirs: [],
     }
     PipInstallSettings {
         package: [],
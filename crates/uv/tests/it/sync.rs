@@ -43,6 +43,139 @@ fn sync() -> Result<()> {
     Ok(())
 }
 
+/// Sync the project into a `--target` directory, rather than the project environment.
+#[test]
+fn sync_target() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--target").arg("target"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    // The packages should be installed at the top-level of the target directory.
+    context
+        .temp_dir
+        .child("target")
+        .child("iniconfig")
+        .child("__init__.py")
+        .assert(predicate::path::is_file());
+
+    // Re-running should be a no-op.
+    uv_snapshot!(context.filters(), context.sync().arg("--target").arg("target"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    "###);
+
+    // The project environment should be left untouched.
+    uv_snapshot!(context.filters(), context.pip_list(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// Sync a packaged project into a `--target` directory, which should install the project itself
+/// as non-editable, alongside its dependencies.
+#[test]
+fn sync_target_project() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [project.scripts]
+        project = "project:main"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    let init = context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py");
+    init.write_str("def main():\n    print('Hello')\n")?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--target").arg("target"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    let target = context.temp_dir.child("target");
+
+    // The project's sources should be copied into the target directory, rather than linked to
+    // with a `.pth` file.
+    target
+        .child("project")
+        .child("__init__.py")
+        .assert(predicate::path::is_file());
+    target
+        .child("_project.pth")
+        .assert(predicate::path::missing());
+
+    // The project's entry points should be written to the `bin` subdirectory.
+    target
+        .child("bin")
+        .child(format!("project{}", std::env::consts::EXE_SUFFIX))
+        .assert(predicate::path::is_file());
+
+    // The project should not be installed into the project environment.
+    uv_snapshot!(context.filters(), context.pip_list(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn locked() -> Result<()> {
     let context = TestContext::new("3.12");
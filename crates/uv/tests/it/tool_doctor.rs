@@ -0,0 +1,224 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::PathChild;
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+#[test]
+fn tool_doctor() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_doctor()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No issues found
+    "###);
+
+    // Remove one of the executables.
+    fs_err::remove_file(bin_dir.join(format!("black{}", std::env::consts::EXE_SUFFIX)))?;
+
+    uv_snapshot!(context.filters(), context.tool_doctor()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    black
+    - Executable `[TEMP_DIR]/bin/black` is missing
+
+    ----- stderr -----
+    Found 1 issue in 1 tool
+    Run `uv tool doctor --fix` to reinstall black
+    "###);
+
+    // Repair the tool from its receipt.
+    uv_snapshot!(context.filters(), context.tool_doctor()
+        .arg("--fix")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black
+    - Executable `[TEMP_DIR]/bin/black` is missing
+
+    ----- stderr -----
+    Found 1 issue in 1 tool
+    Resolved [N] packages in [TIME]
+    Uninstalled [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     ~ black==24.2.0
+    Installed 2 executables: black, blackd
+    "###);
+
+    uv_snapshot!(context.filters(), context.tool_doctor()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No issues found
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn tool_doctor_missing_environment() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Remove the environment, but retain the receipt.
+    let receipt = fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml"))?;
+    fs_err::remove_dir_all(tool_dir.join("black"))?;
+    fs_err::create_dir_all(tool_dir.join("black"))?;
+    fs_err::write(tool_dir.join("black").join("uv-receipt.toml"), receipt)?;
+
+    uv_snapshot!(context.filters(), context.tool_doctor()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    black
+    - Executable `[TEMP_DIR]/bin/black` is missing
+    - Executable `[TEMP_DIR]/bin/blackd` is missing
+    - Tool environment is missing at `[TEMP_DIR]/tools/black`
+
+    ----- stderr -----
+    Found 3 issues in 1 tool
+    Run `uv tool doctor --fix` to reinstall black
+    "###);
+
+    Ok(())
+}
+
+/// A tool whose interpreter was removed is repaired, even if one of its executables is shadowed on
+/// the `PATH`.
+#[test]
+#[cfg(unix)]
+fn tool_doctor_missing_interpreter() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    let shadow_dir = context.temp_dir.child("shadow");
+
+    // Install `black`
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Shadow the `black` executable with another executable earlier on the `PATH`.
+    fs_err::create_dir_all(&shadow_dir)?;
+    let shadow = shadow_dir.join("black");
+    fs_err::write(&shadow, "#!/bin/sh\n")?;
+    fs_err::set_permissions(&shadow, std::fs::Permissions::from_mode(0o755))?;
+    let path = std::env::join_paths([shadow_dir.as_os_str(), bin_dir.as_os_str()])?;
+
+    // Remove the interpreter the tool environment is linked to.
+    let python = tool_dir.join("black").join("bin").join("python");
+    fs_err::remove_file(&python)?;
+    fs_err::os::unix::fs::symlink(context.temp_dir.join("missing").join("python"), &python)?;
+
+    uv_snapshot!(context.filters(), context.tool_doctor()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, &path), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    black
+    - Executable `black` is shadowed on the PATH by `[TEMP_DIR]/shadow/black`
+    - Python interpreter is missing at `[TEMP_DIR]/missing/python`
+
+    ----- stderr -----
+    Found 2 issues in 1 tool
+    Run `uv tool doctor --fix` to reinstall black
+    "###);
+
+    // Repair the tool, which recreates its environment.
+    uv_snapshot!(context.filters(), context.tool_doctor()
+        .arg("--fix")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, &path), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black
+    - Executable `black` is shadowed on the PATH by `[TEMP_DIR]/shadow/black`
+    - Python interpreter is missing at `[TEMP_DIR]/missing/python`
+
+    ----- stderr -----
+    Found 2 issues in 1 tool
+    Resolved [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.2.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    "###);
+
+    // The interpreter is restored, and only the shadowed executable is reported.
+    uv_snapshot!(context.filters(), context.tool_doctor()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, &path), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    black
+    - Executable `black` is shadowed on the PATH by `[TEMP_DIR]/shadow/black`
+
+    ----- stderr -----
+    Found 1 issue in 1 tool
+    "###);
+
+    Ok(())
+}
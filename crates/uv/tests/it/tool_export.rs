@@ -0,0 +1,206 @@
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::{FileWriteStr, PathChild};
+use indoc::{formatdoc, indoc};
+use insta::assert_snapshot;
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+#[test]
+fn tool_export() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Nothing is installed yet.
+    uv_snapshot!(context.filters(), context.tool_export()
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No tools installed
+    "###);
+
+    // Install `black`
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_export()
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [tools.black]
+    requirements = [{ name = "black", specifier = "==24.2.0" }]
+
+    [tools.black.options]
+    exclude-newer = "2024-03-25T00:00:00Z"
+
+    ----- stderr -----
+    "###);
+}
+
+#[test]
+fn tool_install_from_manifest() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let manifest = context.temp_dir.child("tools.toml");
+    manifest.write_str(indoc! {r#"
+        [tools.black]
+        requirements = [{ name = "black", specifier = "==24.2.0" }]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("--from-manifest")
+        .arg("tools.toml")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.2.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    "###);
+
+    // Installing from the manifest again is a no-op.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("--from-manifest")
+        .arg("tools.toml")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    `black==24.2.0` is already installed
+    "###);
+
+    let receipt = fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml"))?;
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(receipt, @r###"
+        [tool]
+        requirements = [{ name = "black", specifier = "==24.2.0" }]
+        entrypoints = [
+            { name = "black", install-path = "[TEMP_DIR]/bin/black" },
+            { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
+        ]
+
+        [tool.options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        "###);
+    });
+
+    Ok(())
+}
+
+/// Tools in a manifest that provide the same executable don't overwrite one another.
+#[test]
+fn tool_install_from_manifest_conflicting_executables() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Create two packages that provide the same executable.
+    for name in ["bar", "foo"] {
+        context
+            .temp_dir
+            .child(name)
+            .child("pyproject.toml")
+            .write_str(&formatdoc! {r#"
+                [project]
+                name = "{name}"
+                version = "0.1.0"
+                requires-python = ">=3.12"
+
+                [project.scripts]
+                shared = "{name}:main"
+
+                [build-system]
+                requires = ["setuptools>=42"]
+                build-backend = "setuptools.build_meta"
+            "#})?;
+        context
+            .temp_dir
+            .child(name)
+            .child("src")
+            .child(name)
+            .child("__init__.py")
+            .write_str(&format!("def main():\n    print('{name}')\n"))?;
+    }
+
+    // The manifest doesn't declare any entry points, so the conflict is detected at install time.
+    let manifest = context.temp_dir.child("tools.toml");
+    manifest.write_str(indoc! {r#"
+        [tools.bar]
+        requirements = [{ name = "bar", directory = "bar" }]
+
+        [tools.foo]
+        requirements = [{ name = "foo", directory = "foo" }]
+    "#})?;
+
+    // Install the tools one at a time, for deterministic output.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("--from-manifest")
+        .arg("tools.toml")
+        .env(EnvVars::UV_CONCURRENT_INSTALLS, "1")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + bar==0.1.0 (from file://[TEMP_DIR]/bar)
+    Installed 1 executable: shared
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==0.1.0 (from file://[TEMP_DIR]/foo)
+    error: Failed to install foo
+      Caused by: The tools `bar` and `foo` both provide the executable `shared`
+    "###);
+
+    // The conflicting tool was not installed, and the executable still belongs to `bar`.
+    assert!(tool_dir.join("bar").join("uv-receipt.toml").exists());
+    assert!(!tool_dir.join("foo").exists());
+    uv_snapshot!(context.filters(), Command::new("shared").env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    bar
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
@@ -3627,3 +3627,165 @@ fn tool_install_mismatched_name() {
     error: Package name (`black`) provided with `--from` does not match install request (`flask`)
     "###);
 }
+
+#[test]
+fn tool_install_locked() {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Installing from the lock requires an existing receipt.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .arg("--frozen")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    The `--frozen` option is only available in preview mode; add the `--preview` flag to use `--frozen`
+    "###);
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .arg("--frozen")
+        .arg("--preview")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `black` is not installed, but `--frozen` requires a lock in an existing tool receipt
+    "###);
+
+    // Install `black`, without recording a lock.
+    context
+        .tool_install()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .arg("--locked")
+        .arg("--preview")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The receipt for `black` does not include a lock, but `--locked` was provided. To record a lock, run `uv tool upgrade --preview black`.
+    "###);
+
+    // Record a lock in the receipt.
+    context
+        .tool_upgrade()
+        .arg("black")
+        .arg("--preview")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    let receipt = fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap();
+    assert!(receipt.contains("[tool.lock]"));
+    assert!(receipt.contains("name = \"black\"\nversion = \"24.3.0\""));
+
+    // The lock is out-of-date with respect to the requested requirements.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black>=24")
+        .arg("--locked")
+        .arg("--preview")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The lock for `black` needs to be updated, but `--locked` was provided. To update the lock, run `uv tool install --preview black`.
+    "###);
+
+    // The lock is out-of-date with respect to the resolved versions.
+    let receipt_path = tool_dir.join("black").join("uv-receipt.toml");
+    fs_err::write(
+        &receipt_path,
+        receipt.replace(
+            "name = \"black\"\nversion = \"24.3.0\"",
+            "name = \"black\"\nversion = \"24.2.0\"",
+        ),
+    )
+    .unwrap();
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .arg("--locked")
+        .arg("--preview")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    error: The lock for `black` needs to be updated, but `--locked` was provided. To update the lock, run `uv tool upgrade --preview black`.
+    "###);
+
+    fs_err::write(&receipt_path, &receipt).unwrap();
+
+    // An up-to-date lock is accepted with `--locked`.
+    context
+        .tool_install()
+        .arg("black")
+        .arg("--locked")
+        .arg("--preview")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Install from the lock, without re-resolving.
+    context
+        .tool_install()
+        .arg("black")
+        .arg("--frozen")
+        .arg("--preview")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), Command::new("black").arg("--version").env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black, 24.3.0 (compiled: yes)
+    Python (CPython) 3.12.[X]
+
+    ----- stderr -----
+    "###);
+
+    // The lock is retained in the receipt.
+    let receipt = fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap();
+    assert!(receipt.contains("[tool.lock]"));
+}
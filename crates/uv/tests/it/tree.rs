@@ -5,6 +5,8 @@ use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
 use url::Url;
 
+use uv_static::EnvVars;
+
 use crate::common::{uv_snapshot, TestContext};
 
 #[test]
@@ -271,6 +273,77 @@ fn outdated() -> Result<()> {
     Ok(())
 }
 
+/// Show the status of each project on its index (PEP 792).
+#[test]
+fn project_status() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let root = context.temp_dir.child("simple-html");
+    let tqdm = root.child("tqdm");
+    tqdm.create_dir_all()?;
+
+    let wheel = tqdm.child("tqdm-1000.0.0-py3-none-any.whl");
+    wheel.write_file(
+        &context
+            .workspace_root
+            .join("scripts/links/tqdm-1000.0.0-py3-none-any.whl"),
+    )?;
+
+    tqdm.child("index.html").write_str(&formatdoc! {r#"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <meta name="pypi:repository-version" content="1.4" />
+            <meta name="pypi:project-status" content="deprecated" />
+            <meta name="pypi:project-status-reason" content="Use tqdm2 instead" />
+          </head>
+          <body>
+            <h1>Links for tqdm</h1>
+            <a href="{}">tqdm-1000.0.0-py3-none-any.whl</a>
+          </body>
+        </html>
+    "#, Url::from_file_path(wheel.path()).unwrap().as_str()})?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["tqdm"]
+
+        [[tool.uv.index]]
+        url = "{}"
+        "#,
+        Url::from_directory_path(root.path()).unwrap().as_str()
+    })?;
+
+    // Locking should warn that the project is deprecated.
+    uv_snapshot!(context.filters(), context.lock().env_remove(EnvVars::UV_EXCLUDE_NEWER), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    warning: `tqdm` is deprecated (reason: "Use tqdm2 instead")
+    "###);
+
+    uv_snapshot!(context.filters(), context.tree().arg("--status").env_remove(EnvVars::UV_EXCLUDE_NEWER), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    └── tqdm v1000.0.0 (deprecated: Use tqdm2 instead)
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 fn platform_dependencies() -> Result<()> {
     let context = TestContext::new("3.12");